@@ -0,0 +1,72 @@
+//! Functions whose generated code should be identical to the same function
+//! written with bare values, to check that quantities stay free at runtime.
+//!
+//! Compare the assembly of each `quantity_*` function with its `raw_*`
+//! counterpart, for example with
+//! [`cargo-show-asm`](https://crates.io/crates/cargo-show-asm):
+//!
+//! ```text
+//! cargo asm --example codegen --release quantity_speed
+//! cargo asm --example codegen --release raw_speed
+//! ```
+//!
+//! Identity conversions and conversions between floating-point prefixes
+//! should compile to nothing or to a single multiplication.
+
+use uy::{si, Div, Quantity};
+
+#[inline(never)]
+pub fn quantity_speed(
+    d: Quantity<f64, si::m>,
+    t: Quantity<f64, si::s>,
+) -> Quantity<f64, Div<si::m, si::s>> {
+    d / t
+}
+
+#[inline(never)]
+pub fn raw_speed(d: f64, t: f64) -> f64 {
+    d / t
+}
+
+#[inline(never)]
+pub fn quantity_identity(x: Quantity<f64, si::m>) -> Quantity<f64, si::m> {
+    x.convert()
+}
+
+#[inline(never)]
+pub fn raw_identity(x: f64) -> f64 {
+    x
+}
+
+#[inline(never)]
+pub fn quantity_to_millimeters(x: Quantity<f64, si::m>) -> Quantity<f64, si::milli<si::m>> {
+    x.convert()
+}
+
+#[inline(never)]
+pub fn raw_to_millimeters(x: f64) -> f64 {
+    x * 1000.0
+}
+
+#[inline(never)]
+pub fn quantity_sum(xs: &[Quantity<f32, si::N>]) -> Quantity<f32, si::N> {
+    xs.iter().copied().fold(Quantity::new(0.0), |a, b| a + b)
+}
+
+#[inline(never)]
+pub fn raw_sum(xs: &[f32]) -> f32 {
+    xs.iter().copied().fold(0.0, |a, b| a + b)
+}
+
+fn main() {
+    let d = std::hint::black_box(Quantity::new(3.0));
+    let t = std::hint::black_box(Quantity::new(1.5));
+    assert_eq!(quantity_speed(d, t).into_value(), raw_speed(3.0, 1.5));
+    assert_eq!(quantity_identity(d).into_value(), raw_identity(3.0));
+    assert_eq!(
+        quantity_to_millimeters(d).into_value(),
+        raw_to_millimeters(3.0)
+    );
+    let xs = [Quantity::new(1.0), Quantity::new(2.5)];
+    assert_eq!(quantity_sum(&xs).into_value(), raw_sum(&[1.0, 2.5]));
+}
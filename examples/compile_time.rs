@@ -0,0 +1,44 @@
+//! A compile-time benchmark for the unit type machinery.
+//!
+//! Every unit expression in this file has to be resolved by the trait solver,
+//! so the time it takes to type-check is a good proxy for the cost of using
+//! `uy` in a downstream crate. Measure it with:
+//!
+//! ```text
+//! touch examples/compile_time.rs
+//! CARGO_INCREMENTAL=0 cargo build --example compile_time --timings
+//! ```
+//!
+//! or, for a per-pass breakdown, with `-Ztime-passes` on a nightly compiler:
+//!
+//! ```text
+//! cargo +nightly rustc --example compile_time -- -Ztime-passes
+//! ```
+
+use uy::{si, Div, Mul, Quantity};
+
+macro_rules! bench {
+    ($($a:ident),*) => {
+        bench!(@outer [$($a),*] [$($a),*]);
+    };
+    (@outer [$($a:ident),*] $b:tt) => {
+        $( bench!(@inner $a $b); )*
+    };
+    (@inner $a:ident [$($b:ident),*]) => {
+        $(
+            {
+                let x: Quantity<f64, Mul<Div<si::$a, si::$b>, si::kilo<si::$a>>> =
+                    Quantity::<f64, si::$a>::new(1.0) / Quantity::<f64, si::$b>::new(2.0)
+                        * Quantity::<f64, si::kilo<si::$a>>::new(3.0);
+                let y: Quantity<f64, Div<Mul<si::$a, si::$b>, si::milli<si::$b>>> =
+                    Quantity::<f64, si::$a>::new(1.0) * Quantity::<f64, si::$b>::new(2.0)
+                        / Quantity::<f64, si::milli<si::$b>>::new(3.0);
+                std::hint::black_box((x, y));
+            }
+        )*
+    };
+}
+
+fn main() {
+    bench!(s, m, kg, A, K, mol, cd, rad, N, J, W, V, Ohm, Pa);
+}
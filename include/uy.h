@@ -0,0 +1,46 @@
+/* C declarations for the `uy::ffi` module of the `uy` crate. */
+
+#ifndef UY_H
+#define UY_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#define UY_MAX_BASE_UNITS 8
+
+#define UY_SYSTEM_SI 0
+#define UY_SYSTEM_CGS 1
+
+/* A unit, as its system, scale, and the exponent of each base unit. */
+typedef struct UnitDescriptor {
+    uint8_t system;
+    int8_t prefix;
+    double factor;
+    int8_t dimension[UY_MAX_BASE_UNITS];
+} UnitDescriptor;
+
+/* A value tagged with a description of its unit. */
+typedef struct QuantityFfi {
+    double value;
+    UnitDescriptor unit;
+} QuantityFfi;
+
+/* A value tagged with its unit packed by `uy_pack_unit`. */
+typedef struct PackedQuantityFfi {
+    double value;
+    uint64_t unit;
+} PackedQuantityFfi;
+
+/* Whether two units measure the same dimension in the same system. */
+bool uy_same_dimension(UnitDescriptor a, UnitDescriptor b);
+
+/* The value of `q` in the unit `to`, or NaN if the dimensions don't match. */
+double uy_convert(QuantityFfi q, UnitDescriptor to);
+
+/* Pack `unit` into `out`, returning whether it could be packed. */
+bool uy_pack_unit(UnitDescriptor unit, uint64_t *out);
+
+/* The descriptor a unit was packed from. */
+UnitDescriptor uy_unpack_unit(uint64_t unit);
+
+#endif /* UY_H */
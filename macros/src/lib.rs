@@ -0,0 +1,414 @@
+//! Procedural macros for [`uy`](https://docs.rs/uy).
+//!
+//! These are re-exported from `uy` when its `macros` feature is enabled, so
+//! there is no need to depend on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::visit::Visit;
+use syn::{parenthesized, parse_macro_input, token, Data, DeriveInput, Fields, Ident, Token};
+
+/// A unit expression such as `kg * m / s^2` or `milli<m>`.
+///
+/// Single identifiers refer to units in `uy::si`, longer paths are used as
+/// they are written.
+struct UnitExpr(TokenStream2);
+
+impl Parse for UnitExpr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut acc = parse_factor(input)?;
+        loop {
+            if input.peek(Token![*]) {
+                input.parse::<Token![*]>()?;
+                let rhs = parse_factor(input)?;
+                acc = quote!(::uy::Mul<#acc, #rhs>);
+            } else if input.peek(Token![/]) {
+                input.parse::<Token![/]>()?;
+                let rhs = parse_factor(input)?;
+                acc = quote!(::uy::Div<#acc, #rhs>);
+            } else {
+                break;
+            }
+        }
+        Ok(UnitExpr(acc))
+    }
+}
+
+fn parse_factor(input: ParseStream) -> syn::Result<TokenStream2> {
+    let base = if input.peek(token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        content.parse::<UnitExpr>()?.0
+    } else if input.peek(syn::LitInt) {
+        let one: syn::LitInt = input.parse()?;
+        if one.base10_parse::<u8>()? != 1 {
+            return Err(syn::Error::new(one.span(), "expected `1` or a unit"));
+        }
+        quote!(::uy::si::unitless)
+    } else {
+        parse_unit_path(input)?
+    };
+
+    if !input.peek(Token![^]) {
+        return Ok(base);
+    }
+    input.parse::<Token![^]>()?;
+    let negative = input.peek(Token![-]);
+    if negative {
+        input.parse::<Token![-]>()?;
+    }
+    let exp: syn::LitInt = input.parse()?;
+    let mut pow = quote!(::uy::si::unitless);
+    for _ in 0..exp.base10_parse::<u8>()? {
+        pow = quote!(::uy::Mul<#pow, #base>);
+    }
+    if negative {
+        pow = quote!(::uy::Div<::uy::si::unitless, #pow>);
+    }
+    Ok(pow)
+}
+
+fn parse_unit_path(input: ParseStream) -> syn::Result<TokenStream2> {
+    let leading_colon = input.parse::<Option<Token![::]>>()?;
+    let mut segments = vec![input.parse::<Ident>()?];
+    while input.peek(Token![::]) {
+        input.parse::<Token![::]>()?;
+        segments.push(input.parse()?);
+    }
+
+    let path = if leading_colon.is_none() && segments.len() == 1 {
+        quote!(::uy::si::#(#segments)*)
+    } else {
+        quote!(#leading_colon #(#segments)::*)
+    };
+
+    if !input.peek(Token![<]) {
+        return Ok(path);
+    }
+    input.parse::<Token![<]>()?;
+    let mut args = Vec::new();
+    loop {
+        if input.peek(Token![-]) || input.peek(syn::LitInt) {
+            let neg = input.parse::<Option<Token![-]>>()?;
+            let lit: syn::LitInt = input.parse()?;
+            args.push(quote!(#neg #lit));
+        } else {
+            args.push(input.parse::<UnitExpr>()?.0);
+        }
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        } else {
+            break;
+        }
+    }
+    input.parse::<Token![>]>()?;
+    Ok(quote!(#path<#(#args),*>))
+}
+
+/// Assert at compile time that a unit has the dimension given by a unit
+/// expression, regardless of its scale.
+///
+/// The unit expression is written as in `#[derive(Quantities)]`.
+///
+/// ```rust
+/// use uy::si;
+///
+/// type Thrust = si::kilo<si::N>;
+/// uy::assert_dimension!(Thrust, kg * m / s^2);
+/// uy::assert_dimension!(si::W, J / s);
+/// ```
+///
+/// ```rust,compile_fail
+/// use uy::si;
+///
+/// uy::assert_dimension!(si::J, kg * m / s^2);
+/// ```
+#[proc_macro]
+pub fn assert_dimension(input: TokenStream) -> TokenStream {
+    let AssertDimension { ty, unit, text } = parse_macro_input!(input as AssertDimension);
+    let message = format!("`{}` does not have the dimension `{}`", quote!(#ty), text);
+    quote! {
+        const _: () = ::core::assert!(::uy::__private::same_dimension::<#ty, #unit>(), #message);
+    }
+    .into()
+}
+
+struct AssertDimension {
+    ty: syn::Type,
+    unit: TokenStream2,
+    text: String,
+}
+
+impl Parse for AssertDimension {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let text = input.fork().parse::<TokenStream2>()?.to_string();
+        let unit = input.parse::<UnitExpr>()?.0;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(AssertDimension { ty, unit, text })
+    }
+}
+
+/// The methods that take the raw value out of a quantity.
+const RAW_ACCESSORS: &[&str] = &["deref", "deref_mut", "into_value", "value", "value_mut"];
+
+/// Forbid taking the raw value out of quantities in a function, module, or
+/// impl block, so that all of the math in it stays unit-checked.
+///
+/// Calls of `into_value()`, `value()`, `value_mut()`, `deref()`, and
+/// `deref_mut()` are compile errors in the annotated item, whether written
+/// as methods or as paths such as `Quantity::into_value`, and so are `*`
+/// dereferences. Macros only see tokens, not types, so these are matched by
+/// name: a method called `value()` on any type is rejected too, as is `*`
+/// applied to a reference.
+///
+/// ```rust
+/// use uy::{si, Quantity};
+///
+/// #[uy::no_raw_math]
+/// fn work(force: Quantity<f64, si::N>, distance: Quantity<f64, si::m>) -> Quantity<f64, si::J> {
+///     force * distance
+/// }
+///
+/// let w = work(Quantity::new(2.0), Quantity::new(3.0));
+/// assert_eq!(w.into_value(), 6.0);
+/// ```
+///
+/// ```rust,compile_fail
+/// #[uy::no_raw_math]
+/// mod guidance {
+///     use uy::{si, Quantity};
+///
+///     pub fn scale(d: Quantity<f64, si::m>) -> f64 {
+///         d.into_value() * 2.0
+///     }
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use uy::{si, Quantity};
+///
+/// #[uy::no_raw_math]
+/// fn scale(d: Quantity<f64, si::m>) -> f64 {
+///     *d * 2.0
+/// }
+/// ```
+///
+/// The check only sees what's written in the item, so it can't catch
+/// methods of the value type reached through `Deref` without naming it,
+/// such as `d.sqrt()`. Disabling the default `deref` feature of `uy` closes
+/// that gap.
+#[proc_macro_attribute]
+pub fn no_raw_math(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = TokenStream2::from(args);
+    if !args.is_empty() {
+        return syn::Error::new_spanned(args, "`no_raw_math` takes no arguments")
+            .into_compile_error()
+            .into();
+    }
+    let item = parse_macro_input!(input as syn::Item);
+    let mut finder = RawAccessFinder { error: None };
+    finder.visit_item(&item);
+    let error = finder.error.map(syn::Error::into_compile_error);
+    quote!(#item #error).into()
+}
+
+struct RawAccessFinder {
+    error: Option<syn::Error>,
+}
+
+impl RawAccessFinder {
+    fn check(&mut self, ident: &Ident) {
+        let name = ident.to_string();
+        if !RAW_ACCESSORS.contains(&name.as_str()) {
+            return;
+        }
+        let error = syn::Error::new(
+            ident.span(),
+            format!(
+                "`{}` takes the raw value out of a quantity inside `#[no_raw_math]`",
+                name
+            ),
+        );
+        self.report(error);
+    }
+
+    fn report(&mut self, error: syn::Error) {
+        match &mut self.error {
+            Some(e) => e.combine(error),
+            None => self.error = Some(error),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for RawAccessFinder {
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        self.check(&call.method);
+        syn::visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_unary(&mut self, unary: &'ast syn::ExprUnary) {
+        if let syn::UnOp::Deref(star) = unary.op {
+            self.report(syn::Error::new(
+                star.span,
+                "`*` takes the raw value out of a quantity inside `#[no_raw_math]`",
+            ));
+        }
+        syn::visit::visit_expr_unary(self, unary);
+    }
+
+    fn visit_expr_path(&mut self, path: &'ast syn::ExprPath) {
+        // A single segment is a local or a free function, not a method.
+        if path.path.segments.len() > 1 {
+            self.check(&path.path.segments.last().unwrap().ident);
+        }
+        syn::visit::visit_expr_path(self, path);
+    }
+}
+
+/// Generate a strongly-typed view of a plain struct.
+///
+/// Fields annotated with `#[unit(...)]` become [`Quantity`]s with that unit
+/// in the generated struct, which is named `<Name>Quantities` unless
+/// overridden with `#[quantities(name = ...)]`. Other fields are copied over
+/// unchanged. `From` impls convert in both directions, and
+/// `into_quantities()` is added to the original struct.
+///
+/// Unit expressions combine units with `*`, `/`, and `^`. Single identifiers
+/// refer to units in `uy::si`.
+///
+/// ```rust
+/// use uy::{si, Quantity, Quantities};
+///
+/// #[derive(Quantities)]
+/// struct Telemetry {
+///     #[unit(m / s)]
+///     speed: f64,
+///     #[unit(kg * m / s^2)]
+///     thrust: f64,
+///     #[unit(milli<A>)]
+///     current: i32,
+///     sequence: u32,
+/// }
+///
+/// let raw = Telemetry { speed: 12.5, thrust: 40.0, current: 150, sequence: 7 };
+/// let typed: TelemetryQuantities = raw.into_quantities();
+/// let _: Quantity<f64, uy::Div<si::m, si::s>> = typed.speed;
+/// let _: Quantity<f64, si::N> = typed.thrust;
+/// let _: Quantity<i32, si::milli<si::A>> = typed.current;
+/// assert_eq!(typed.sequence, 7);
+///
+/// let raw = Telemetry::from(typed);
+/// assert_eq!(raw.current, 150);
+/// ```
+///
+/// [`Quantity`]: https://docs.rs/uy/latest/uy/struct.Quantity.html
+#[proc_macro_derive(Quantities, attributes(unit, quantities))]
+pub fn derive_quantities(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_quantities(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_quantities(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut view = format_ident!("{}Quantities", name);
+    for attr in &input.attrs {
+        if attr.path().is_ident("quantities") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    view = meta.value()?.parse()?;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `name = ...`"))
+                }
+            })?;
+        }
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "`Quantities` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`Quantities` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut view_fields = Vec::new();
+    let mut to_view = Vec::new();
+    let mut from_view = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+        let docs = field.attrs.iter().filter(|a| a.path().is_ident("doc"));
+
+        let mut unit = None;
+        for attr in &field.attrs {
+            if attr.path().is_ident("unit") {
+                if unit.is_some() {
+                    return Err(syn::Error::new_spanned(attr, "duplicate `#[unit]`"));
+                }
+                unit = Some(attr.parse_args::<UnitExpr>()?.0);
+            }
+        }
+
+        match unit {
+            Some(unit) => {
+                view_fields.push(quote!(#(#docs)* #field_vis #ident: ::uy::Quantity<#ty, #unit>));
+                to_view.push(quote!(#ident: ::uy::Quantity::new(raw.#ident)));
+                from_view.push(quote!(#ident: typed.#ident.into_value()));
+            }
+            None => {
+                view_fields.push(quote!(#(#docs)* #field_vis #ident: #ty));
+                to_view.push(quote!(#ident: raw.#ident));
+                from_view.push(quote!(#ident: typed.#ident));
+            }
+        }
+    }
+
+    let doc = format!("Strongly-typed view of [`{}`].", name);
+    let generics = &input.generics;
+    Ok(quote! {
+        #[doc = #doc]
+        #vis struct #view #generics #where_clause {
+            #(#view_fields,)*
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for #view #ty_generics #where_clause {
+            fn from(raw: #name #ty_generics) -> Self {
+                Self { #(#to_view,)* }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#view #ty_generics> for #name #ty_generics #where_clause {
+            fn from(typed: #view #ty_generics) -> Self {
+                Self { #(#from_view,)* }
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Convert into the strongly-typed view of this struct.
+            #vis fn into_quantities(self) -> #view #ty_generics {
+                ::core::convert::From::from(self)
+            }
+        }
+    })
+}
@@ -0,0 +1,164 @@
+//! Converting raw analog-to-digital converter readings into quantities, and
+//! voltages into the codes of a digital-to-analog converter.
+//!
+//! Raw converter values are quantities in [`codes`], which can't be mixed up
+//! with plain numbers or with other counts:
+//!
+//! ```rust
+//! use uy::adc::{self, codes};
+//! use uy::si::quantities::ElectricPotential;
+//! use uy::Quantity;
+//!
+//! let vref = ElectricPotential::new(2.5);
+//! let code: Quantity<u32, codes> = Quantity::new(4095);
+//! assert_eq!(adc::codes_to_volts::<12>(code, vref).into_value(), 2.5);
+//!
+//! let setpoint = adc::volts_to_codes::<10>(ElectricPotential::new(1.25), vref);
+//! assert_eq!(setpoint.into_value(), 512);
+//! assert_eq!(setpoint.to_string(), "512 codes");
+//! ```
+//!
+//! ```rust,compile_fail
+//! use uy::adc::codes;
+//! use uy::{si, Quantity};
+//!
+//! let code: Quantity<u32, codes> = Quantity::new(4095);
+//! let n: Quantity<u32, si::counts> = code.convert();
+//! ```
+
+use crate::kind::kind;
+use crate::si::quantities::{ElectricPotential, Resistance};
+use crate::{si, Kinded, Quantity, Unit};
+
+kind!(
+    /// Raw values of an analog-to-digital or digital-to-analog converter.
+    Codes: si::unitless, "codes"
+);
+
+/// The unit of raw converter values.
+#[allow(non_camel_case_types)]
+pub type codes = Kinded<Codes, si::unitless>;
+
+/// The voltage of a `BITS`-bit converter code, given the reference voltage
+/// at full scale. Codes above full scale are clamped to it.
+pub fn codes_to_volts<const BITS: u8>(
+    code: Quantity<u32, codes>,
+    vref: ElectricPotential<f32>,
+) -> ElectricPotential<f32> {
+    AdcReading::<BITS>(code.val.min(AdcReading::<BITS>::FULL_SCALE)).to_voltage(vref)
+}
+
+/// The nearest `BITS`-bit converter code to a voltage, given the reference
+/// voltage at full scale. Voltages outside `0` to `vref` are clamped to the
+/// range of the converter.
+///
+/// ```rust
+/// use uy::adc;
+/// use uy::si::quantities::ElectricPotential;
+///
+/// let vref = ElectricPotential::new(3.3);
+/// assert_eq!(adc::volts_to_codes::<8>(ElectricPotential::new(5.0), vref).into_value(), 255);
+/// assert_eq!(adc::volts_to_codes::<8>(ElectricPotential::new(-0.2), vref).into_value(), 0);
+/// ```
+pub fn volts_to_codes<const BITS: u8>(
+    v: ElectricPotential<f32>,
+    vref: ElectricPotential<f32>,
+) -> Quantity<u32, codes> {
+    let full_scale = AdcReading::<BITS>::FULL_SCALE;
+    let fraction = (v.val / vref.val).clamp(0.0, 1.0);
+    Quantity::new(((fraction * full_scale as f32).round() as u32).min(full_scale))
+}
+
+/// A raw reading from an ADC with a resolution of `BITS` bits.
+///
+/// ```rust
+/// use uy::adc::AdcReading;
+/// use uy::si::quantities::ElectricPotential;
+/// use uy::{si, Quantity};
+///
+/// let reading = AdcReading::<12>::new(2048).unwrap();
+/// let v = reading.to_voltage(ElectricPotential::new(3.3));
+/// assert!((v.into_value() - 1.65).abs() < 1e-3);
+///
+/// // A ratiometric pressure sensor spanning 0 to 100 kPa.
+/// let p: Quantity<f32, si::kilo<si::Pa>> =
+///     reading.ratiometric(Quantity::new(0.0), Quantity::new(100.0));
+/// assert!((p.into_value() - 50.0).abs() < 0.1);
+///
+/// assert_eq!(reading.code().into_value(), 2048);
+/// assert!(AdcReading::<10>::new(1024).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AdcReading<const BITS: u8>(u32);
+
+impl<const BITS: u8> AdcReading<BITS> {
+    /// The largest raw value the ADC can produce.
+    pub const FULL_SCALE: u32 = {
+        assert!(
+            BITS >= 1 && BITS <= 32,
+            "ADC resolution must be 1 to 32 bits"
+        );
+        u32::MAX >> (32 - BITS)
+    };
+
+    /// A reading of `raw` counts, or `None` if it's above [`FULL_SCALE`](Self::FULL_SCALE).
+    pub fn new(raw: u32) -> Option<Self> {
+        (raw <= Self::FULL_SCALE).then_some(Self(raw))
+    }
+
+    /// The raw number of counts.
+    pub fn raw(self) -> u32 {
+        self.0
+    }
+
+    /// A reading of `code`, or `None` if it's above [`FULL_SCALE`](Self::FULL_SCALE).
+    pub fn from_code(code: Quantity<u32, codes>) -> Option<Self> {
+        Self::new(code.val)
+    }
+
+    /// The raw number of counts, as a quantity in [`codes`].
+    pub fn code(self) -> Quantity<u32, codes> {
+        Quantity::new(self.0)
+    }
+
+    /// The reading as a fraction of full scale, from `0.0` to `1.0`.
+    pub fn fraction(self) -> f32 {
+        self.0 as f32 / Self::FULL_SCALE as f32
+    }
+
+    /// The voltage at the ADC input, given its reference voltage.
+    pub fn to_voltage(self, vref: ElectricPotential<f32>) -> ElectricPotential<f32> {
+        Quantity::new(self.fraction() * vref.val)
+    }
+
+    /// The voltage at the top of a divider of `r_top` over `r_bottom` whose
+    /// midpoint is connected to the ADC input.
+    ///
+    /// ```rust
+    /// use uy::adc::AdcReading;
+    /// use uy::Quantity;
+    ///
+    /// let reading = AdcReading::<10>::new(1023).unwrap();
+    /// let battery =
+    ///     reading.to_divided_voltage(Quantity::new(3.3), Quantity::new(30e3), Quantity::new(10e3));
+    /// assert!((battery.into_value() - 13.2).abs() < 1e-3);
+    /// ```
+    pub fn to_divided_voltage(
+        self,
+        vref: ElectricPotential<f32>,
+        r_top: Resistance<f32>,
+        r_bottom: Resistance<f32>,
+    ) -> ElectricPotential<f32> {
+        Quantity::new(self.to_voltage(vref).val * (r_top.val + r_bottom.val) / r_bottom.val)
+    }
+
+    /// The quantity measured by a ratiometric sensor whose output spans the
+    /// ADC's full scale from `min` to `max`.
+    pub fn ratiometric<U: Unit>(
+        self,
+        min: Quantity<f32, U>,
+        max: Quantity<f32, U>,
+    ) -> Quantity<f32, U> {
+        Quantity::new(min.val + self.fraction() * (max.val - min.val))
+    }
+}
@@ -0,0 +1,213 @@
+//! Quantities in whichever prefix of a unit they were given in.
+
+use std::str::FromStr;
+use std::{fmt, ops};
+
+use crate::parse::parse_unit;
+use crate::{si, BaseOf, Error, MulPowerOfTen, Quantity, Scalar, TenTo, UnitInfo, UnitScale};
+
+/// A unit with the prefixes an [`AnyPrefix`] can hold. This is implemented
+/// for every unit that can be prefixed.
+pub trait Prefixes: UnitInfo + UnitScale {
+    /// The unit scaled by 10⁻⁹.
+    type Nano: UnitInfo;
+    /// The unit scaled by 10⁻⁶.
+    type Micro: UnitInfo;
+    /// The unit scaled by 10⁻³.
+    type Milli: UnitInfo;
+    /// The unit scaled by 10³.
+    type Kilo: UnitInfo;
+}
+
+impl<U> Prefixes for U
+where
+    U: UnitInfo
+        + UnitScale
+        + ops::Mul<TenTo<-9>>
+        + ops::Mul<TenTo<-6>>
+        + ops::Mul<TenTo<-3>>
+        + ops::Mul<TenTo<3>>,
+    si::nano<U>: UnitInfo,
+    si::micro<U>: UnitInfo,
+    si::milli<U>: UnitInfo,
+    si::kilo<U>: UnitInfo,
+{
+    type Nano = si::nano<U>;
+    type Micro = si::micro<U>;
+    type Milli = si::milli<U>;
+    type Kilo = si::kilo<U>;
+}
+
+/// A quantity in one of the common prefixes of the unit `U`, chosen at
+/// runtime, such as a length typed on a command line or in a configuration
+/// file as either `250 nm` or `3 km`.
+///
+/// Parsing keeps the prefix that was written, and [`to`](AnyPrefix::to)
+/// normalizes the quantity once it's past that boundary. Any other unit of
+/// the same dimension is converted to the unprefixed unit.
+///
+/// ```rust
+/// use uy::{si, AnyLength, AnyPrefix, Quantity};
+///
+/// let gap: AnyLength<u64> = "250 nm".parse().unwrap();
+/// assert!(matches!(gap, AnyPrefix::Nano(_)));
+/// assert_eq!(gap.to::<si::pico<si::m>>().into_value(), 250_000);
+/// assert_eq!(gap.to_string(), "250 nm");
+///
+/// let span: AnyLength<f64> = "3 km".parse().unwrap();
+/// let m: Quantity<f64, si::m> = span.to();
+/// assert_eq!(m.into_value(), 3000.0);
+///
+/// let timeout: uy::AnyTime<u32> = "1500 ms".parse().unwrap();
+/// assert_eq!(timeout.try_to::<si::s>(), Err(uy::Error::Truncation));
+/// assert_eq!(timeout.try_to::<si::micro<si::s>>().unwrap().into_value(), 1_500_000);
+///
+/// let supply: uy::AnyVoltage<i32> = "3300 mV".parse().unwrap();
+/// assert!(matches!(supply, AnyPrefix::Milli(_)));
+/// let width: AnyLength<f64> = "25 cm".parse().unwrap();
+/// assert!(matches!(width, AnyPrefix::Base(q) if q.into_value() == 0.25));
+/// assert!("3 A".parse::<AnyLength<f64>>().is_err());
+/// ```
+///
+/// Only prefixes of the same unit can be converted to:
+///
+/// ```rust,compile_fail
+/// use uy::{si, AnyLength};
+///
+/// let span: AnyLength<f64> = "3 km".parse().unwrap();
+/// span.to::<si::s>();
+/// ```
+pub enum AnyPrefix<T, U: Prefixes> {
+    Nano(Quantity<T, U::Nano>),
+    Micro(Quantity<T, U::Micro>),
+    Milli(Quantity<T, U::Milli>),
+    Base(Quantity<T, U>),
+    Kilo(Quantity<T, U::Kilo>),
+}
+
+/// A length in nanometers, micrometers, millimeters, meters, or kilometers.
+pub type AnyLength<T> = AnyPrefix<T, si::m>;
+/// A time in nanoseconds, microseconds, milliseconds, seconds, or
+/// kiloseconds.
+pub type AnyTime<T> = AnyPrefix<T, si::s>;
+/// A current in nanoamperes, microamperes, milliamperes, amperes, or
+/// kiloamperes.
+pub type AnyCurrent<T> = AnyPrefix<T, si::A>;
+/// A voltage in nanovolts, microvolts, millivolts, volts, or kilovolts.
+pub type AnyVoltage<T> = AnyPrefix<T, si::V>;
+/// A power in nanowatts, microwatts, milliwatts, watts, or kilowatts.
+pub type AnyPower<T> = AnyPrefix<T, si::W>;
+/// A frequency in nanohertz, microhertz, millihertz, hertz, or kilohertz.
+pub type AnyFrequency<T> = AnyPrefix<T, si::Hz>;
+
+impl<T, U: Prefixes> AnyPrefix<T, U> {
+    /// The value and the prefix of its unit.
+    fn parts(self) -> (T, i8) {
+        match self {
+            Self::Nano(q) => (q.val, U::Nano::PREFIX),
+            Self::Micro(q) => (q.val, U::Micro::PREFIX),
+            Self::Milli(q) => (q.val, U::Milli::PREFIX),
+            Self::Base(q) => (q.val, U::PREFIX),
+            Self::Kilo(q) => (q.val, U::Kilo::PREFIX),
+        }
+    }
+
+    /// Convert to a prefix of the unit chosen at compile time, truncating
+    /// integers towards zero when converting to a coarser prefix, like
+    /// [`Quantity::convert_lossy`].
+    pub fn to<Y>(self) -> Quantity<T, Y>
+    where
+        T: MulPowerOfTen,
+        Y: UnitScale<Base = BaseOf<U>> + UnitInfo,
+    {
+        let (val, prefix) = self.parts();
+        Quantity::new(val.mul_power_of_ten(Y::PREFIX - prefix))
+    }
+
+    /// Convert to a prefix of the unit chosen at compile time, failing with
+    /// [`Error::Truncation`] if an integer isn't a whole number in `Y`, or
+    /// [`Error::Overflow`] if it doesn't fit in `T`.
+    pub fn try_to<Y>(self) -> Result<Quantity<T, Y>, Error>
+    where
+        T: Scalar,
+        Y: UnitScale<Base = BaseOf<U>> + UnitInfo,
+    {
+        let (val, prefix) = self.parts();
+        let exp = Y::PREFIX - prefix;
+        let converted = val
+            .clone()
+            .checked_mul_power_of_ten(exp)
+            .ok_or(Error::Overflow)?;
+        if T::TRUNCATES && exp > 0 && converted.clone().mul_power_of_ten(-exp) != val {
+            return Err(Error::Truncation);
+        }
+        Ok(Quantity::new(converted))
+    }
+}
+
+impl<T: Clone, U: Prefixes> Clone for AnyPrefix<T, U> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Nano(q) => Self::Nano(q.clone()),
+            Self::Micro(q) => Self::Micro(q.clone()),
+            Self::Milli(q) => Self::Milli(q.clone()),
+            Self::Base(q) => Self::Base(q.clone()),
+            Self::Kilo(q) => Self::Kilo(q.clone()),
+        }
+    }
+}
+
+impl<T: Copy, U: Prefixes> Copy for AnyPrefix<T, U> {}
+
+impl<T: fmt::Display, U: Prefixes> fmt::Display for AnyPrefix<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nano(q) => q.fmt(f),
+            Self::Micro(q) => q.fmt(f),
+            Self::Milli(q) => q.fmt(f),
+            Self::Base(q) => q.fmt(f),
+            Self::Kilo(q) => q.fmt(f),
+        }
+    }
+}
+
+impl<T: fmt::Debug, U: Prefixes> fmt::Debug for AnyPrefix<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nano(q) => f.debug_tuple("Nano").field(q).finish(),
+            Self::Micro(q) => f.debug_tuple("Micro").field(q).finish(),
+            Self::Milli(q) => f.debug_tuple("Milli").field(q).finish(),
+            Self::Base(q) => f.debug_tuple("Base").field(q).finish(),
+            Self::Kilo(q) => f.debug_tuple("Kilo").field(q).finish(),
+        }
+    }
+}
+
+/// Parses a value followed by a unit like the [`FromStr`] impl of
+/// [`Quantity`], keeping the prefix if it's one of the variants.
+impl<T, U> FromStr for AnyPrefix<T, U>
+where
+    T: Scalar + FromStr,
+    T::Err: fmt::Display,
+    U: Prefixes,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let unit = s
+            .trim()
+            .split_once(char::is_whitespace)
+            .map_or("", |(_, u)| u.trim());
+        let prefix = match parse_unit(unit, U::BASE_SYMBOLS) {
+            Ok(parsed) if parsed.factor == U::FACTOR => i8::try_from(parsed.exp).ok(),
+            _ => None,
+        };
+        Ok(match prefix {
+            Some(p) if p == U::Nano::PREFIX => Self::Nano(s.parse()?),
+            Some(p) if p == U::Micro::PREFIX => Self::Micro(s.parse()?),
+            Some(p) if p == U::Milli::PREFIX => Self::Milli(s.parse()?),
+            Some(p) if p == U::Kilo::PREFIX => Self::Kilo(s.parse()?),
+            _ => Self::Base(s.parse()?),
+        })
+    }
+}
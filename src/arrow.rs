@@ -0,0 +1,95 @@
+//! Columns of quantities as [Arrow](https://docs.rs/arrow-array) arrays,
+//! for moving measurements into dataframes.
+//!
+//! The unit of a column is stored in the metadata of its field under
+//! [`UNIT_KEY`]. When reading, the unit in the metadata is checked against
+//! the dimension of the quantity being read, and values are converted to
+//! the quantity's unit.
+//!
+//! ```rust
+//! use arrow_array::Array;
+//! use uy::{si, QuantityVec};
+//!
+//! let speeds: QuantityVec<f64, uy::Div<si::m, si::s>> = QuantityVec::from_values(vec![1.5, 3.0]);
+//! let field = uy::arrow::field::<uy::Div<si::m, si::s>>("speed");
+//! assert_eq!(field.metadata()["unit"], "m/s");
+//! // The values are moved into the array without copying them.
+//! let array = uy::arrow::array(speeds);
+//! assert_eq!(array.value(1), 3.0);
+//!
+//! let back: QuantityVec<f64, uy::Div<si::milli<si::m>, si::s>> = uy::arrow::from_array(&field, &array).unwrap();
+//! assert_eq!(back.values(), &[1500.0, 3000.0]);
+//!
+//! // A column in the wrong dimension is an error.
+//! assert!(uy::arrow::from_array::<si::kg>(&field, &array).is_err());
+//! ```
+
+use arrow_array::{Array, Float64Array};
+use arrow_schema::{DataType, Field};
+
+use crate::parse::parse_unit;
+use crate::{Error, Quantity, QuantityVec, UnitInfo};
+
+/// The key of the field metadata that holds the unit of a column.
+pub const UNIT_KEY: &str = "unit";
+
+/// A non-nullable field named `name` for a column of quantities in `U`, with
+/// the unit in its metadata.
+pub fn field<U: UnitInfo>(name: &str) -> Field {
+    Field::new(name, DataType::Float64, false).with_metadata(
+        [(UNIT_KEY.to_string(), U::UNIT_STR.to_string())]
+            .into_iter()
+            .collect(),
+    )
+}
+
+/// An array of the values of `values`, reusing their allocation.
+pub fn array<U: UnitInfo>(values: QuantityVec<f64, U>) -> Float64Array {
+    Float64Array::from(values.into_values())
+}
+
+/// An array of the values of `values`, copied.
+pub fn array_from_slice<U: UnitInfo>(values: &[Quantity<f64, U>]) -> Float64Array {
+    values.iter().map(|q| q.val).collect::<Vec<_>>().into()
+}
+
+/// Read a column of quantities in `U` from `array`, whose field is `field`,
+/// converting them from the unit in the field metadata. A field without a
+/// unit holds dimensionless values.
+///
+/// Fails if the array isn't a `Float64Array`, if it has nulls, or if the
+/// unit isn't known or has a different dimension than `U`.
+pub fn from_array<U: UnitInfo>(
+    field: &Field,
+    array: &dyn Array,
+) -> Result<QuantityVec<f64, U>, Error> {
+    let array = array
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .ok_or_else(|| {
+            Error::ParseError(format!(
+                "column `{}` is {}, not Float64",
+                field.name(),
+                array.data_type()
+            ))
+        })?;
+    if array.null_count() > 0 {
+        return Err(Error::ParseError(format!(
+            "column `{}` has null values",
+            field.name()
+        )));
+    }
+
+    let text = field.metadata().get(UNIT_KEY).map_or("", String::as_str);
+    let unit = parse_unit(text, U::BASE_SYMBOLS)?;
+    if !unit.same_dimension_as::<U>() {
+        return Err(Error::DimensionMismatch {
+            expected: U::SYMBOL.to_string(),
+            found: text.to_string(),
+        });
+    }
+    let factor = unit.factor_to::<U>();
+    Ok(QuantityVec::from_values(
+        array.values().iter().map(|&v| v * factor).collect(),
+    ))
+}
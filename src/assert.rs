@@ -0,0 +1,193 @@
+//! Compile-time assertions about units, and assertions that quantities are
+//! approximately equal.
+
+/// Assert at compile time that two units are the same, including their
+/// scale.
+///
+/// ```rust
+/// use uy::si;
+///
+/// type Torque = uy::Mul<si::N, si::m>;
+/// uy::assert_same_unit!(Torque, si::J);
+/// uy::assert_same_unit!(si::kilo<si::m>, uy::Mul<si::m, uy::TenTo<3>>);
+/// ```
+///
+/// ```rust,compile_fail
+/// use uy::si;
+///
+/// uy::assert_same_unit!(si::milli<si::V>, si::V);
+/// ```
+#[macro_export]
+macro_rules! assert_same_unit {
+    ($a:ty, $b:ty $(,)?) => {
+        const _: () = ::core::assert!(
+            $crate::__private::same_unit::<$a, $b>(),
+            ::core::concat!(
+                "`",
+                ::core::stringify!($a),
+                "` and `",
+                ::core::stringify!($b),
+                "` are not the same unit"
+            )
+        );
+    };
+}
+
+/// Assert that two floating-point quantities are equal to within a
+/// tolerance, either an absolute one given as a quantity in any unit of the
+/// same dimension, or a relative one given as a fraction of the larger
+/// value.
+///
+/// On failure, both quantities, the tolerance, and their difference are
+/// printed with their unit symbols. Like [`assert_eq!`], a message may
+/// follow the tolerance.
+///
+/// ```rust
+/// use uy::{si, Quantity};
+///
+/// let a: Quantity<f64, si::m> = Quantity::new(1.0000004);
+/// let b: Quantity<f64, si::m> = Quantity::new(1.0);
+/// uy::assert_quantity_eq!(a, b, tol = Quantity::<f64, si::micro<si::m>>::new(1.0));
+/// uy::assert_quantity_eq!(a, b, rel = 1e-6, "the lengths differ");
+/// ```
+///
+/// ```rust,should_panic
+/// use uy::{si, Quantity};
+///
+/// let a: Quantity<f64, si::m> = Quantity::new(1.00001);
+/// let b: Quantity<f64, si::m> = Quantity::new(1.0);
+/// // assertion `left ≈ right` failed
+/// //   left: 1.00001 m
+/// //  right: 1 m
+/// //    tol: 1 µm
+/// //   diff: 0.000010000000000065512 m
+/// uy::assert_quantity_eq!(a, b, tol = Quantity::<f64, si::micro<si::m>>::new(1.0));
+/// ```
+#[macro_export]
+macro_rules! assert_quantity_eq {
+    ($left:expr, $right:expr, tol = $tol:expr $(,)?) => {
+        $crate::__private::assert_quantity_eq_abs($left, $right, $tol, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, tol = $tol:expr, $($arg:tt)+) => {
+        $crate::__private::assert_quantity_eq_abs(
+            $left,
+            $right,
+            $tol,
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+    ($left:expr, $right:expr, rel = $rel:expr $(,)?) => {
+        $crate::__private::assert_quantity_eq_rel($left, $right, $rel, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, rel = $rel:expr, $($arg:tt)+) => {
+        $crate::__private::assert_quantity_eq_rel(
+            $left,
+            $right,
+            $rel,
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use std::fmt;
+
+    use num_traits::Float;
+
+    use crate::symbol::str_eq;
+    use crate::{Quantity, UnitConvertLossy, UnitInfo};
+
+    // Used by `power_of_ten_unit_system!`.
+    pub use crate::inner::{Const, EqAtScale, Equal, OrdAtScale, ToConst, ToTypenum};
+    pub use crate::scaled_unit::factor as scaled_factor;
+    pub use crate::symbol::{unit_symbol, Style, SymbolBuf};
+    pub use {num_traits, paste, typenum};
+
+    /// Zero, for repeating a const argument for each base unit of a unit
+    /// system.
+    pub const fn zero(_unit: &str) -> i8 {
+        0
+    }
+
+    /// Whether two units have the same base units with the same exponents,
+    /// regardless of scale.
+    pub const fn same_dimension<A: UnitInfo, B: UnitInfo>() -> bool {
+        let (a, b) = (A::DIMENSION, B::DIMENSION);
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] || !str_eq(A::BASE_SYMBOLS[i], B::BASE_SYMBOLS[i]) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Whether two units have the same dimension, scale, and kind.
+    pub const fn same_unit<A: UnitInfo, B: UnitInfo>() -> bool {
+        let same_kind = match (A::KIND, B::KIND) {
+            (Some(a), Some(b)) => str_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        A::PREFIX == B::PREFIX
+            && A::FACTOR.numer == B::FACTOR.numer
+            && A::FACTOR.denom == B::FACTOR.denom
+            && same_kind
+            && same_dimension::<A, B>()
+    }
+
+    // Used by `assert_quantity_eq!`.
+    #[track_caller]
+    pub fn assert_quantity_eq_abs<T, U, V>(
+        left: Quantity<T, U>,
+        right: Quantity<T, U>,
+        tol: Quantity<T, V>,
+        msg: Option<fmt::Arguments<'_>>,
+    ) where
+        T: Float + fmt::Display,
+        U: UnitInfo + UnitConvertLossy<T, V>,
+        V: UnitInfo,
+    {
+        let diff = (left.val - right.val).abs();
+        // NaN differences fail too.
+        if diff.is_nan() || diff > U::unit_convert_lossy(tol.val).abs() {
+            failed(left, right, &tol, Quantity::<T, U>::new(diff), msg);
+        }
+    }
+
+    #[track_caller]
+    pub fn assert_quantity_eq_rel<T, U>(
+        left: Quantity<T, U>,
+        right: Quantity<T, U>,
+        rel: T,
+        msg: Option<fmt::Arguments<'_>>,
+    ) where
+        T: Float + fmt::Display,
+        U: UnitInfo,
+    {
+        let diff = (left.val - right.val).abs();
+        if diff.is_nan() || diff > rel * left.val.abs().max(right.val.abs()) {
+            failed(left, right, &rel, Quantity::<T, U>::new(diff), msg);
+        }
+    }
+
+    #[track_caller]
+    fn failed<T: fmt::Display, U: UnitInfo>(
+        left: Quantity<T, U>,
+        right: Quantity<T, U>,
+        tol: &dyn fmt::Display,
+        diff: Quantity<T, U>,
+        msg: Option<fmt::Arguments<'_>>,
+    ) -> ! {
+        let msg = msg.map(|m| format!(": {}", m)).unwrap_or_default();
+        panic!(
+            "assertion `left ≈ right` failed{}\n  left: {}\n right: {}\n   tol: {}\n  diff: {}",
+            msg, left, right, tol, diff
+        )
+    }
+}
@@ -0,0 +1,81 @@
+//! Units for batteries: capacities in ampere-hours, and charge and discharge
+//! rates relative to capacity.
+//!
+//! ```rust
+//! use uy::battery::{self, mAh, C_rate};
+//! use uy::{si, Quantity};
+//!
+//! let cell: Quantity<f64, mAh> = Quantity::new(2500.0);
+//! let coulombs: Quantity<f64, si::C> = cell.convert();
+//! assert!((coulombs.into_value() - 9000.0).abs() < 1e-9);
+//!
+//! // Charging at 0.5 C takes 1.25 A.
+//! let rate: Quantity<f64, C_rate> = Quantity::new(0.5);
+//! let current = battery::current(cell, rate);
+//! assert!((current.into_value() - 1.25).abs() < 1e-12);
+//! assert_eq!(battery::c_rate(current, cell).to_string(), "0.5 C");
+//! ```
+//!
+//! A C-rate is a frequency, but of its own kind, so it can't be mixed up
+//! with one:
+//!
+//! ```rust,compile_fail
+//! use uy::battery::C_rate;
+//! use uy::{si, Quantity};
+//!
+//! let rate: Quantity<f64, C_rate> = Quantity::new(0.5);
+//! let f: Quantity<f64, si::Hz> = rate.convert();
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use num_traits::Float;
+
+use crate::kind::kind;
+use crate::scaled_unit::scaled_unit;
+use crate::{si, Kinded, Quantity, Unit, UnitConvertLossy};
+
+scaled_unit!(
+    /// The ampere-hour, 3600 C.
+    Ah = 3600 / 1 si::C, "Ah"
+);
+scaled_unit!(
+    /// The milliampere-hour, 3.6 C.
+    mAh = 36 / 10 si::C, "mAh"
+);
+scaled_unit!(
+    /// Once per hour, 1/3600 Hz.
+    per_h = 1 / 3600 si::Hz, "h⁻¹"
+);
+
+kind!(
+    /// A charge or discharge current relative to the capacity of a battery,
+    /// as the number of times the capacity would be delivered per hour.
+    CRate: per_h, "C"
+);
+
+/// The C-rate, a current of one capacity per hour.
+pub type C_rate = Kinded<CRate, per_h>;
+
+/// The current that charges or discharges a battery of `capacity` at `rate`.
+pub fn current<T: Float, U: Unit>(
+    capacity: Quantity<T, U>,
+    rate: Quantity<T, C_rate>,
+) -> Quantity<T, si::A>
+where
+    Ah: UnitConvertLossy<T, U>,
+{
+    Quantity::new(capacity.convert_lossy::<Ah>().val * rate.val)
+}
+
+/// The rate at which `current` charges or discharges a battery of
+/// `capacity`.
+pub fn c_rate<T: Float, U: Unit>(
+    current: Quantity<T, si::A>,
+    capacity: Quantity<T, U>,
+) -> Quantity<T, C_rate>
+where
+    Ah: UnitConvertLossy<T, U>,
+{
+    Quantity::new(current.val / capacity.convert_lossy::<Ah>().val)
+}
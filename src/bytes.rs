@@ -0,0 +1,65 @@
+//! Quantities as bytes, for binary protocols and file formats.
+//!
+//! The byte conversions of the primitive types are also available on
+//! quantities of them, keeping the unit in the signature so packing code
+//! can't mix up fields:
+//!
+//! ```rust
+//! use uy::{si, Quantity};
+//!
+//! let current: Quantity<u16, si::milli<si::A>> = Quantity::new(0x1234);
+//! assert_eq!(current.to_le_bytes(), [0x34, 0x12]);
+//! assert_eq!(current.to_be_bytes(), [0x12, 0x34]);
+//!
+//! let temp = Quantity::<f32, si::K>::from_be_bytes([0x43, 0x96, 0x00, 0x00]);
+//! assert_eq!(temp.into_value(), 300.0);
+//! ```
+
+use crate::{Quantity, Unit};
+
+macro_rules! impl_bytes {
+    ($($ty:ty),*) => {
+        $(
+            impl<U: Unit> Quantity<$ty, U> {
+                /// The value as bytes in little-endian order.
+                #[inline]
+                pub const fn to_le_bytes(self) -> [u8; size_of::<$ty>()] {
+                    self.val.to_le_bytes()
+                }
+
+                /// The value as bytes in big-endian order.
+                #[inline]
+                pub const fn to_be_bytes(self) -> [u8; size_of::<$ty>()] {
+                    self.val.to_be_bytes()
+                }
+
+                /// The value as bytes in the target's native order.
+                #[inline]
+                pub const fn to_ne_bytes(self) -> [u8; size_of::<$ty>()] {
+                    self.val.to_ne_bytes()
+                }
+
+                /// A quantity from a value as bytes in little-endian order.
+                #[inline]
+                pub const fn from_le_bytes(bytes: [u8; size_of::<$ty>()]) -> Self {
+                    Self::from_raw(<$ty>::from_le_bytes(bytes))
+                }
+
+                /// A quantity from a value as bytes in big-endian order.
+                #[inline]
+                pub const fn from_be_bytes(bytes: [u8; size_of::<$ty>()]) -> Self {
+                    Self::from_raw(<$ty>::from_be_bytes(bytes))
+                }
+
+                /// A quantity from a value as bytes in the target's native
+                /// order.
+                #[inline]
+                pub const fn from_ne_bytes(bytes: [u8; size_of::<$ty>()]) -> Self {
+                    Self::from_raw(<$ty>::from_ne_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_bytes!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
@@ -0,0 +1,202 @@
+//! Calibrations that map a raw measurement in one unit to a calibrated
+//! quantity in another, such as a thermistor's resistance to a temperature.
+//!
+//! The coefficients of a calibration are typically stored as plain numbers,
+//! in EEPROM or a configuration file. Wrapping them in a [`LinearCal`] or
+//! [`PolyCal`] fixes the units they're in, so they can only be applied to
+//! measurements in the right unit:
+//!
+//! ```rust
+//! use uy::calibration::{Calibration, LinearCal, PolyCal};
+//! use uy::{si, Quantity};
+//!
+//! // A load cell read in millivolts, calibrated with two known weights.
+//! let load: LinearCal<f64, si::milli<si::V>, si::N> = LinearCal::from_points(
+//!     (Quantity::new(0.5), Quantity::new(0.0)),
+//!     (Quantity::new(10.5), Quantity::new(200.0)),
+//! )
+//! .unwrap();
+//! assert_eq!(load.gain().into_value(), 20.0);
+//! assert_eq!(load.offset().into_value(), -10.0);
+//! assert_eq!(load.apply(Quantity::new(3.0)).into_value(), 50.0);
+//!
+//! // A second-order fit of a thermocouple from millivolts to kelvins.
+//! let eeprom = [273.15, 25.08, -0.0618];
+//! let probe: PolyCal<f64, si::milli<si::V>, si::K, 3> = PolyCal::new(eeprom);
+//! let t = probe.apply(Quantity::new(4.0));
+//! assert!((t.into_value() - 372.481_2).abs() < 1e-9);
+//! ```
+//!
+//! ```rust,compile_fail
+//! use uy::calibration::{Calibration, LinearCal};
+//! use uy::{si, Quantity};
+//!
+//! let load: LinearCal<f64, si::milli<si::V>, si::N> =
+//!     LinearCal::new(Quantity::new(20.0), Quantity::new(-10.0));
+//! load.apply(Quantity::<f64, si::V>::new(0.003));
+//! ```
+
+use std::marker::PhantomData;
+use std::{fmt, ops};
+
+use num_traits::Zero;
+
+use crate::{Div, Quantity, Unit};
+
+/// A mapping from measurements in `In` to quantities in `Out`.
+pub trait Calibration<T, In: Unit, Out: Unit> {
+    /// The calibrated quantity for the measurement `input`.
+    fn apply(&self, input: Quantity<T, In>) -> Quantity<T, Out>;
+}
+
+/// A linear calibration, `gain * input + offset`.
+pub struct LinearCal<T, In: Unit, Out: Unit> {
+    gain: T,
+    offset: T,
+    _marker: PhantomData<(In, Out)>,
+}
+
+impl<T, In: Unit, Out: Unit> LinearCal<T, In, Out>
+where
+    Out: ops::Div<In>,
+    Div<Out, In>: Unit,
+{
+    /// The calibration with `gain`, the change in output per unit of
+    /// input, and `offset`, the output for an input of zero.
+    pub fn new(gain: Quantity<T, Div<Out, In>>, offset: Quantity<T, Out>) -> Self {
+        Self {
+            gain: gain.val,
+            offset: offset.val,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The calibration through two measured points, each an input and the
+    /// known output for it. Returns `None` if the inputs are equal, since
+    /// the line through them would be vertical.
+    ///
+    /// ```rust
+    /// use uy::calibration::LinearCal;
+    /// use uy::{si, Quantity};
+    ///
+    /// let p = (Quantity::new(2.0), Quantity::new(1.0));
+    /// assert!(LinearCal::<f64, si::V, si::N>::from_points(p, p).is_none());
+    /// ```
+    pub fn from_points(
+        (x0, y0): (Quantity<T, In>, Quantity<T, Out>),
+        (x1, y1): (Quantity<T, In>, Quantity<T, Out>),
+    ) -> Option<Self>
+    where
+        T: Copy + PartialEq + ops::Sub<Output = T> + ops::Mul<Output = T> + ops::Div<Output = T>,
+    {
+        if x0.val == x1.val {
+            return None;
+        }
+        let gain = (y1.val - y0.val) / (x1.val - x0.val);
+        Some(Self {
+            gain,
+            offset: y0.val - gain * x0.val,
+            _marker: PhantomData,
+        })
+    }
+
+    /// The change in output per unit of input.
+    pub fn gain(&self) -> Quantity<T, Div<Out, In>>
+    where
+        T: Clone,
+    {
+        Quantity::new(self.gain.clone())
+    }
+
+    /// The output for an input of zero.
+    pub fn offset(&self) -> Quantity<T, Out>
+    where
+        T: Clone,
+    {
+        Quantity::new(self.offset.clone())
+    }
+}
+
+impl<T, In: Unit, Out: Unit> Calibration<T, In, Out> for LinearCal<T, In, Out>
+where
+    T: Clone + ops::Mul<Output = T> + ops::Add<Output = T>,
+{
+    fn apply(&self, input: Quantity<T, In>) -> Quantity<T, Out> {
+        Quantity::new(self.gain.clone() * input.val + self.offset.clone())
+    }
+}
+
+impl<T: Clone, In: Unit, Out: Unit> Clone for LinearCal<T, In, Out> {
+    fn clone(&self) -> Self {
+        Self {
+            gain: self.gain.clone(),
+            offset: self.offset.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Copy, In: Unit, Out: Unit> Copy for LinearCal<T, In, Out> {}
+
+impl<T: fmt::Debug, In: Unit, Out: Unit> fmt::Debug for LinearCal<T, In, Out> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LinearCal")
+            .field("gain", &self.gain)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+/// A polynomial calibration, `c[0] + c[1] * input + c[2] * input² + ...`,
+/// with `N` coefficients for an input in `In` and an output in `Out`.
+pub struct PolyCal<T, In: Unit, Out: Unit, const N: usize> {
+    coefficients: [T; N],
+    _marker: PhantomData<(In, Out)>,
+}
+
+impl<T, In: Unit, Out: Unit, const N: usize> PolyCal<T, In, Out, N> {
+    /// The calibration with `coefficients`, from the constant term up, for
+    /// an input in `In` and an output in `Out`.
+    pub const fn new(coefficients: [T; N]) -> Self {
+        Self {
+            coefficients,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The coefficients, from the constant term up.
+    pub fn coefficients(&self) -> &[T; N] {
+        &self.coefficients
+    }
+}
+
+impl<T, In: Unit, Out: Unit, const N: usize> Calibration<T, In, Out> for PolyCal<T, In, Out, N>
+where
+    T: Clone + Zero + ops::Mul<Output = T>,
+{
+    fn apply(&self, input: Quantity<T, In>) -> Quantity<T, Out> {
+        let x = input.val;
+        let y = self
+            .coefficients
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, c| acc * x.clone() + c.clone());
+        Quantity::new(y)
+    }
+}
+
+impl<T: Clone, In: Unit, Out: Unit, const N: usize> Clone for PolyCal<T, In, Out, N> {
+    fn clone(&self) -> Self {
+        Self::new(self.coefficients.clone())
+    }
+}
+
+impl<T: Copy, In: Unit, Out: Unit, const N: usize> Copy for PolyCal<T, In, Out, N> {}
+
+impl<T: fmt::Debug, In: Unit, Out: Unit, const N: usize> fmt::Debug for PolyCal<T, In, Out, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PolyCal")
+            .field("coefficients", &self.coefficients)
+            .finish()
+    }
+}
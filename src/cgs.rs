@@ -0,0 +1,99 @@
+//! The centimetre–gram–second unit system, with its mechanical units.
+//!
+//! Units of the same dimension convert to and from SI for floating-point
+//! values:
+//!
+//! ```rust
+//! use uy::{cgs, si, Quantity};
+//!
+//! let f: Quantity<f64, cgs::dyne> = Quantity::new(2.5e5);
+//! let f: Quantity<f64, si::N> = f.convert();
+//! assert!((f.into_value() - 2.5).abs() < 1e-12);
+//!
+//! let e: Quantity<f64, si::kilo<si::J>> = Quantity::new(1.0);
+//! let e: Quantity<f64, cgs::erg> = e.convert();
+//! assert!((e.into_value() - 1e10).abs() < 1e-2);
+//! ```
+//!
+//! ```rust,compile_fail
+//! use uy::{cgs, si, Quantity};
+//!
+//! let f: Quantity<f64, cgs::dyne> = Quantity::new(1.0);
+//! let e: Quantity<f64, si::J> = f.convert();
+//! ```
+
+#![allow(non_camel_case_types)]
+
+mod inner {
+    crate::power_of_ten_unit_system!(Cgs {
+        s = "second",
+        cm = "centimeter",
+        g = "gram",
+        A = "ampere",
+        K = "kelvin",
+        mol = "mole",
+        cd = "candela",
+        rad = "radian"
+    });
+    crate::scaled_unit::scaled_unit_conversions!(Cgs {
+        s,
+        cm,
+        g,
+        A,
+        K,
+        mol,
+        cd,
+        rad
+    });
+
+    crate::system_conversion!(Cgs => crate::si::Si {
+        s: 1.0,
+        cm: 0.01,
+        g: 0.001,
+        A: 1.0,
+        K: 1.0,
+        mol: 1.0,
+        cd: 1.0,
+        rad: 1.0,
+    });
+}
+
+pub use self::base::*;
+pub use self::derived::*;
+pub use self::inner::{Cgs, Dimension};
+
+pub mod base {
+    //! CGS base units.
+
+    use super::Cgs;
+
+    pub type unitless = Cgs<0, 0, 0, 0, 0, 0, 0, 0, 0>;
+    pub type s = Cgs<0, 1, 0, 0, 0, 0, 0, 0, 0>;
+    pub type cm = Cgs<0, 0, 1, 0, 0, 0, 0, 0, 0>;
+    pub type g = Cgs<0, 0, 0, 1, 0, 0, 0, 0, 0>;
+    pub type A = Cgs<0, 0, 0, 0, 1, 0, 0, 0, 0>;
+    pub type K = Cgs<0, 0, 0, 0, 0, 1, 0, 0, 0>;
+    pub type mol = Cgs<0, 0, 0, 0, 0, 0, 1, 0, 0>;
+    pub type cd = Cgs<0, 0, 0, 0, 0, 0, 0, 1, 0>;
+    pub type rad = Cgs<0, 0, 0, 0, 0, 0, 0, 0, 1>;
+}
+
+pub mod derived {
+    //! CGS derived units.
+
+    use super::base::*;
+    use crate::{Div, Mul};
+
+    /// The gal, a unit of acceleration.
+    pub type Gal = Div<cm, Mul<s, s>>;
+    /// The dyne, a unit of force.
+    pub type dyne = Mul<g, Gal>;
+    /// The erg, a unit of energy.
+    pub type erg = Mul<dyne, cm>;
+    /// The barye, a unit of pressure.
+    pub type Ba = Div<dyne, Mul<cm, cm>>;
+    /// The poise, a unit of dynamic viscosity.
+    pub type P = Mul<Ba, s>;
+    /// The stokes, a unit of kinematic viscosity.
+    pub type St = Div<Mul<cm, cm>, s>;
+}
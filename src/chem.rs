@@ -0,0 +1,57 @@
+//! Concentrations, and the pH scale.
+//!
+//! ```rust
+//! use uy::chem::{self, Molarity};
+//! use uy::{si, Quantity};
+//!
+//! let stock: Molarity<f64> = Molarity::new(0.5);
+//! let dilute: Quantity<f64, si::milli<chem::M>> = (stock / Quantity::<f64, si::unitless>::new(20.0)).convert();
+//! assert!((dilute.into_value() - 25.0).abs() < 1e-9);
+//!
+//! let lead: Quantity<f64, chem::ppb> = Quantity::new(15.0);
+//! let ppm: Quantity<f64, chem::ppm> = lead.convert();
+//! assert!((ppm.into_value() - 0.015).abs() < 1e-12);
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use crate::scaled_unit::scaled_unit;
+use crate::si::quantities::Dimensionless;
+use crate::{si, units, Div, Quantity};
+
+/// The molar, one mole of solute per liter of solution.
+pub type M = Div<si::mol, units::L>;
+/// One mole of solute per kilogram of solvent.
+pub type molal = Div<si::mol, si::kg>;
+
+/// The amount of a solute per volume of solution.
+pub type Molarity<T> = Quantity<T, M>;
+/// The amount of a solute per mass of solvent.
+pub type Molality<T> = Quantity<T, molal>;
+
+scaled_unit!(
+    /// Parts per million, a ratio of 10⁻⁶.
+    ppm = 1 / 1 si::micro<si::unitless>, "ppm"
+);
+scaled_unit!(
+    /// Parts per billion, a ratio of 10⁻⁹.
+    ppb = 1 / 1 si::nano<si::unitless>, "ppb"
+);
+
+/// The pH of a solution with a hydrogen ion concentration of `h`.
+///
+/// ```rust
+/// use uy::chem::{self, Molarity};
+///
+/// let ph = chem::ph(Molarity::<f64>::new(1e-7));
+/// assert!((ph.into_value() - 7.0).abs() < 1e-9);
+/// assert!((chem::from_ph(ph).into_value() - 1e-7).abs() < 1e-15);
+/// ```
+pub fn ph<T: num_traits::Float>(h: Molarity<T>) -> Dimensionless<T> {
+    Quantity::new(-(h / Molarity::new(T::one())).log10().val)
+}
+
+/// The hydrogen ion concentration of a solution with a pH of `ph`.
+pub fn from_ph<T: num_traits::Float>(ph: Dimensionless<T>) -> Molarity<T> {
+    Quantity::new(T::from(10.0).unwrap().powf(-ph.val))
+}
@@ -0,0 +1,219 @@
+//! Units made of a unit from each of two unit systems.
+
+use std::marker::PhantomData;
+use std::{cmp, ops};
+
+use crate::symbol::{join_symbols, SymbolBuf};
+use crate::{
+    Div, Mul, Normalize, Ratio, SameDimension, ScaleEq, ScaleOrd, Unit, UnitConvertExact,
+    UnitConvertLossy, UnitInfo, UnitNormalize,
+};
+
+/// A unit that is the product of `A` and `B`, two units from independently
+/// defined unit systems, such as an SI unit and a unit of a currency.
+///
+/// Composite units multiply, divide, and convert one side at a time, so
+/// mixed-domain rates work without folding both systems into one:
+///
+/// ```rust
+/// use uy::{si, Composite, Quantity};
+///
+/// uy::power_of_ten_unit_system!(Money { usd });
+/// #[allow(non_camel_case_types)]
+/// type usd = Money<0, 1>;
+/// type NoMoney = Money<0, 0>;
+///
+/// // A tariff of 0.15 $/kWh is about 41.7 µ$ per kJ.
+/// let tariff: Quantity<f64, Composite<si::micro<usd>, uy::Inv<si::kilo<si::J>>>> = Quantity::new(41.666);
+/// let used: Quantity<f64, Composite<NoMoney, si::mega<si::J>>> = Quantity::new(36.0);
+///
+/// let tariff: Quantity<f64, Composite<usd, uy::Inv<si::J>>> = tariff.convert();
+/// let used: Quantity<f64, Composite<NoMoney, si::J>> = used.convert();
+/// let cost: Quantity<f64, Composite<usd, si::unitless>> = tariff * used;
+/// assert!((cost.into_value() - 1.5).abs() < 1e-3);
+/// assert_eq!(cost.to_string(), format!("{} usd", cost.into_value()));
+/// ```
+///
+/// Each side only converts within its own unit system:
+///
+/// ```rust,compile_fail
+/// use uy::{si, Composite, Quantity};
+///
+/// uy::power_of_ten_unit_system!(Money { usd });
+/// let cost: Quantity<f64, Composite<Money<0, 1>, si::unitless>> = Quantity::new(1.0);
+/// let _: Quantity<f64, Composite<Money<0, 1>, si::J>> = cost.convert();
+/// ```
+pub struct Composite<A, B>(PhantomData<(A, B)>);
+
+impl<A: Unit, B: Unit> Unit for Composite<A, B> {
+    const DEBUG_INFO: Option<(&'static str, i8, &'static [i8])> = match &Self::DEBUG_BUF {
+        Some((symbol, prefix, (dims, len))) => {
+            Some((symbol.as_str(), *prefix, dims.split_at(*len).0))
+        }
+        None => None,
+    };
+}
+
+impl<A: Unit, B: Unit> Composite<A, B> {
+    // The same symbol and dimension as `UnitInfo`, where both sides have them.
+    #[allow(clippy::type_complexity)]
+    const DEBUG_BUF: Option<(SymbolBuf, i8, ([i8; MAX_BASE_UNITS], usize))> =
+        match (A::DEBUG_INFO, B::DEBUG_INFO) {
+            (Some((sa, pa, da)), Some((sb, pb, db))) => {
+                Some((join_symbols(sa, "·", sb), pa + pb, concat_dims(da, db)))
+            }
+            _ => None,
+        };
+}
+
+impl<T: PartialEq, A: Unit, B: Unit> ScaleEq<T, Self> for Composite<A, B> {
+    #[inline]
+    fn scale_eq(lhs: &T, rhs: &T) -> bool {
+        lhs == rhs
+    }
+}
+
+impl<T: PartialOrd, A: Unit, B: Unit> ScaleOrd<T, Self> for Composite<A, B> {
+    #[inline]
+    fn scale_partial_cmp(lhs: &T, rhs: &T) -> Option<cmp::Ordering> {
+        lhs.partial_cmp(rhs)
+    }
+}
+
+impl<A: UnitNormalize, B: UnitNormalize> UnitNormalize for Composite<A, B> {
+    type Output = Composite<Normalize<A>, Normalize<B>>;
+}
+
+/// The most base units a composite unit can have.
+const MAX_BASE_UNITS: usize = 32;
+
+const fn concat_dims(a: &[i8], b: &[i8]) -> ([i8; MAX_BASE_UNITS], usize) {
+    let mut buf = [0; MAX_BASE_UNITS];
+    assert!(
+        a.len() + b.len() <= MAX_BASE_UNITS,
+        "composite unit has too many base units"
+    );
+    let mut i = 0;
+    while i < a.len() + b.len() {
+        buf[i] = if i < a.len() { a[i] } else { b[i - a.len()] };
+        i += 1;
+    }
+    (buf, a.len() + b.len())
+}
+
+impl<A: UnitInfo, B: UnitInfo> Composite<A, B> {
+    const BASE_SYMBOLS_BUF: ([&'static str; MAX_BASE_UNITS], usize) = {
+        let mut buf = [""; MAX_BASE_UNITS];
+        let (a, b) = (A::BASE_SYMBOLS, B::BASE_SYMBOLS);
+        assert!(
+            a.len() + b.len() <= MAX_BASE_UNITS,
+            "composite unit has too many base units"
+        );
+        let mut i = 0;
+        while i < a.len() + b.len() {
+            buf[i] = if i < a.len() { a[i] } else { b[i - a.len()] };
+            i += 1;
+        }
+        (buf, a.len() + b.len())
+    };
+    const DIMENSION_BUF: ([i8; MAX_BASE_UNITS], usize) = concat_dims(A::DIMENSION, B::DIMENSION);
+    const SYMBOL_BUF: SymbolBuf = join_symbols(A::SYMBOL, "·", B::SYMBOL);
+    const UNIT_STR_BUF: SymbolBuf = join_symbols(A::UNIT_STR, "*", B::UNIT_STR);
+}
+
+/// The base units of `A` come first, followed by those of `B`. The symbol
+/// is the symbols of `A` and `B` joined, leaving out a dimensionless side.
+impl<A: UnitInfo, B: UnitInfo> UnitInfo for Composite<A, B> {
+    const BASE_SYMBOLS: &'static [&'static str] = Self::BASE_SYMBOLS_BUF
+        .0
+        .split_at(Self::BASE_SYMBOLS_BUF.1)
+        .0;
+    const DIMENSION: &'static [i8] = Self::DIMENSION_BUF.0.split_at(Self::DIMENSION_BUF.1).0;
+    const PREFIX: i8 = A::PREFIX + B::PREFIX;
+    const SYMBOL: &'static str = Self::SYMBOL_BUF.as_str();
+    const UNIT_STR: &'static str = Self::UNIT_STR_BUF.as_str();
+    const FACTOR: Ratio = match A::FACTOR.checked_mul(B::FACTOR) {
+        Some(factor) => factor,
+        None => panic!("composite unit factor overflows a u128"),
+    };
+}
+
+impl<A1, B1, A2, B2> ops::Mul<Composite<A2, B2>> for Composite<A1, B1>
+where
+    A1: ops::Mul<A2>,
+    B1: ops::Mul<B2>,
+{
+    type Output = Composite<Mul<A1, A2>, Mul<B1, B2>>;
+
+    fn mul(self, _: Composite<A2, B2>) -> Self::Output {
+        Composite(PhantomData)
+    }
+}
+
+impl<A1, B1, A2, B2> ops::Div<Composite<A2, B2>> for Composite<A1, B1>
+where
+    A1: ops::Div<A2>,
+    B1: ops::Div<B2>,
+{
+    type Output = Composite<Div<A1, A2>, Div<B1, B2>>;
+
+    fn div(self, _: Composite<A2, B2>) -> Self::Output {
+        Composite(PhantomData)
+    }
+}
+
+impl<A1: UnitInfo, B1: UnitInfo, A2, B2> SameDimension<Composite<A1, B1>> for Composite<A2, B2>
+where
+    A2: SameDimension<A1>,
+    B2: SameDimension<B1>,
+{
+}
+
+/// Whether converting a value from `From` to `To` scales it up, as
+/// converting to a finer unit does.
+fn scales_up<From: UnitInfo, To: UnitInfo>() -> bool {
+    let size = |prefix: i8, factor: Ratio| 10f64.powi(prefix.into()) * factor.to_f64();
+    size(To::PREFIX, To::FACTOR) < size(From::PREFIX, From::FACTOR)
+}
+
+/// Converts the side that goes to a finer unit first, so an integer is
+/// only truncated once the other side has been scaled up:
+///
+/// ```rust
+/// use uy::{si, Composite, Quantity};
+///
+/// uy::power_of_ten_unit_system!(Money { usd });
+/// #[allow(non_camel_case_types)]
+/// type usd = Money<0, 1>;
+///
+/// let rate: Quantity<i64, Composite<si::milli<usd>, si::kilo<si::J>>> = Quantity::new(1500);
+/// let rate: Quantity<i64, Composite<usd, si::J>> = rate.convert_lossy();
+/// assert_eq!(rate.into_value(), 1500);
+/// ```
+impl<T, A1: UnitInfo, B1: UnitInfo, A2, B2> UnitConvertLossy<T, Composite<A1, B1>>
+    for Composite<A2, B2>
+where
+    A2: UnitInfo + UnitConvertLossy<T, A1>,
+    B2: UnitInfo + UnitConvertLossy<T, B1>,
+{
+    #[inline]
+    fn unit_convert_lossy(val: T) -> T {
+        if scales_up::<B1, B2>() {
+            A2::unit_convert_lossy(B2::unit_convert_lossy(val))
+        } else {
+            B2::unit_convert_lossy(A2::unit_convert_lossy(val))
+        }
+    }
+}
+
+impl<T, A1: UnitInfo, B1: UnitInfo, A2, B2> UnitConvertExact<T, Composite<A1, B1>>
+    for Composite<A2, B2>
+where
+    A2: UnitInfo + UnitConvertExact<T, A1>,
+    B2: UnitInfo + UnitConvertExact<T, B1>,
+{
+    #[inline]
+    fn unit_convert(val: T) -> T {
+        B2::unit_convert(A2::unit_convert(val))
+    }
+}
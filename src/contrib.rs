@@ -0,0 +1,57 @@
+//! Miscellaneous units that are often asked for, each defined against the
+//! SI so that every project uses the same factors.
+//!
+//! ```rust
+//! use uy::si::quantities::{AngularVelocity, Power};
+//! use uy::{contrib, si, Quantity};
+//!
+//! let spindle: Quantity<f64, contrib::rpm> = Quantity::new(3000.0);
+//! let w: AngularVelocity<f64> = spindle.convert();
+//! assert!((w.into_value() - 314.159).abs() < 1e-3);
+//!
+//! let engine: Quantity<f64, contrib::hp> = Quantity::new(100.0);
+//! let p: Power<f64> = engine.convert();
+//! assert!((p.into_value() - 74_569.987).abs() < 1e-3);
+//!
+//! let coating: Quantity<f64, contrib::mil> = Quantity::new(2.0);
+//! let um: Quantity<f64, contrib::micron> = coating.convert();
+//! assert!((um.into_value() - 50.8).abs() < 1e-9);
+//!
+//! let turn: Quantity<f64, contrib::gn> = Quantity::new(3.0);
+//! let a: Quantity<f64, uy::Div<si::m, uy::Mul<si::s, si::s>>> = turn.convert();
+//! assert!((a.into_value() - 29.41995).abs() < 1e-9);
+//! assert_eq!(turn.to_string(), "3 gₙ");
+//! assert_eq!(turn.to_canonical_string(), "3 gn");
+//! assert_eq!("3 gn".parse::<Quantity<f64, contrib::gn>>().unwrap().into_value(), 3.0);
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use crate::scaled_unit::scaled_unit;
+use crate::{si, Div, Mul};
+
+pub use crate::units::psi;
+
+/// The micron, another name for the micrometer.
+pub type micron = si::micro<si::m>;
+
+scaled_unit!(
+    /// Revolutions per minute, π/30 rad·s⁻¹.
+    ///
+    /// π is approximated to 36 significant digits, well beyond the
+    /// precision of an `f64`.
+    rpm = 314159265358979323846264338327950288 / 3000000000000000000000000000000000000 Div<si::rad, si::s>, "rpm"
+);
+scaled_unit!(
+    /// Multiples of standard gravity, 9.80665 m·s⁻², as felt in a turn or
+    /// an impact.
+    gn = 980665 / 100000 Div<si::m, Mul<si::s, si::s>>, "gₙ", "gn"
+);
+scaled_unit!(
+    /// The mil, a thousandth of an inch, 25.4 µm.
+    mil = 127 / 5 si::micro<si::m>, "mil"
+);
+scaled_unit!(
+    /// Mechanical horsepower, 550 ft·lbf·s⁻¹, about 745.7 W.
+    hp = 74569987158227022000 / 100000000000000000 si::W, "hp"
+);
@@ -0,0 +1,105 @@
+//! Feedback controllers with dimension-checked gains.
+
+use std::marker::PhantomData;
+
+use crate::{si, Div, Mul, Quantity, Unit};
+
+/// The unit of the proportional gain of a controller from `In` to `Out`.
+pub type Kp<In, Out> = Div<Out, In>;
+/// The unit of the integral gain of a controller from `In` to `Out`.
+pub type Ki<In, Out> = Div<Out, Mul<In, si::s>>;
+/// The unit of the derivative gain of a controller from `In` to `Out`.
+pub type Kd<In, Out> = Div<Mul<Out, si::s>, In>;
+
+/// A PID controller producing an output in `Out` from an error in `In`.
+///
+/// The gains are quantities whose units make each term of the output come
+/// out in `Out`, so mixing up a gain is a compile-time error.
+///
+/// ```rust
+/// use uy::control::Pid;
+/// use uy::{si, Quantity};
+///
+/// // Hold a position by applying a force.
+/// let mut pid: Pid<f64, si::m, si::N> = Pid::new(
+///     Quantity::new(20.0),
+///     Quantity::new(2.0),
+///     Quantity::new(5.0),
+/// );
+///
+/// let dt: Quantity<f64, si::s> = Quantity::new(0.5);
+/// let force = pid.update(Quantity::new(0.1), dt);
+/// assert!((force.into_value() - 2.1).abs() < 1e-9);
+///
+/// let force = pid.update(Quantity::new(0.05), dt);
+/// assert!((force.into_value() - 0.65).abs() < 1e-9);
+/// ```
+///
+/// ```rust,compile_fail
+/// use uy::control::Pid;
+/// use uy::{si, Quantity};
+///
+/// let ki: Quantity<f64, uy::Div<si::N, si::m>> = Quantity::new(2.0);
+/// let pid: Pid<f64, si::m, si::N> = Pid::new(Quantity::new(20.0), ki, Quantity::new(5.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pid<T, In: Unit, Out: Unit> {
+    kp: T,
+    ki: T,
+    kd: T,
+    integral: T,
+    prev_error: Option<T>,
+    _marker: PhantomData<(In, Out)>,
+}
+
+impl<T, In: Unit, Out: Unit> Pid<T, In, Out>
+where
+    T: Copy + num_traits::Num,
+{
+    pub fn new(
+        kp: Quantity<T, Kp<In, Out>>,
+        ki: Quantity<T, Ki<In, Out>>,
+        kd: Quantity<T, Kd<In, Out>>,
+    ) -> Self
+    where
+        Out: std::ops::Div<In> + std::ops::Mul<si::s>,
+        In: std::ops::Mul<si::s>,
+        Kp<In, Out>: Unit,
+        Mul<In, si::s>: Unit,
+        Out: std::ops::Div<Mul<In, si::s>>,
+        Ki<In, Out>: Unit,
+        Mul<Out, si::s>: Unit + std::ops::Div<In>,
+        Kd<In, Out>: Unit,
+    {
+        Self {
+            kp: kp.val,
+            ki: ki.val,
+            kd: kd.val,
+            integral: T::zero(),
+            prev_error: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Advance the controller by `dt` with the current `error`, returning the
+    /// new output.
+    ///
+    /// The derivative term is zero on the first update after creation or
+    /// [`reset`](Self::reset).
+    pub fn update(&mut self, error: Quantity<T, In>, dt: Quantity<T, si::s>) -> Quantity<T, Out> {
+        let error = error.val;
+        self.integral = self.integral + error * dt.val;
+        let derivative = match self.prev_error {
+            Some(prev) => (error - prev) / dt.val,
+            None => T::zero(),
+        };
+        self.prev_error = Some(error);
+        Quantity::new(self.kp * error + self.ki * self.integral + self.kd * derivative)
+    }
+
+    /// Clear the accumulated integral and the previous error.
+    pub fn reset(&mut self) {
+        self.integral = T::zero();
+        self.prev_error = None;
+    }
+}
@@ -0,0 +1,80 @@
+//! Iterator adapters for quantities.
+//!
+//! [`QuantityIterator::convert_units`] converts each quantity as it's
+//! yielded, so a stream of samples can be rescaled without collecting it
+//! first:
+//!
+//! ```rust
+//! use uy::{si, Quantity, QuantityIterator};
+//!
+//! let raw = [1200, 1350, 980].map(Quantity::<i32, si::micro<si::V>>::new);
+//! let peak = raw
+//!     .into_iter()
+//!     .convert_units::<si::nano<si::V>>()
+//!     .map(|v| v.into_value())
+//!     .max();
+//! assert_eq!(peak, Some(1_350_000));
+//! ```
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use crate::{Quantity, Unit, UnitConvertExact};
+
+/// Adapters for iterators of quantities.
+pub trait QuantityIterator<T, U: Unit>: Iterator<Item = Quantity<T, U>> + Sized {
+    /// Convert every quantity to the unit `Y`, lazily.
+    fn convert_units<Y: UnitConvertExact<T, U>>(self) -> ConvertUnits<Self, Y> {
+        ConvertUnits {
+            iter: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, T, U: Unit> QuantityIterator<T, U> for I where I: Iterator<Item = Quantity<T, U>> {}
+
+/// An iterator that converts quantities to the unit `Y`, made by
+/// [`QuantityIterator::convert_units`].
+#[derive(Clone, Debug)]
+pub struct ConvertUnits<I, Y> {
+    iter: I,
+    _marker: PhantomData<Y>,
+}
+
+impl<I, T, U: Unit, Y: UnitConvertExact<T, U>> Iterator for ConvertUnits<I, Y>
+where
+    I: Iterator<Item = Quantity<T, U>>,
+{
+    type Item = Quantity<T, Y>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Quantity<T, Y>> {
+        self.iter.next().map(Quantity::convert)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, U: Unit, Y: UnitConvertExact<T, U>> DoubleEndedIterator for ConvertUnits<I, Y>
+where
+    I: DoubleEndedIterator<Item = Quantity<T, U>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Quantity<T, Y>> {
+        self.iter.next_back().map(Quantity::convert)
+    }
+}
+
+impl<I, T, U: Unit, Y: UnitConvertExact<T, U>> ExactSizeIterator for ConvertUnits<I, Y> where
+    I: ExactSizeIterator<Item = Quantity<T, U>>
+{
+}
+
+impl<I, T, U: Unit, Y: UnitConvertExact<T, U>> FusedIterator for ConvertUnits<I, Y> where
+    I: FusedIterator<Item = Quantity<T, U>>
+{
+}
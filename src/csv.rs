@@ -0,0 +1,145 @@
+//! Columns of quantities in CSV files, through [`csv`](https://docs.rs/csv).
+//!
+//! The unit of a column is written in its header after the name, as in
+//! `speed [m/s]`. When reading, the unit in the header is checked against
+//! the dimension of the quantity being read, and values are converted to
+//! the quantity's unit.
+//!
+//! ```rust
+//! use uy::csv::{header, UnitColumn};
+//! use uy::{si, Quantity};
+//!
+//! // Writing: quantities serialize as their raw value, so only the header
+//! // needs the unit.
+//! let mut writer = csv::WriterBuilder::new().has_headers(false).from_writer(vec![]);
+//! writer.write_record([header::<si::s>("time"), header::<uy::Div<si::m, si::s>>("speed")]).unwrap();
+//! writer.serialize((Quantity::<f64, si::s>::new(1.0), Quantity::<f64, uy::Div<si::m, si::s>>::new(3.5))).unwrap();
+//! let data = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+//! assert_eq!(data, "time [s],speed [m/s]\n1.0,3.5\n");
+//!
+//! // Reading: the speed column is converted to mm/s.
+//! let mut reader = csv::Reader::from_reader(data.as_bytes());
+//! let speed: UnitColumn<f64, uy::Div<si::milli<si::m>, si::s>> =
+//!     UnitColumn::find(reader.headers().unwrap(), "speed").unwrap();
+//! let record = reader.records().next().unwrap().unwrap();
+//! assert_eq!(speed.get(&record).unwrap().into_value(), 3500.0);
+//!
+//! // A column in the wrong dimension is an error.
+//! let mut reader = csv::Reader::from_reader(data.as_bytes());
+//! assert!(UnitColumn::<f64, si::kg>::find(reader.headers().unwrap(), "speed").is_err());
+//! ```
+
+use std::fmt;
+use std::io;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use ::csv::{Reader, StringRecord};
+
+use crate::parse::{convert_parsed, parse_unit};
+use crate::registry::RuntimeUnit;
+use crate::{Error, Quantity, Scalar, UnitInfo};
+
+/// The header of a column named `name` holding quantities in `U`, such as
+/// `speed [m/s]`, or just the name for dimensionless quantities.
+pub fn header<U: UnitInfo>(name: &str) -> String {
+    let unit = U::UNIT_STR;
+    if unit.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{} [{}]", name, unit)
+    }
+}
+
+/// Split a header into its name and the unit in square brackets, if any.
+///
+/// ```rust
+/// assert_eq!(uy::csv::split_header("speed [m/s]"), ("speed", "m/s"));
+/// assert_eq!(uy::csv::split_header("count"), ("count", ""));
+/// ```
+pub fn split_header(header: &str) -> (&str, &str) {
+    let header = header.trim();
+    match header
+        .strip_suffix(']')
+        .and_then(|rest| rest.rsplit_once('['))
+    {
+        Some((name, unit)) => (name.trim_end(), unit.trim()),
+        None => (header, ""),
+    }
+}
+
+/// A column of quantities in `U`, whose unit in the header has been checked.
+pub struct UnitColumn<T, U> {
+    index: usize,
+    unit: RuntimeUnit,
+    text: String,
+    _marker: PhantomData<(T, U)>,
+}
+
+impl<T, U> UnitColumn<T, U>
+where
+    T: Scalar + FromStr,
+    T::Err: fmt::Display,
+    U: UnitInfo,
+{
+    /// Find the column named `name`, checking that the unit in its header
+    /// has the same dimension as `U`.
+    pub fn find(headers: &StringRecord, name: &str) -> Result<Self, Error> {
+        let (index, unit) = headers
+            .iter()
+            .map(split_header)
+            .enumerate()
+            .find_map(|(i, (n, unit))| (n == name).then_some((i, unit)))
+            .ok_or_else(|| Error::ParseError(format!("no column named `{}`", name)))?;
+        let parsed = parse_unit(unit, U::BASE_SYMBOLS)?;
+        // Check the dimension now rather than on the first value.
+        if let Ok(zero) = "0".parse::<T>() {
+            convert_parsed::<T, U>(zero, &parsed, unit)?;
+        }
+        Ok(Self {
+            index,
+            unit: parsed,
+            text: unit.to_owned(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// The index of the column in each record.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The value of the column in `record`, converted to `U`.
+    pub fn get(&self, record: &StringRecord) -> Result<Quantity<T, U>, Error> {
+        let field = record
+            .get(self.index)
+            .ok_or_else(|| Error::ParseError(format!("record has no field {}", self.index)))?
+            .trim();
+        let val = field
+            .parse()
+            .map_err(|e| Error::ParseError(format!("invalid value `{}`: {}", field, e)))?;
+        convert_parsed::<T, U>(val, &self.unit, &self.text).map(Quantity::new)
+    }
+}
+
+/// Read every value of the column named `name`, converted to `U`.
+pub fn read_column<T, U, R>(
+    reader: &mut Reader<R>,
+    name: &str,
+) -> Result<Vec<Quantity<T, U>>, Error>
+where
+    T: Scalar + FromStr,
+    T::Err: fmt::Display,
+    U: UnitInfo,
+    R: io::Read,
+{
+    let headers = reader
+        .headers()
+        .map_err(|e| Error::ParseError(e.to_string()))?
+        .clone();
+    let column = UnitColumn::<T, U>::find(&headers, name)?;
+    reader
+        .records()
+        .map(|record| column.get(&record.map_err(|e| Error::ParseError(e.to_string()))?))
+        .collect()
+}
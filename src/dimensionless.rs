@@ -0,0 +1,78 @@
+//! Functions that only make sense for dimensionless quantities, such as
+//! ratios, efficiencies, and Reynolds numbers.
+//!
+//! Dimensioned quantities can't be raised to a runtime power, since the unit
+//! of the result would depend on the exponent:
+//!
+//! ```rust,compile_fail
+//! use uy::si::quantities::{Dimensionless, Length};
+//!
+//! let l: Length<f64> = Length::new(2.0);
+//! let _ = l.powf(Dimensionless::new(1.5));
+//! ```
+
+use num_traits::Float;
+
+use crate::{si, Quantity};
+
+impl<T: Float> Quantity<T, si::unitless> {
+    /// Raise to a dimensionless power.
+    ///
+    /// ```rust
+    /// # use uy::si::quantities::Dimensionless;
+    /// let ratio: Dimensionless<f64> = Dimensionless::new(4.0);
+    /// assert_eq!(ratio.powf(Dimensionless::new(1.5)).into_value(), 8.0);
+    /// ```
+    pub fn powf(self, n: Quantity<T, si::unitless>) -> Self {
+        Quantity::new(self.val.powf(n.val))
+    }
+
+    /// `e` raised to this power.
+    ///
+    /// ```rust
+    /// # use uy::si::quantities::Dimensionless;
+    /// let x: Dimensionless<f64> = Dimensionless::new(0.0);
+    /// assert_eq!(x.exp().into_value(), 1.0);
+    /// ```
+    pub fn exp(self) -> Self {
+        Quantity::new(self.val.exp())
+    }
+
+    /// 2 raised to this power.
+    pub fn exp2(self) -> Self {
+        Quantity::new(self.val.exp2())
+    }
+
+    /// The natural logarithm.
+    ///
+    /// ```rust
+    /// # use uy::si::quantities::Dimensionless;
+    /// let growth: Dimensionless<f64> = Dimensionless::new(1.0);
+    /// assert_eq!(growth.ln().into_value(), 0.0);
+    /// ```
+    pub fn ln(self) -> Self {
+        Quantity::new(self.val.ln())
+    }
+
+    /// The base 10 logarithm, such as for converting a power ratio to
+    /// decibels.
+    ///
+    /// ```rust
+    /// # use uy::si::quantities::Dimensionless;
+    /// let gain: Dimensionless<f64> = Dimensionless::new(100.0);
+    /// assert_eq!(gain.log10().into_value() * 10.0, 20.0);
+    /// ```
+    pub fn log10(self) -> Self {
+        Quantity::new(self.val.log10())
+    }
+
+    /// The base 2 logarithm.
+    pub fn log2(self) -> Self {
+        Quantity::new(self.val.log2())
+    }
+
+    /// The logarithm with respect to an arbitrary base.
+    pub fn log(self, base: Quantity<T, si::unitless>) -> Self {
+        Quantity::new(self.val.log(base.val))
+    }
+}
@@ -0,0 +1,90 @@
+//! Gear ratios, and the power carried by a rotating shaft.
+//!
+//! Torques are [`si::Nm`](crate::si::Nm), a kind of their own, so a torque can't be passed
+//! where an energy is expected, or the other way around:
+//!
+//! ```rust
+//! use uy::drivetrain::{self, GearRatio};
+//! use uy::si::quantities::{AngularVelocity, Torque};
+//! use uy::{si, Quantity};
+//!
+//! // A 12-tooth pinion driving a 60-tooth gear, then a 3:1 belt.
+//! let ratio = GearRatio::from_teeth(12.0, 60.0).then(GearRatio::new(3.0));
+//! assert_eq!(ratio.value(), 15.0);
+//!
+//! let motor: Torque<f64> = Quantity::new(2.0);
+//! let shaft: Quantity<f64, si::milli<si::Nm>> = ratio.torque(motor).convert();
+//! assert_eq!(shaft.into_value(), 30_000.0);
+//!
+//! let speed: AngularVelocity<f64> = Quantity::new(300.0);
+//! assert_eq!(ratio.speed(speed).into_value(), 20.0);
+//! assert_eq!(drivetrain::power(motor, speed).into_value(), 600.0);
+//! ```
+//!
+//! ```rust,compile_fail
+//! use uy::drivetrain::GearRatio;
+//! use uy::si::quantities::Energy;
+//! use uy::Quantity;
+//!
+//! let e: Energy<f64> = Quantity::new(2.0);
+//! GearRatio::new(3.0).torque(e);
+//! ```
+
+use std::ops;
+
+use crate::si::kinds;
+use crate::si::quantities::{AngularVelocity, Power, Torque};
+use crate::{Kinded, Quantity, Unit};
+
+/// The ratio of the input speed to the output speed of a gear train. A
+/// reduction, where the output turns slower than the input, has a ratio
+/// greater than one and multiplies torque by it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GearRatio<T>(T);
+
+impl<T: Copy + ops::Mul<Output = T> + ops::Div<Output = T>> GearRatio<T> {
+    pub const fn new(ratio: T) -> Self {
+        Self(ratio)
+    }
+
+    /// The ratio of a gear with `driving` teeth driving one with `driven`
+    /// teeth.
+    pub fn from_teeth(driving: T, driven: T) -> Self {
+        Self(driven / driving)
+    }
+
+    pub fn value(self) -> T {
+        self.0
+    }
+
+    /// The ratio of this gear train followed by `next`.
+    pub fn then(self, next: Self) -> Self {
+        Self(self.0 * next.0)
+    }
+
+    /// The ratio of this gear train run backwards.
+    pub fn inverse(self) -> Self
+    where
+        T: num_traits::One,
+    {
+        Self(T::one() / self.0)
+    }
+
+    /// The output torque for an input torque, ignoring losses.
+    pub fn torque<U: Unit>(
+        self,
+        input: Quantity<T, Kinded<kinds::Torque, U>>,
+    ) -> Quantity<T, Kinded<kinds::Torque, U>> {
+        Quantity::new(input.val * self.0)
+    }
+
+    /// The output speed for an input speed, in any unit.
+    pub fn speed<U: Unit>(self, input: Quantity<T, U>) -> Quantity<T, U> {
+        Quantity::new(input.val / self.0)
+    }
+}
+
+/// The power carried by a shaft turning at `speed` under `torque`.
+pub fn power<T: ops::Mul<Output = T>>(torque: Torque<T>, speed: AngularVelocity<T>) -> Power<T> {
+    Quantity::new(torque.val * speed.val)
+}
@@ -0,0 +1,26 @@
+//! Support for [`encase`](https://docs.rs/encase), so quantities can be
+//! fields of uniform and storage buffers uploaded to the GPU. A quantity has
+//! the layout of its value, and the unit is only checked on the CPU side.
+//!
+//! ```rust
+//! use encase::{ShaderType, UniformBuffer};
+//! use uy::{si, Quantity};
+//!
+//! #[derive(ShaderType)]
+//! struct Light {
+//!     power: Quantity<f32, si::W>,
+//!     range: Quantity<f32, si::m>,
+//! }
+//!
+//! let light = Light { power: Quantity::new(60.0), range: Quantity::new(12.5) };
+//! let mut buffer = UniformBuffer::new(Vec::<u8>::new());
+//! buffer.write(&light).unwrap();
+//!
+//! let bytes = buffer.into_inner();
+//! assert_eq!(bytes[..4], 60f32.to_ne_bytes());
+//! assert_eq!(bytes[4..8], 12.5f32.to_ne_bytes());
+//! ```
+
+use crate::{Quantity, Unit};
+
+::encase::impl_wrapper!(Quantity<T, U>; (T, U: Unit); using Ref{ .val } Mut{ .value_mut() } From{ new });
@@ -0,0 +1,249 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{Quantity, UnitInfo};
+
+/// Errors from fallible conversions and from parsing quantities.
+///
+/// ```rust
+/// let err = uy::Error::DimensionMismatch {
+///     expected: "m·s⁻¹".into(),
+///     found: "m".into(),
+/// };
+/// assert_eq!(err.to_string(), "dimension mismatch: expected m·s⁻¹, found m");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A unit had a different dimension than expected.
+    DimensionMismatch { expected: String, found: String },
+    /// A value doesn't fit in the storage type after conversion.
+    Overflow,
+    /// A conversion would have discarded part of a value.
+    Truncation,
+    /// A floating-point conversion would have lost precision.
+    PrecisionLoss,
+    /// A quantity couldn't be parsed from a string.
+    ParseError(String),
+    /// A unit symbol or name isn't known.
+    UnknownUnit(String),
+    /// A quantity was outside the range it's bounded to.
+    OutOfRange {
+        value: String,
+        min: String,
+        max: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DimensionMismatch { expected, found } => {
+                write!(
+                    f,
+                    "dimension mismatch: expected {}, found {}",
+                    expected, found
+                )
+            }
+            Error::Overflow => f.write_str("value overflowed during conversion"),
+            Error::Truncation => f.write_str("value would be truncated by conversion"),
+            Error::PrecisionLoss => f.write_str("value would lose precision in conversion"),
+            Error::ParseError(msg) => write!(f, "failed to parse quantity: {}", msg),
+            Error::UnknownUnit(unit) => write!(f, "unknown unit `{}`", unit),
+            Error::OutOfRange { value, min, max } => {
+                write!(f, "{} is outside the range {}..={}", value, min, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A quantity rendered with its unit symbol, for storing in error types.
+///
+/// Quantities are generic over their value and unit, so an error that holds
+/// one is too. This holds the formatted value and the symbol instead, so it's
+/// `'static`, `Send`, and `Sync`, and can be a field of any error type.
+/// Width and alignment flags apply to the value and symbol together.
+///
+/// ```rust
+/// use uy::{si, DisplayWithUnit, Quantity};
+///
+/// #[derive(Debug)]
+/// struct Fault {
+///     rail: &'static str,
+///     measured: DisplayWithUnit,
+/// }
+///
+/// impl std::fmt::Display for Fault {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{} rail measured {}", self.rail, self.measured)
+///     }
+/// }
+///
+/// let i: Quantity<f32, si::milli<si::A>> = Quantity::new(812.5);
+/// let fault = Fault { rail: "3V3", measured: i.into() };
+/// assert_eq!(fault.to_string(), "3V3 rail measured 812.5 mA");
+/// assert_eq!(format!("[{:>9}]", fault.measured), "[ 812.5 mA]");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DisplayWithUnit {
+    value: String,
+    symbol: &'static str,
+}
+
+impl DisplayWithUnit {
+    /// The formatted value, without its unit.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The unit symbol, which is empty for unitless quantities.
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+}
+
+impl<T: fmt::Display, U: UnitInfo> From<Quantity<T, U>> for DisplayWithUnit {
+    fn from(q: Quantity<T, U>) -> Self {
+        DisplayWithUnit {
+            value: q.val.to_string(),
+            symbol: U::SYMBOL,
+        }
+    }
+}
+
+impl fmt::Display for DisplayWithUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::fmt_with_symbol(f, &self.value, self.symbol)
+    }
+}
+
+/// A quantity that was outside a limit, such as a commanded velocity above
+/// the maximum a drive allows.
+///
+/// ```rust
+/// use uy::{si, Div, LimitError, Quantity};
+///
+/// fn command(v: Quantity<f64, Div<si::m, si::s>>) -> Result<(), LimitError> {
+///     LimitError::check_max("commanded velocity", v, Quantity::new(8.0))?;
+///     Ok(())
+/// }
+///
+/// let err = command(Quantity::new(12.0)).unwrap_err();
+/// assert_eq!(err.to_string(), "commanded velocity 12 m·s⁻¹ exceeds limit 8 m·s⁻¹");
+/// assert_eq!(err.value.value(), "12");
+///
+/// let err = LimitError::check_min("cell temperature", Quantity::<f32, si::K>::new(250.5), Quantity::new(263.0));
+/// assert_eq!(err.unwrap_err().to_string(), "cell temperature 250.5 K is below limit 263 K");
+///
+/// // Boxed as any other error.
+/// let err: Box<dyn std::error::Error + Send + Sync> = command(Quantity::new(9.0)).unwrap_err().into();
+/// assert!(err.is::<LimitError>());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LimitError {
+    /// What the quantity is, such as `"commanded velocity"`.
+    pub what: Cow<'static, str>,
+    /// The quantity that was outside the limit.
+    pub value: DisplayWithUnit,
+    /// The limit it was outside.
+    pub limit: DisplayWithUnit,
+    /// Whether the limit was an upper or a lower bound.
+    pub bound: Bound,
+}
+
+/// Which side of a [`LimitError`] the quantity was on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bound {
+    /// The quantity was above the limit.
+    Upper,
+    /// The quantity was below the limit.
+    Lower,
+}
+
+impl LimitError {
+    /// The error for `value` being above `limit`.
+    pub fn exceeds<T, U>(
+        what: impl Into<Cow<'static, str>>,
+        value: Quantity<T, U>,
+        limit: Quantity<T, U>,
+    ) -> Self
+    where
+        T: fmt::Display,
+        U: UnitInfo,
+    {
+        LimitError {
+            what: what.into(),
+            value: value.into(),
+            limit: limit.into(),
+            bound: Bound::Upper,
+        }
+    }
+
+    /// The error for `value` being below `limit`.
+    pub fn below<T, U>(
+        what: impl Into<Cow<'static, str>>,
+        value: Quantity<T, U>,
+        limit: Quantity<T, U>,
+    ) -> Self
+    where
+        T: fmt::Display,
+        U: UnitInfo,
+    {
+        LimitError {
+            bound: Bound::Lower,
+            ..Self::exceeds(what, value, limit)
+        }
+    }
+
+    /// `value`, or an error if it's above `max`. NaN is never above it.
+    pub fn check_max<T, U>(
+        what: impl Into<Cow<'static, str>>,
+        value: Quantity<T, U>,
+        max: Quantity<T, U>,
+    ) -> Result<Quantity<T, U>, Self>
+    where
+        T: PartialOrd + fmt::Display,
+        U: UnitInfo,
+    {
+        if value.val > max.val {
+            Err(Self::exceeds(what, value, max))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// `value`, or an error if it's below `min`. NaN is never below it.
+    pub fn check_min<T, U>(
+        what: impl Into<Cow<'static, str>>,
+        value: Quantity<T, U>,
+        min: Quantity<T, U>,
+    ) -> Result<Quantity<T, U>, Self>
+    where
+        T: PartialOrd + fmt::Display,
+        U: UnitInfo,
+    {
+        if value.val < min.val {
+            Err(Self::below(what, value, min))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let relation = match self.bound {
+            Bound::Upper => "exceeds",
+            Bound::Lower => "is below",
+        };
+        write!(
+            f,
+            "{} {} {} limit {}",
+            self.what, self.value, relation, self.limit
+        )
+    }
+}
+
+impl std::error::Error for LimitError {}
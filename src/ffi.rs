@@ -0,0 +1,348 @@
+//! `#[repr(C)]` views of quantities, for passing them across a C ABI.
+//!
+//! A [`QuantityFfi`] carries a value along with a [`UnitDescriptor`]
+//! describing its unit, so the receiving side can check the unit instead of
+//! trusting it. Converting back into a [`Quantity`] fails if the dimension
+//! doesn't match and rescales if only the prefix differs.
+//!
+//! ```rust
+//! use uy::ffi::QuantityFfi;
+//! use uy::{si, Error, Quantity};
+//!
+//! let sent = QuantityFfi::from(Quantity::<f64, si::milli<si::A>>::new(250.0));
+//! assert_eq!(sent.unit.prefix, -3);
+//!
+//! let received: Quantity<f64, si::A> = sent.try_into().unwrap();
+//! assert_eq!(received.into_value(), 0.25);
+//!
+//! let wrong: Result<Quantity<f64, si::V>, _> = sent.try_into();
+//! assert!(matches!(wrong, Err(Error::DimensionMismatch { .. })));
+//! ```
+//!
+//! A [`PackedQuantityFfi`] is a smaller form, with the unit packed into a
+//! single `u64`, for units that aren't scaled by any factor besides a power
+//! of ten.
+//!
+//! The `extern "C"` functions in this module are declared in [`C_HEADER`],
+//! which can be written out by a build script for C callers.
+
+use crate::symbol::{unit_symbol, Style};
+use crate::{cgs, si, Error, Quantity, UnitInfo};
+
+/// The contents of `uy.h`, declaring the types and functions of this module
+/// for C.
+pub const C_HEADER: &str = include_str!("../include/uy.h");
+
+/// The most base units a [`UnitDescriptor`] can describe.
+pub const MAX_BASE_UNITS: usize = 8;
+
+/// The unit systems a [`UnitDescriptor`] can refer to, indexed by
+/// [`UnitDescriptor::system`].
+const SYSTEMS: &[&[&str]] = &[
+    <si::unitless as UnitInfo>::BASE_SYMBOLS,
+    <cgs::unitless as UnitInfo>::BASE_SYMBOLS,
+];
+
+/// A unit, as its system, scale, and the exponent of each base unit.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitDescriptor {
+    /// `0` for SI units and `1` for CGS units.
+    pub system: u8,
+    /// The power of ten the unit is scaled by.
+    pub prefix: i8,
+    /// Any other factor the unit is scaled by, such as 9.80665 for the
+    /// kilogram-force, or `1.0`.
+    pub factor: f64,
+    /// The exponent of each base unit, in the system's declaration order,
+    /// with unused entries set to zero.
+    pub dimension: [i8; MAX_BASE_UNITS],
+}
+
+impl UnitDescriptor {
+    /// The descriptor of the unit `U`.
+    ///
+    /// Panics if `U` belongs to a unit system without a descriptor.
+    pub fn of<U: UnitInfo>() -> Self {
+        let system = SYSTEMS
+            .iter()
+            .position(|&base| base == U::BASE_SYMBOLS)
+            .expect("unit system has no FFI descriptor");
+        let mut dimension = [0; MAX_BASE_UNITS];
+        dimension[..U::DIMENSION.len()].copy_from_slice(U::DIMENSION);
+        Self {
+            system: system as u8,
+            prefix: U::PREFIX,
+            factor: U::FACTOR.to_f64(),
+            dimension,
+        }
+    }
+
+    /// Whether both units measure the same dimension in the same system.
+    pub fn same_dimension(&self, other: &Self) -> bool {
+        self.system == other.system && self.dimension == other.dimension
+    }
+
+    fn symbol(&self) -> String {
+        let symbol = match SYSTEMS.get(self.system as usize) {
+            Some(base) => unit_symbol(
+                base,
+                &self.dimension[..base.len()],
+                self.prefix,
+                Style::Unicode,
+            ),
+            None => return format!("unit of unknown system {}", self.system),
+        };
+        if self.factor == 1.0 {
+            symbol.as_str().to_owned()
+        } else {
+            format!("{}·{}", self.factor, symbol.as_str())
+        }
+    }
+}
+
+/// The bits of each base unit exponent in a [`PackedUnit`].
+const EXPONENT_BITS: u32 = 6;
+const EXPONENT_MASK: u64 = (1 << EXPONENT_BITS) - 1;
+const PREFIX_SHIFT: u32 = 48;
+const SYSTEM_SHIFT: u32 = 56;
+
+/// A [`UnitDescriptor`] packed into 64 bits, so two units can be compared
+/// with a single integer comparison.
+///
+/// From the lowest bits up, it holds each base unit exponent in six bits of
+/// two's complement, the prefix in eight, and the system in the top eight.
+/// Only units with a factor of `1.0` and exponents from -32 to 31 can be
+/// packed.
+///
+/// ```rust
+/// use uy::ffi::{PackedUnit, UnitDescriptor, MAX_BASE_UNITS};
+/// use uy::si;
+///
+/// let packed = UnitDescriptor::of::<si::kilo<si::W>>().pack().unwrap();
+/// assert_eq!(packed.unpack(), UnitDescriptor::of::<si::kilo<si::W>>());
+/// assert_eq!(packed.prefix(), 3);
+/// assert!(packed.same_dimension(PackedUnit::of::<si::W>()));
+/// assert!(!packed.same_dimension(PackedUnit::of::<si::J>()));
+///
+/// // Every exponent, prefix, and system round-trips through the packing.
+/// let mut unit = UnitDescriptor::of::<si::unitless>();
+/// for system in 0..=u8::MAX {
+///     unit.system = system;
+///     assert_eq!(unit.pack().unwrap().unpack(), unit);
+/// }
+/// for prefix in i8::MIN..=i8::MAX {
+///     unit.prefix = prefix;
+///     assert_eq!(unit.pack().unwrap().unpack(), unit);
+/// }
+/// for i in 0..MAX_BASE_UNITS {
+///     for exp in i8::MIN..=i8::MAX {
+///         let mut unit = unit;
+///         unit.dimension[i] = exp;
+///         let packed = unit.pack();
+///         assert_eq!(packed.is_some(), (-32..=31).contains(&exp));
+///         if let Some(packed) = packed {
+///             assert_eq!(packed.unpack(), unit);
+///         }
+///     }
+/// }
+///
+/// let mut kgf = UnitDescriptor::of::<si::N>();
+/// kgf.factor = 9.80665;
+/// assert_eq!(kgf.pack(), None);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedUnit(pub u64);
+
+impl PackedUnit {
+    /// The packed descriptor of the unit `U`.
+    ///
+    /// Panics if `U` belongs to a unit system without a descriptor, or if
+    /// it can't be packed.
+    pub fn of<U: UnitInfo>() -> Self {
+        UnitDescriptor::of::<U>()
+            .pack()
+            .expect("unit can't be packed")
+    }
+
+    /// The power of ten the unit is scaled by.
+    pub const fn prefix(self) -> i8 {
+        (self.0 >> PREFIX_SHIFT) as u8 as i8
+    }
+
+    /// Whether both units measure the same dimension in the same system.
+    pub const fn same_dimension(self, other: Self) -> bool {
+        (self.0 ^ other.0) & !(0xff << PREFIX_SHIFT) == 0
+    }
+
+    /// The descriptor the unit was packed from.
+    pub fn unpack(self) -> UnitDescriptor {
+        let mut dimension = [0; MAX_BASE_UNITS];
+        for (i, exp) in dimension.iter_mut().enumerate() {
+            // Shift the exponent to the top bits and back to sign extend it.
+            let bits = (self.0 >> (EXPONENT_BITS * i as u32)) as u8;
+            *exp = ((bits << (8 - EXPONENT_BITS)) as i8) >> (8 - EXPONENT_BITS);
+        }
+        UnitDescriptor {
+            system: (self.0 >> SYSTEM_SHIFT) as u8,
+            prefix: self.prefix(),
+            factor: 1.0,
+            dimension,
+        }
+    }
+}
+
+impl UnitDescriptor {
+    /// Pack the descriptor into 64 bits, or `None` if its factor isn't
+    /// `1.0` or an exponent is outside -32 to 31.
+    pub fn pack(&self) -> Option<PackedUnit> {
+        if self.factor != 1.0 {
+            return None;
+        }
+        let limit = 1 << (EXPONENT_BITS - 1);
+        let mut bits = 0;
+        for (i, &exp) in self.dimension.iter().enumerate() {
+            if !(-limit..limit).contains(&exp) {
+                return None;
+            }
+            bits |= (exp as u64 & EXPONENT_MASK) << (EXPONENT_BITS * i as u32);
+        }
+        bits |= u64::from(self.prefix as u8) << PREFIX_SHIFT;
+        bits |= u64::from(self.system) << SYSTEM_SHIFT;
+        Some(PackedUnit(bits))
+    }
+}
+
+/// A value tagged with a description of its unit.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuantityFfi {
+    pub value: f64,
+    pub unit: UnitDescriptor,
+}
+
+impl<U: UnitInfo> From<Quantity<f64, U>> for QuantityFfi {
+    fn from(q: Quantity<f64, U>) -> Self {
+        QuantityFfi {
+            value: q.val,
+            unit: UnitDescriptor::of::<U>(),
+        }
+    }
+}
+
+/// Rescales by the difference of the prefixes, which can be anywhere in
+/// `-255..=255` since the sender chooses its own.
+///
+/// ```rust
+/// use uy::ffi::QuantityFfi;
+/// use uy::{si, Quantity};
+///
+/// let mut sent = QuantityFfi::from(Quantity::<f64, si::m>::new(1.0));
+/// sent.unit.prefix = 127;
+/// let received: Quantity<f64, si::deci<si::m>> = sent.try_into().unwrap();
+/// assert!((received.into_value() / 1e128 - 1.0).abs() < 1e-12);
+/// ```
+impl<U: UnitInfo> TryFrom<QuantityFfi> for Quantity<f64, U> {
+    type Error = Error;
+
+    fn try_from(q: QuantityFfi) -> Result<Self, Error> {
+        let expected = UnitDescriptor::of::<U>();
+        if !q.unit.same_dimension(&expected) {
+            return Err(Error::DimensionMismatch {
+                expected: U::SYMBOL.to_string(),
+                found: q.unit.symbol(),
+            });
+        }
+        let value = q.value * q.unit.factor / expected.factor
+            * 10f64.powi(i32::from(q.unit.prefix) - i32::from(expected.prefix));
+        Ok(Quantity::new(value))
+    }
+}
+
+/// A value tagged with its packed unit, in 16 bytes.
+///
+/// ```rust
+/// use uy::ffi::{PackedQuantityFfi, QuantityFfi};
+/// use uy::{si, Quantity};
+///
+/// assert_eq!(std::mem::size_of::<PackedQuantityFfi>(), 16);
+///
+/// let sent = QuantityFfi::from(Quantity::<f64, si::milli<si::s>>::new(1500.0))
+///     .pack()
+///     .unwrap();
+/// let received: Quantity<f64, si::s> = sent.try_into().unwrap();
+/// assert_eq!(received.into_value(), 1.5);
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PackedQuantityFfi {
+    pub value: f64,
+    pub unit: PackedUnit,
+}
+
+impl QuantityFfi {
+    /// Pack the unit into 64 bits, or `None` if it can't be packed. See
+    /// [`UnitDescriptor::pack`].
+    pub fn pack(&self) -> Option<PackedQuantityFfi> {
+        Some(PackedQuantityFfi {
+            value: self.value,
+            unit: self.unit.pack()?,
+        })
+    }
+}
+
+impl From<PackedQuantityFfi> for QuantityFfi {
+    fn from(q: PackedQuantityFfi) -> Self {
+        QuantityFfi {
+            value: q.value,
+            unit: q.unit.unpack(),
+        }
+    }
+}
+
+impl<U: UnitInfo> TryFrom<PackedQuantityFfi> for Quantity<f64, U> {
+    type Error = Error;
+
+    fn try_from(q: PackedQuantityFfi) -> Result<Self, Error> {
+        QuantityFfi::from(q).try_into()
+    }
+}
+
+/// Whether two units measure the same dimension in the same system.
+#[no_mangle]
+pub extern "C" fn uy_same_dimension(a: UnitDescriptor, b: UnitDescriptor) -> bool {
+    a.same_dimension(&b)
+}
+
+/// The value of `q` in the unit `to`, or NaN if the dimensions don't match.
+#[no_mangle]
+pub extern "C" fn uy_convert(q: QuantityFfi, to: UnitDescriptor) -> f64 {
+    if !q.unit.same_dimension(&to) {
+        return f64::NAN;
+    }
+    q.value * q.unit.factor / to.factor
+        * 10f64.powi(i32::from(q.unit.prefix) - i32::from(to.prefix))
+}
+
+/// Pack `unit` into `out`, returning whether it could be packed.
+///
+/// # Safety
+///
+/// `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uy_pack_unit(unit: UnitDescriptor, out: *mut u64) -> bool {
+    match unit.pack() {
+        Some(packed) => {
+            out.write(packed.0);
+            true
+        }
+        None => false,
+    }
+}
+
+/// The descriptor a unit was packed from.
+#[no_mangle]
+pub extern "C" fn uy_unpack_unit(unit: u64) -> UnitDescriptor {
+    PackedUnit(unit).unpack()
+}
@@ -0,0 +1,111 @@
+//! Formatting integer quantities in another prefix of their unit without
+//! floating-point math, for targets without an FPU.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use num_traits::PrimInt;
+
+use crate::{fmt_with_symbol, BaseOf, Quantity, UnitInfo, UnitScale};
+
+/// An integer quantity displayed in the unit `Y`, with as many decimal places
+/// as the prefixes of the units differ by. Created by
+/// [`Quantity::display_fixed`].
+///
+/// The value is only ever shifted by powers of ten as digits, so nothing is
+/// lost and no floating-point math is used. A precision rounds to that many
+/// decimal places, half away from zero.
+///
+/// ```rust
+/// use uy::{si, Quantity};
+///
+/// let reading: Quantity<i32, si::micro<si::A>> = Quantity::new(1234);
+/// assert_eq!(reading.display_fixed::<si::milli<si::A>>().to_string(), "1.234 mA");
+/// assert_eq!(format!("{:.2}", reading.display_fixed::<si::milli<si::A>>()), "1.23 mA");
+/// assert_eq!(reading.display_fixed::<si::A>().to_string(), "0.001234 A");
+///
+/// let drop: Quantity<i16, si::milli<si::A>> = Quantity::new(-5);
+/// assert_eq!(drop.display_fixed::<si::A>().to_string(), "-0.005 A");
+/// assert_eq!(format!("{:.2}", drop.display_fixed::<si::A>()), "-0.01 A");
+///
+/// let span: Quantity<u8, si::kilo<si::m>> = Quantity::new(12);
+/// assert_eq!(format!("{:>10}", span.display_fixed::<si::m>()), "   12000 m");
+/// ```
+///
+/// Only prefixes of the same unit can be displayed this way:
+///
+/// ```rust,compile_fail
+/// use uy::{si, Quantity};
+///
+/// let reading: Quantity<i32, si::micro<si::A>> = Quantity::new(1234);
+/// reading.display_fixed::<si::milli<si::V>>();
+/// ```
+pub struct DisplayFixed<T, U, Y> {
+    val: T,
+    _marker: PhantomData<(U, Y)>,
+}
+
+impl<T: PrimInt, U: UnitScale + UnitInfo> Quantity<T, U> {
+    /// Display the quantity in `Y`, a unit with a different prefix, using
+    /// only integer math. See [`DisplayFixed`].
+    pub fn display_fixed<Y>(self) -> DisplayFixed<T, U, Y>
+    where
+        Y: UnitScale<Base = BaseOf<U>> + UnitInfo,
+    {
+        DisplayFixed {
+            val: self.val,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: PrimInt, U: UnitInfo, Y: UnitInfo> fmt::Display for DisplayFixed<T, U, Y> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (negative, mut magnitude) = match self.val.to_i128() {
+            Some(v) => (v < 0, v.unsigned_abs()),
+            None => (false, self.val.to_u128().unwrap()),
+        };
+        let shift = i32::from(U::PREFIX) - i32::from(Y::PREFIX);
+        let mut decimals = (-shift).max(0) as usize;
+        if let Some(precision) = f.precision().filter(|&p| p < decimals) {
+            magnitude = round_off(magnitude, (decimals - precision) as u32);
+            decimals = precision;
+        }
+
+        let mut digits = magnitude.to_string();
+        if shift > 0 && magnitude != 0 {
+            digits.extend(std::iter::repeat_n('0', shift as usize));
+        }
+        if decimals > 0 {
+            if digits.len() <= decimals {
+                let zeros = decimals + 1 - digits.len();
+                digits.insert_str(0, &"0".repeat(zeros));
+            }
+            digits.insert(digits.len() - decimals, '.');
+        }
+        if let Some(precision) = f.precision().filter(|&p| p > decimals) {
+            if decimals == 0 {
+                digits.push('.');
+            }
+            digits.extend(std::iter::repeat_n('0', precision - decimals));
+        }
+        if negative && magnitude != 0 {
+            digits.insert(0, '-');
+        }
+        fmt_with_symbol(f, &digits, Y::SYMBOL)
+    }
+}
+
+impl<T: PrimInt, U: UnitInfo, Y: UnitInfo> fmt::Debug for DisplayFixed<T, U, Y> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Drop the last `digits` decimal digits of `magnitude`, rounding half up.
+fn round_off(magnitude: u128, digits: u32) -> u128 {
+    match 10u128.checked_pow(digits) {
+        Some(scale) => magnitude / scale + u128::from(magnitude % scale >= scale / 2),
+        None => 0,
+    }
+}
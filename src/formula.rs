@@ -0,0 +1,155 @@
+//! Functions defined by a formula over quantities, whose output unit is
+//! worked out from the formula.
+
+/// Define a function that evaluates a formula, with the unit it returns
+/// worked out from the units of its arguments and constants.
+///
+/// The arguments are written as `name: unit` and become quantities of any
+/// floating-point type `T` in that unit. The body may start with constants,
+/// written as `const NAME: unit = value;`, followed by the formula: products
+/// and quotients of the arguments, constants, and plain numbers, sums and
+/// differences of such terms, and parentheses. Every term of a sum must
+/// have the same unit, or the function won't compile.
+///
+/// ```rust
+/// use uy::{si, Div, Mul, Quantity};
+///
+/// uy::formula! {
+///     /// The kinetic energy of a mass moving at a speed.
+///     pub fn kinetic_energy(m: si::kg, v: Div<si::m, si::s>) {
+///         0.5 * m * v * v
+///     }
+/// }
+/// uy::formula! {
+///     /// Newton's law of universal gravitation.
+///     fn gravity(m1: si::kg, m2: si::kg, r: si::m) {
+///         const G: Div<Mul<si::N, Mul<si::m, si::m>>, Mul<si::kg, si::kg>> = 6.674e-11;
+///         G * m1 * m2 / (r * r)
+///     }
+/// }
+/// uy::formula! {
+///     fn displacement(v0: Div<si::m, si::s>, a: Div<si::m, Mul<si::s, si::s>>, t: si::s) {
+///         v0 * t + a * t * t / 2
+///     }
+/// }
+///
+/// let e: Quantity<f64, si::J> = kinetic_energy(Quantity::new(2.0), Quantity::new(3.0));
+/// assert_eq!(e.into_value(), 9.0);
+///
+/// let f: Quantity<f64, si::N> = gravity(Quantity::new(5.972e24), Quantity::new(70.0), Quantity::new(6.371e6));
+/// assert!((f.into_value() - 686.9).abs() < 0.5);
+///
+/// let d: Quantity<f32, si::m> = displacement(Quantity::new(1.0), Quantity::new(2.0), Quantity::new(3.0));
+/// assert_eq!(d.into_value(), 12.0);
+/// ```
+///
+/// Adding terms of different units is a compile-time error:
+///
+/// ```rust,compile_fail
+/// use uy::{si, Div};
+///
+/// uy::formula! {
+///     fn wrong(v: Div<si::m, si::s>, t: si::s) {
+///         v * t + t
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! formula {
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $unit:ty),* $(,)?) {
+            $($body:tt)+
+        }
+    ) => {
+        $crate::formula!(@consts [$(#[$attr])* $vis fn $name($($arg: $unit),*)] []; $($body)+);
+    };
+
+    (@consts [$($head:tt)*] [$($consts:tt)*]; const $c:ident: $cunit:ty = $cval:expr; $($rest:tt)+) => {
+        $crate::formula!(@consts [$($head)*] [$($consts)* ($c, $cunit, $cval)]; $($rest)+);
+    };
+    (
+        @consts [$(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident: $unit:ty),*)]
+        [$(($c:ident, $cunit:ty, $cval:expr))*]; $($body:tt)+
+    ) => {
+        $crate::__private::paste::paste! {
+            $(
+                #[allow(non_camel_case_types)]
+                type [<__ $name _ $arg>] = $unit;
+            )*
+            $(
+                #[allow(non_camel_case_types)]
+                type [<__ $name _ $c>] = $cunit;
+            )*
+
+            $(#[$attr])*
+            #[allow(non_snake_case)]
+            $vis fn $name<T: $crate::__private::num_traits::Float>(
+                $($arg: $crate::Quantity<T, $unit>),*
+            ) -> $crate::Quantity<T, $crate::formula!(@unit $name; $($body)+)> {
+                $(
+                    let $c = $crate::Quantity::<T, $cunit>::new(
+                        <T as $crate::__private::num_traits::NumCast>::from($cval).unwrap(),
+                    );
+                )*
+                $crate::formula!(@expr []; $($body)+)
+            }
+        }
+    };
+
+    // The unit of a formula is the unit of its first term.
+    (@unit $name:ident; $($t:tt)+) => {
+        $crate::formula!(@term $name; []; $($t)+)
+    };
+    (@term $name:ident; [$($acc:tt)+]; + $($rest:tt)*) => {
+        $crate::formula!(@product $name; $($acc)+)
+    };
+    (@term $name:ident; [$($acc:tt)+]; - $($rest:tt)*) => {
+        $crate::formula!(@product $name; $($acc)+)
+    };
+    (@term $name:ident; [$($acc:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::formula!(@term $name; [$($acc)* $next]; $($rest)*)
+    };
+    (@term $name:ident; [$($acc:tt)+];) => {
+        $crate::formula!(@product $name; $($acc)+)
+    };
+
+    (@product $name:ident; $first:tt $($rest:tt)*) => {
+        $crate::formula!(@fold $name; [$crate::formula!(@atom $name; $first)]; $($rest)*)
+    };
+    (@fold $name:ident; [$($u:tt)+]; * $next:tt $($rest:tt)*) => {
+        $crate::formula!(@fold $name; [$crate::Mul<$($u)+, $crate::formula!(@atom $name; $next)>]; $($rest)*)
+    };
+    (@fold $name:ident; [$($u:tt)+]; / $next:tt $($rest:tt)*) => {
+        $crate::formula!(@fold $name; [$crate::Div<$($u)+, $crate::formula!(@atom $name; $next)>]; $($rest)*)
+    };
+    (@fold $name:ident; [$($u:tt)+];) => {
+        $($u)+
+    };
+
+    (@atom $name:ident; ($($inner:tt)+)) => {
+        $crate::formula!(@unit $name; $($inner)+)
+    };
+    (@atom $name:ident; $lit:literal) => {
+        $crate::si::unitless
+    };
+    (@atom $name:ident; $id:ident) => {
+        $crate::__private::paste::paste!([<__ $name _ $id>])
+    };
+
+    // The formula itself, with numbers turned into dimensionless quantities.
+    (@expr [$($acc:tt)*];) => {
+        $($acc)*
+    };
+    (@expr [$($acc:tt)*]; ($($inner:tt)+) $($rest:tt)*) => {
+        $crate::formula!(@expr [$($acc)* ($crate::formula!(@expr []; $($inner)+))]; $($rest)*)
+    };
+    (@expr [$($acc:tt)*]; $lit:literal $($rest:tt)*) => {
+        $crate::formula!(@expr [$($acc)* $crate::Quantity::<T, $crate::si::unitless>::new(
+            <T as $crate::__private::num_traits::NumCast>::from($lit).unwrap(),
+        )]; $($rest)*)
+    };
+    (@expr [$($acc:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::formula!(@expr [$($acc)* $next]; $($rest)*)
+    };
+}
@@ -0,0 +1,137 @@
+//! Positions on the Earth as latitude and longitude, and the distances
+//! between them.
+//!
+//! [`Latitude`] and [`Longitude`] are angles in [`units::deg`] that can't be
+//! swapped for each other or for any other angle. A latitude must be within
+//! ±90°, and a longitude is normalized to `-180°..180°`:
+//!
+//! ```rust
+//! use uy::geodesy::{self, Latitude, Longitude, Position};
+//! use uy::{si, units, Quantity};
+//!
+//! let jfk = Position::new(Latitude::new(Quantity::new(40.6413)).unwrap(), Longitude::new(Quantity::new(-73.7781)));
+//! let lhr = Position::new(Latitude::new(Quantity::new(51.4700)).unwrap(), Longitude::new(Quantity::new(-0.4543)));
+//!
+//! let d: Quantity<f64, units::nmi> = geodesy::haversine(jfk, lhr).convert();
+//! assert!((d.into_value() - 2991.4).abs() < 0.1);
+//!
+//! // Eight hours at 480 knots.
+//! let cruise: Quantity<f64, units::kn> = Quantity::new(480.0);
+//! let hours: Quantity<f64, units::h> = Quantity::new(8.0);
+//! let flown: Quantity<f64, si::kilo<si::m>> = (cruise.convert::<uy::Div<si::m, si::s>>() * hours.convert::<si::s>()).convert();
+//! assert!((flown.into_value() - 7111.68).abs() < 1e-6);
+//!
+//! assert_eq!(Longitude::new(Quantity::new(190.0)).degrees().into_value(), -170.0);
+//! assert!(Latitude::new(Quantity::new(91.0)).is_err());
+//! ```
+//!
+//! ```rust,compile_fail
+//! use uy::geodesy::{Latitude, Longitude, Position};
+//! use uy::Quantity;
+//!
+//! let lon = Longitude::new(Quantity::new(-73.7781));
+//! let lat = Latitude::new(Quantity::new(40.6413)).unwrap();
+//! Position::new(lon, lat);
+//! ```
+
+use std::fmt;
+
+use num_traits::Float;
+
+use crate::si::quantities::{Angle, Length};
+use crate::{units, Error, Quantity};
+
+/// The mean radius of the Earth, 6 371 008.8 m, as used for great-circle
+/// distances.
+pub fn earth_radius<T: Float>() -> Length<T> {
+    Quantity::new(T::from(6_371_008.8).unwrap())
+}
+
+/// An angle north of the equator, or south if negative.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Latitude<T>(T);
+
+impl<T: Float + fmt::Display> Latitude<T> {
+    /// The latitude `degrees`, or an error if it isn't within ±90°.
+    pub fn new(degrees: Quantity<T, units::deg>) -> Result<Self, Error> {
+        let limit = T::from(90).unwrap();
+        if !(-limit..=limit).contains(&degrees.val) {
+            return Err(Error::OutOfRange {
+                value: degrees.to_string(),
+                min: Quantity::<T, units::deg>::new(-limit).to_string(),
+                max: Quantity::<T, units::deg>::new(limit).to_string(),
+            });
+        }
+        Ok(Self(degrees.val))
+    }
+}
+
+impl<T: Float> Latitude<T> {
+    /// The latitude in degrees.
+    pub fn degrees(self) -> Quantity<T, units::deg> {
+        Quantity::new(self.0)
+    }
+
+    /// The latitude in radians.
+    pub fn radians(self) -> Angle<T> {
+        Quantity::new(self.0.to_radians())
+    }
+}
+
+/// An angle east of the prime meridian, or west if negative.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Longitude<T>(T);
+
+impl<T: Float> Longitude<T> {
+    /// The longitude `degrees`, wrapped around to be within `-180°..180°`.
+    pub fn new(degrees: Quantity<T, units::deg>) -> Self {
+        let half_turn = T::from(180).unwrap();
+        let turn = half_turn + half_turn;
+        let wrapped = (degrees.val + half_turn) % turn;
+        let wrapped = if wrapped < T::zero() {
+            wrapped + turn
+        } else {
+            wrapped
+        };
+        Self(wrapped - half_turn)
+    }
+
+    /// The longitude in degrees.
+    pub fn degrees(self) -> Quantity<T, units::deg> {
+        Quantity::new(self.0)
+    }
+
+    /// The longitude in radians.
+    pub fn radians(self) -> Angle<T> {
+        Quantity::new(self.0.to_radians())
+    }
+}
+
+/// A point on the surface of the Earth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position<T> {
+    pub latitude: Latitude<T>,
+    pub longitude: Longitude<T>,
+}
+
+impl<T> Position<T> {
+    pub const fn new(latitude: Latitude<T>, longitude: Longitude<T>) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+}
+
+/// The great-circle distance between `a` and `b`, taking the Earth to be a
+/// sphere of [`earth_radius`]. This is within 0.5% of the distance on the
+/// WGS 84 ellipsoid.
+pub fn haversine<T: Float>(a: Position<T>, b: Position<T>) -> Length<T> {
+    let two = T::one() + T::one();
+    let (lat_a, lat_b) = (a.latitude.radians().val, b.latitude.radians().val);
+    let d_lat = lat_b - lat_a;
+    let d_lon = b.longitude.radians().val - a.longitude.radians().val;
+    let h = (d_lat / two).sin().powi(2) + lat_a.cos() * lat_b.cos() * (d_lon / two).sin().powi(2);
+    let central = two * h.sqrt().min(T::one()).asin();
+    Quantity::new(earth_radius::<T>().val * central)
+}
@@ -0,0 +1,181 @@
+//! Points and extents whose components are quantities of the same unit.
+//!
+//! Subtracting two points gives the extent between them, and adding an
+//! extent to a point moves it:
+//!
+//! ```rust
+//! use uy::geom::{Extent2, Point2};
+//! use uy::{si, Quantity};
+//!
+//! let a: Point2<f64, si::milli<si::m>> = Point2::new(Quantity::new(10.0), Quantity::new(20.0));
+//! let b = Point2::new(Quantity::new(40.0), Quantity::new(60.0));
+//!
+//! let size: Extent2<f64, si::milli<si::m>> = b - a;
+//! assert_eq!(size.width.into_value(), 30.0);
+//! assert_eq!(a.distance(b).into_value(), 50.0);
+//!
+//! let area: Quantity<f64, uy::Mul<si::milli<si::m>, si::milli<si::m>>> = size.area();
+//! assert_eq!(area.into_value(), 1200.0);
+//!
+//! let c = a + size.scale(0.5);
+//! assert_eq!(c.y.into_value(), 40.0);
+//! ```
+
+use std::{fmt, ops};
+
+use crate::{Mul, Quantity, Unit, UnitInfo};
+
+macro_rules! geom_types {
+    ($point:ident, $extent:ident, $dims:literal, $($coord:ident => $size:ident),*) => {
+        #[doc = concat!("A point in ", $dims, " dimensions.")]
+        #[derive(Clone, Copy)]
+        #[cfg_attr(feature = "encase", derive(::encase::ShaderType))]
+        pub struct $point<T, U: Unit> {
+            $(pub $coord: Quantity<T, U>,)*
+        }
+
+        #[doc = concat!("The size of a region, or the displacement between two points, in ", $dims, " dimensions.")]
+        #[derive(Clone, Copy)]
+        #[cfg_attr(feature = "encase", derive(::encase::ShaderType))]
+        pub struct $extent<T, U: Unit> {
+            $(pub $size: Quantity<T, U>,)*
+        }
+
+        impl<T, U: Unit> $point<T, U> {
+            pub fn new($($coord: Quantity<T, U>),*) -> Self {
+                Self { $($coord),* }
+            }
+
+            /// Scale each coordinate by `factor`, about the origin.
+            pub fn scale(self, factor: T) -> Self
+            where
+                T: Clone + ops::Mul<Output = T>,
+            {
+                Self { $($coord: Quantity::new(self.$coord.val * factor.clone())),* }
+            }
+
+            /// The straight-line distance between two points.
+            pub fn distance(self, other: Self) -> Quantity<T, U>
+            where
+                T: num_traits::Float,
+            {
+                (other - self).length()
+            }
+        }
+
+        impl<T, U: Unit> $extent<T, U> {
+            pub fn new($($size: Quantity<T, U>),*) -> Self {
+                Self { $($size),* }
+            }
+
+            /// Scale each component by `factor`.
+            pub fn scale(self, factor: T) -> Self
+            where
+                T: Clone + ops::Mul<Output = T>,
+            {
+                Self { $($size: Quantity::new(self.$size.val * factor.clone())),* }
+            }
+
+            /// The Euclidean length of the extent, as a displacement.
+            pub fn length(self) -> Quantity<T, U>
+            where
+                T: num_traits::Float,
+            {
+                let sum = T::zero() $(+ self.$size.val * self.$size.val)*;
+                Quantity::new(sum.sqrt())
+            }
+        }
+
+        impl<T: ops::Add<Output = T>, U: Unit> ops::Add<$extent<T, U>> for $point<T, U> {
+            type Output = Self;
+
+            fn add(self, rhs: $extent<T, U>) -> Self {
+                Self { $($coord: self.$coord + rhs.$size),* }
+            }
+        }
+
+        impl<T: ops::Sub<Output = T>, U: Unit> ops::Sub<$extent<T, U>> for $point<T, U> {
+            type Output = Self;
+
+            fn sub(self, rhs: $extent<T, U>) -> Self {
+                Self { $($coord: self.$coord - rhs.$size),* }
+            }
+        }
+
+        impl<T: ops::Sub<Output = T>, U: Unit> ops::Sub for $point<T, U> {
+            type Output = $extent<T, U>;
+
+            fn sub(self, rhs: Self) -> $extent<T, U> {
+                $extent { $($size: self.$coord - rhs.$coord),* }
+            }
+        }
+
+        impl<T: ops::Add<Output = T>, U: Unit> ops::Add for $extent<T, U> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($size: self.$size + rhs.$size),* }
+            }
+        }
+
+        impl<T: ops::Sub<Output = T>, U: Unit> ops::Sub for $extent<T, U> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($size: self.$size - rhs.$size),* }
+            }
+        }
+
+        impl<T: fmt::Debug, U: UnitInfo> fmt::Debug for $point<T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($point))
+                    $(.field(stringify!($coord), &self.$coord))*
+                    .finish()
+            }
+        }
+
+        impl<T: fmt::Debug, U: UnitInfo> fmt::Debug for $extent<T, U> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($extent))
+                    $(.field(stringify!($size), &self.$size))*
+                    .finish()
+            }
+        }
+    };
+}
+
+geom_types!(Point2, Extent2, "two", x => width, y => height);
+geom_types!(Point3, Extent3, "three", x => width, y => height, z => depth);
+
+impl<T, U: Unit> Extent2<T, U> {
+    /// The area covered by the extent.
+    pub fn area(self) -> Quantity<T, Mul<U, U>>
+    where
+        T: ops::Mul<Output = T>,
+        U: ops::Mul<U>,
+        Mul<U, U>: Unit,
+    {
+        self.width * self.height
+    }
+}
+
+impl<T, U: Unit> Extent3<T, U> {
+    /// The volume covered by the extent.
+    ///
+    /// ```rust
+    /// use uy::geom::Extent3;
+    /// use uy::{si, Quantity};
+    ///
+    /// let box_: Extent3<i32, si::m> = Extent3::new(Quantity::new(2), Quantity::new(3), Quantity::new(4));
+    /// assert_eq!(box_.volume().to_string(), "24 m³");
+    /// ```
+    pub fn volume(self) -> Quantity<T, Mul<Mul<U, U>, U>>
+    where
+        T: ops::Mul<Output = T>,
+        U: ops::Mul<U>,
+        Mul<U, U>: Unit + ops::Mul<U>,
+        Mul<Mul<U, U>, U>: Unit,
+    {
+        self.width * self.height * self.depth
+    }
+}
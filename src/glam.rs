@@ -0,0 +1,100 @@
+//! Support for [`glam`](https://docs.rs/glam) vectors as the value of a
+//! quantity, so kinematics code keeps its units.
+//!
+//! ```rust
+//! use glam::Vec3;
+//! use uy::{si, Quantity};
+//!
+//! let r: Quantity<Vec3, si::m> = Quantity::new(Vec3::new(0.0, 2.0, 0.0));
+//! let f: Quantity<Vec3, si::N> = Quantity::new(Vec3::new(3.0, 0.0, 0.0));
+//! let torque: Quantity<Vec3, uy::Mul<si::m, si::N>> = r.cross(f);
+//! assert_eq!(torque.into_value(), Vec3::new(0.0, 0.0, -6.0));
+//!
+//! let d: Quantity<Vec3, si::kilo<si::m>> = Quantity::new(Vec3::new(3.0, 4.0, 0.0));
+//! assert_eq!(d.length().into_value(), 5.0);
+//! assert_eq!(d.convert::<si::m>().x().into_value(), 3000.0);
+//! ```
+
+use ::glam::{DVec2, DVec3, DVec4, Vec2, Vec3, Vec4};
+
+use crate::{ExactPowerOfTen, Mul, MulPowerOfTen, Quantity, Unit};
+
+macro_rules! impl_glam {
+    ($($vec:ident($scalar:ident) { $($axis:ident),* }),*) => {
+        $(
+            /// Scales every component.
+            impl MulPowerOfTen for $vec {
+                fn mul_power_of_ten(self, exp: i8) -> Self {
+                    self * (10 as $scalar).powi(-exp as i32)
+                }
+            }
+
+            impl<const FROM: i8, const TO: i8> ExactPowerOfTen<FROM, TO> for $vec {}
+
+            impl<U: Unit> Quantity<$vec, U> {
+                /// Create a vector quantity from its components.
+                pub fn from_components($($axis: Quantity<$scalar, U>),*) -> Self {
+                    Quantity::new($vec::new($($axis.val),*))
+                }
+
+                $(
+                    #[doc = concat!("The `", stringify!($axis), "` component.")]
+                    pub fn $axis(&self) -> Quantity<$scalar, U> {
+                        Quantity::new(self.val.$axis)
+                    }
+                )*
+
+                /// The length of the vector.
+                pub fn length(self) -> Quantity<$scalar, U> {
+                    Quantity::new(self.val.length())
+                }
+
+                /// The dot product of two vectors.
+                pub fn dot<U2: Unit>(self, rhs: Quantity<$vec, U2>) -> Quantity<$scalar, Mul<U, U2>>
+                where
+                    U: std::ops::Mul<U2>,
+                    Mul<U, U2>: Unit,
+                {
+                    Quantity::new(self.val.dot(rhs.val))
+                }
+
+                /// Multiply every component by a scalar quantity.
+                pub fn scale<U2: Unit>(self, rhs: Quantity<$scalar, U2>) -> Quantity<$vec, Mul<U, U2>>
+                where
+                    U: std::ops::Mul<U2>,
+                    Mul<U, U2>: Unit,
+                {
+                    Quantity::new(self.val * rhs.val)
+                }
+            }
+        )*
+    };
+}
+
+impl_glam!(
+    Vec2(f32) { x, y },
+    Vec3(f32) { x, y, z },
+    Vec4(f32) { x, y, z, w },
+    DVec2(f64) { x, y },
+    DVec3(f64) { x, y, z },
+    DVec4(f64) { x, y, z, w }
+);
+
+macro_rules! impl_cross {
+    ($($vec:ident),*) => {
+        $(
+            impl<U: Unit> Quantity<$vec, U> {
+                /// The cross product of two vectors.
+                pub fn cross<U2: Unit>(self, rhs: Quantity<$vec, U2>) -> Quantity<$vec, Mul<U, U2>>
+                where
+                    U: std::ops::Mul<U2>,
+                    Mul<U, U2>: Unit,
+                {
+                    Quantity::new(self.val.cross(rhs.val))
+                }
+            }
+        )*
+    };
+}
+
+impl_cross!(Vec3, DVec3);
@@ -0,0 +1,40 @@
+//! Quantities stored in 16-bit floats, [`f16`] and [`bf16`].
+//!
+//! Unit conversions are computed in `f32` and rounded once, since powers of
+//! ten beyond `10⁴` overflow an `f16`:
+//!
+//! ```rust
+//! use half::{bf16, f16};
+//! use uy::{si, Quantity};
+//!
+//! let a: Quantity<f16, si::kilo<si::m>> = Quantity::new(f16::from_f32(1.5));
+//! let b: Quantity<f16, si::m> = a.convert();
+//! assert_eq!(b.into_value(), f16::from_f32(1500.0));
+//!
+//! let c: Quantity<bf16, si::micro<si::A>> = Quantity::new(bf16::from_f32(250.0));
+//! let d: Quantity<bf16, si::milli<si::A>> = c.convert();
+//! assert_eq!(d.into_value(), bf16::from_f32(0.25));
+//! assert_eq!((d + d).to_string(), "0.5 mA");
+//! ```
+
+use ::half::{bf16, f16};
+
+use crate::{ExactPowerOfTen, MulPowerOfTen};
+
+impl MulPowerOfTen for f16 {
+    #[inline]
+    fn mul_power_of_ten(self, exp: i8) -> Self {
+        f16::from_f32(self.to_f32().mul_power_of_ten(exp))
+    }
+}
+
+impl MulPowerOfTen for bf16 {
+    #[inline]
+    fn mul_power_of_ten(self, exp: i8) -> Self {
+        bf16::from_f32(self.to_f32().mul_power_of_ten(exp))
+    }
+}
+
+impl<const FROM: i8, const TO: i8> ExactPowerOfTen<FROM, TO> for f16 {}
+
+impl<const FROM: i8, const TO: i8> ExactPowerOfTen<FROM, TO> for bf16 {}
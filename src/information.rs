@@ -0,0 +1,246 @@
+//! A unit system for amounts of data and data rates, with bytes as a base
+//! unit alongside seconds.
+//!
+//! Sizes and bandwidths take SI prefixes on either side of a rate, and bits
+//! and the binary prefixes are scaled units. The seconds of the system are
+//! SI seconds, so bytes multiply and divide by units of time from [`si`],
+//! and times convert to and from them:
+//!
+//! ```rust
+//! use uy::information::{self, B, KiB, Mbps};
+//! use uy::{si, Quantity};
+//!
+//! let link: Quantity<u64, uy::Div<si::mega<B>, si::s>> = Quantity::new(125);
+//! let minute: Quantity<u64, si::s> = Quantity::new(60);
+//! let sent: Quantity<u64, si::giga<B>> = (link * minute).convert_lossy();
+//! assert_eq!(sent.into_value(), 7);
+//!
+//! let ethernet: Quantity<f64, Mbps> = Quantity::new(100.0);
+//! let rate: Quantity<f64, uy::Div<si::mega<B>, si::s>> = ethernet.convert();
+//! assert_eq!(rate.into_value(), 12.5);
+//!
+//! let page: Quantity<f64, KiB> = Quantity::new(4.0);
+//! let bytes: Quantity<f64, B> = page.convert();
+//! assert_eq!(bytes.into_value(), 4096.0);
+//!
+//! let image: Quantity<f64, si::giga<B>> = Quantity::new(4.7);
+//! let t: Quantity<f64, si::s> = information::time_to_transfer(image, ethernet).convert();
+//! assert!((t.into_value() - 376.0).abs() < 1e-9);
+//! ```
+//!
+//! Only units of time from [`si`] combine with bytes:
+//!
+//! ```rust,compile_fail
+//! use uy::information::B;
+//! use uy::{si, Quantity};
+//!
+//! let size: Quantity<u64, B> = Quantity::new(1024);
+//! let d: Quantity<u64, si::m> = Quantity::new(2);
+//! let _ = size / d;
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use num_traits::Float;
+
+use crate::{Div, Quantity, SameDimension};
+
+mod inner {
+    use std::marker::PhantomData;
+    use std::ops;
+
+    use crate::__private::{Const, ToConst};
+    use crate::si::Si;
+    use crate::{ExactPowerOfTen, MulPowerOfTen, UnitConvertExact, UnitConvertLossy};
+
+    crate::power_of_ten_unit_system!(Information { s = "second", B = "byte" });
+    crate::scaled_unit::scaled_unit_conversions!(Information { s, B });
+
+    // Seconds in the SI and in this unit system are the same, so units that
+    // are only a power of seconds combine with units of information and
+    // convert to and from them.
+
+    impl<const EXP1: i8, const EXP2: i8, const S1: i8, const S2: i8, const BYTES: i8>
+        ops::Mul<Si<EXP2, S2, 0, 0, 0, 0, 0, 0, 0>> for Information<EXP1, S1, BYTES>
+    where
+        Const<EXP1>: ops::Add<Const<EXP2>>,
+        Const<S1>: ops::Add<Const<S2>>,
+        TypenumInformation<
+            <Const<EXP1> as ops::Add<Const<EXP2>>>::Output,
+            <Const<S1> as ops::Add<Const<S2>>>::Output,
+            Const<BYTES>,
+        >: ToConst,
+    {
+        type Output = <TypenumInformation<
+            <Const<EXP1> as ops::Add<Const<EXP2>>>::Output,
+            <Const<S1> as ops::Add<Const<S2>>>::Output,
+            Const<BYTES>,
+        > as ToConst>::Output;
+
+        #[inline]
+        fn mul(self, _rhs: Si<EXP2, S2, 0, 0, 0, 0, 0, 0, 0>) -> Self::Output {
+            ToConst::to_const(TypenumInformation(PhantomData))
+        }
+    }
+
+    impl<const EXP1: i8, const EXP2: i8, const S1: i8, const S2: i8, const BYTES: i8>
+        ops::Mul<Information<EXP2, S2, BYTES>> for Si<EXP1, S1, 0, 0, 0, 0, 0, 0, 0>
+    where
+        Const<EXP1>: ops::Add<Const<EXP2>>,
+        Const<S1>: ops::Add<Const<S2>>,
+        TypenumInformation<
+            <Const<EXP1> as ops::Add<Const<EXP2>>>::Output,
+            <Const<S1> as ops::Add<Const<S2>>>::Output,
+            Const<BYTES>,
+        >: ToConst,
+    {
+        type Output = <TypenumInformation<
+            <Const<EXP1> as ops::Add<Const<EXP2>>>::Output,
+            <Const<S1> as ops::Add<Const<S2>>>::Output,
+            Const<BYTES>,
+        > as ToConst>::Output;
+
+        #[inline]
+        fn mul(self, _rhs: Information<EXP2, S2, BYTES>) -> Self::Output {
+            ToConst::to_const(TypenumInformation(PhantomData))
+        }
+    }
+
+    impl<const EXP1: i8, const EXP2: i8, const S1: i8, const S2: i8, const BYTES: i8>
+        ops::Div<Si<EXP2, S2, 0, 0, 0, 0, 0, 0, 0>> for Information<EXP1, S1, BYTES>
+    where
+        Const<EXP1>: ops::Sub<Const<EXP2>>,
+        Const<S1>: ops::Sub<Const<S2>>,
+        TypenumInformation<
+            <Const<EXP1> as ops::Sub<Const<EXP2>>>::Output,
+            <Const<S1> as ops::Sub<Const<S2>>>::Output,
+            Const<BYTES>,
+        >: ToConst,
+    {
+        type Output = <TypenumInformation<
+            <Const<EXP1> as ops::Sub<Const<EXP2>>>::Output,
+            <Const<S1> as ops::Sub<Const<S2>>>::Output,
+            Const<BYTES>,
+        > as ToConst>::Output;
+
+        #[inline]
+        fn div(self, _rhs: Si<EXP2, S2, 0, 0, 0, 0, 0, 0, 0>) -> Self::Output {
+            ToConst::to_const(TypenumInformation(PhantomData))
+        }
+    }
+
+    impl<T, const EXP1: i8, const EXP2: i8, const S: i8>
+        UnitConvertLossy<T, Si<EXP1, S, 0, 0, 0, 0, 0, 0, 0>> for Information<EXP2, S, 0>
+    where
+        T: MulPowerOfTen,
+    {
+        #[inline]
+        fn unit_convert_lossy(val: T) -> T {
+            val.mul_power_of_ten(EXP2 - EXP1)
+        }
+    }
+
+    impl<T, const EXP1: i8, const EXP2: i8, const S: i8>
+        UnitConvertExact<T, Si<EXP1, S, 0, 0, 0, 0, 0, 0, 0>> for Information<EXP2, S, 0>
+    where
+        T: ExactPowerOfTen<EXP1, EXP2>,
+    {
+        #[inline]
+        #[track_caller]
+        fn unit_convert(val: T) -> T {
+            val.strict_mul_power_of_ten(EXP2 - EXP1)
+        }
+    }
+
+    impl<T, const EXP1: i8, const EXP2: i8, const S: i8>
+        UnitConvertLossy<T, Information<EXP1, S, 0>> for Si<EXP2, S, 0, 0, 0, 0, 0, 0, 0>
+    where
+        T: MulPowerOfTen,
+    {
+        #[inline]
+        fn unit_convert_lossy(val: T) -> T {
+            val.mul_power_of_ten(EXP2 - EXP1)
+        }
+    }
+
+    impl<T, const EXP1: i8, const EXP2: i8, const S: i8>
+        UnitConvertExact<T, Information<EXP1, S, 0>> for Si<EXP2, S, 0, 0, 0, 0, 0, 0, 0>
+    where
+        T: ExactPowerOfTen<EXP1, EXP2>,
+    {
+        #[inline]
+        #[track_caller]
+        fn unit_convert(val: T) -> T {
+            val.strict_mul_power_of_ten(EXP2 - EXP1)
+        }
+    }
+}
+
+pub use self::base::*;
+pub use self::derived::*;
+pub use self::inner::{Dimension, Information};
+
+pub mod base {
+    //! Information base units.
+
+    use super::Information;
+
+    pub type unitless = Information<0, 0, 0>;
+    pub type s = Information<0, 1, 0>;
+    /// The byte, eight bits.
+    pub type B = Information<0, 0, 1>;
+}
+
+pub mod derived {
+    //! Information derived units.
+
+    use super::base::*;
+    use crate::scaled_unit::scaled_unit;
+    use crate::Div;
+
+    /// Bytes per second.
+    pub type Bps = Div<B, s>;
+
+    scaled_unit!(
+        /// The bit, 1/8 B.
+        bit = 1 / 8 B, "bit"
+    );
+    scaled_unit!(
+        /// Bits per second, 1/8 B·s⁻¹.
+        bps = 1 / 8 Bps, "bit/s"
+    );
+    scaled_unit!(
+        /// Megabits per second, 125 000 B·s⁻¹.
+        Mbps = 125000 / 1 Bps, "Mbit/s"
+    );
+    scaled_unit!(
+        /// Gigabits per second, 125 000 000 B·s⁻¹.
+        Gbps = 125000000 / 1 Bps, "Gbit/s"
+    );
+    scaled_unit!(
+        /// The kibibyte, 1024 B.
+        KiB = 1024 / 1 B, "KiB"
+    );
+    scaled_unit!(
+        /// The mebibyte, 1024² B.
+        MiB = 1048576 / 1 B, "MiB"
+    );
+    scaled_unit!(
+        /// The gibibyte, 1024³ B.
+        GiB = 1073741824 / 1 B, "GiB"
+    );
+}
+
+/// The time to transfer `size` at `rate`, for sizes and rates in any units,
+/// in seconds that convert to [`si::s`](crate::si::s).
+pub fn time_to_transfer<T, Size, Rate>(
+    size: Quantity<T, Size>,
+    rate: Quantity<T, Rate>,
+) -> Quantity<T, Div<B, Bps>>
+where
+    T: Float,
+    Size: SameDimension<B>,
+    Rate: SameDimension<Bps>,
+{
+    size.convert_generic::<B>() / rate.convert_generic::<Bps>()
+}
@@ -1,31 +1,49 @@
-use std::ops;
-use typenum;
+use std::{cmp, ops};
+
+use crate::Scalar;
 
 pub struct Const<const I: i8>;
 
-impl<const A: i8, const B: i8> ops::Add<Const<B>> for Const<A>
-where
-    Self: ToTypenum,
-    Const<B>: ToTypenum,
-    <Self as ToTypenum>::Output: ops::Add<<Const<B> as ToTypenum>::Output>,
-    <<Self as ToTypenum>::Output as ops::Add<<Const<B> as ToTypenum>::Output>>::Output: ToConst,
-{
-    type Output = <<<Self as ToTypenum>::Output as ops::Add<<Const<B> as ToTypenum>::Output>>::Output as ToConst>::Output;
-    fn add(self, rhs: Const<B>) -> Self::Output {
-        (self.to_typenum() + rhs.to_typenum()).to_const()
-    }
+/// Implement `Add` between every pair of exponents directly, with the sum
+/// computed by const evaluation, which is much less work for the trait
+/// solver than converting both sides to `typenum` integers and back. A sum
+/// outside -30..=30 is rejected when it's turned into a unit, by the
+/// [`ToTypenum`] bounds there.
+macro_rules! impl_const_add {
+    ($($a:literal),*) => {
+        impl_const_add!(@outer [$($a),*] [$($a),*]);
+    };
+    (@outer [$($a:literal),*] $b:tt) => {
+        $( impl_const_add!(@inner $a $b); )*
+    };
+    (@inner $a:literal [$($b:literal),*]) => {
+        $(
+            impl ops::Add<Const<{ $b }>> for Const<{ $a }> {
+                type Output = Const<{ $a + $b }>;
+                fn add(self, _: Const<{ $b }>) -> Self::Output {
+                    Const
+                }
+            }
+        )*
+    };
 }
 
+impl_const_add!(
+    -30, -29, -28, -27, -26, -25, -24, -23, -22, -21, -20, -19, -18, -17, -16, -15, -14, -13, -12,
+    -11, -10, -9, -8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+    15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30
+);
+
+/// Subtraction is addition of the negation, so it only needs the `Add` and
+/// `Neg` impls.
 impl<const A: i8, const B: i8> ops::Sub<Const<B>> for Const<A>
 where
-    Self: ToTypenum,
-    Const<B>: ToTypenum,
-    <Self as ToTypenum>::Output: ops::Sub<<Const<B> as ToTypenum>::Output>,
-    <<Self as ToTypenum>::Output as ops::Sub<<Const<B> as ToTypenum>::Output>>::Output: ToConst,
+    Const<B>: ops::Neg,
+    Self: ops::Add<<Const<B> as ops::Neg>::Output>,
 {
-    type Output = <<<Self as ToTypenum>::Output as ops::Sub<<Const<B> as ToTypenum>::Output>>::Output as ToConst>::Output;
+    type Output = <Self as ops::Add<<Const<B> as ops::Neg>::Output>>::Output;
     fn sub(self, rhs: Const<B>) -> Self::Output {
-        (self.to_typenum() - rhs.to_typenum()).to_const()
+        self + -rhs
     }
 }
 
@@ -55,23 +73,136 @@ where
     }
 }
 
-impl<const N: i8> ops::Neg for Const<N>
+impl<const A: i8, const B: i8> typenum::Min<Const<B>> for Const<A>
+where
+    Self: ToTypenum,
+    Const<B>: ToTypenum,
+    <Self as ToTypenum>::Output: typenum::Min<<Const<B> as ToTypenum>::Output>,
+    <<Self as ToTypenum>::Output as typenum::Min<<Const<B> as ToTypenum>::Output>>::Output: ToConst,
+{
+    type Output = <<<Self as ToTypenum>::Output as typenum::Min<<Const<B> as ToTypenum>::Output>>::Output as ToConst>::Output;
+    fn min(self, rhs: Const<B>) -> Self::Output {
+        typenum::Min::min(self.to_typenum(), rhs.to_typenum()).to_const()
+    }
+}
+
+impl<const A: i8, const B: i8> typenum::PartialDiv<Const<B>> for Const<A>
+where
+    Self: ToTypenum,
+    Const<B>: ToTypenum,
+    <Self as ToTypenum>::Output: typenum::PartialDiv<<Const<B> as ToTypenum>::Output>,
+    <<Self as ToTypenum>::Output as typenum::PartialDiv<<Const<B> as ToTypenum>::Output>>::Output:
+        ToConst,
+{
+    type Output = <<<Self as ToTypenum>::Output as typenum::PartialDiv<
+        <Const<B> as ToTypenum>::Output,
+    >>::Output as ToConst>::Output;
+    fn partial_div(self, rhs: Const<B>) -> Self::Output {
+        typenum::PartialDiv::partial_div(self.to_typenum(), rhs.to_typenum()).to_const()
+    }
+}
+
+/// Implement `Neg` for every exponent directly, like `Add`.
+macro_rules! impl_const_neg {
+    ($($a:literal),*) => {
+        $(
+            impl ops::Neg for Const<{ $a }> {
+                type Output = Const<{ -$a }>;
+                fn neg(self) -> Self::Output {
+                    Const
+                }
+            }
+        )*
+    };
+}
+
+impl_const_neg!(
+    -30, -29, -28, -27, -26, -25, -24, -23, -22, -21, -20, -19, -18, -17, -16, -15, -14, -13, -12,
+    -11, -10, -9, -8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+    15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30
+);
+
+/// `typenum::True` if `A == B`, otherwise `typenum::False`.
+pub trait Equal<B> {
+    type Output;
+}
+
+impl<const A: i8, const B: i8> Equal<Const<B>> for Const<A>
 where
     Self: ToTypenum,
-    <Self as ToTypenum>::Output: ops::Neg,
-    <<Self as ToTypenum>::Output as ops::Neg>::Output: ToConst,
+    Const<B>: ToTypenum,
+    <Self as ToTypenum>::Output: typenum::IsEqual<<Const<B> as ToTypenum>::Output>,
 {
-    type Output = <<<Self as ToTypenum>::Output as ops::Neg>::Output as ToConst>::Output;
-    fn neg(self) -> Self::Output {
-        (self.to_typenum().neg()).to_const()
+    type Output =
+        <<Self as ToTypenum>::Output as typenum::IsEqual<<Const<B> as ToTypenum>::Output>>::Output;
+}
+
+/// Equality of values `diff` powers of ten apart, where `Same` is
+/// `typenum::True` if they're in the same unit, so that only needs
+/// `PartialEq`.
+pub trait EqAtScale<Same> {
+    fn eq_at_scale(lhs: &Self, rhs: &Self, diff: i8) -> bool;
+}
+
+impl<T: PartialEq> EqAtScale<typenum::True> for T {
+    #[inline]
+    fn eq_at_scale(lhs: &T, rhs: &T, _diff: i8) -> bool {
+        lhs == rhs
+    }
+}
+
+impl<T: Scalar> EqAtScale<typenum::False> for T {
+    #[inline]
+    fn eq_at_scale(lhs: &T, rhs: &T, diff: i8) -> bool {
+        crate::eq_at_common_scale(lhs, rhs, diff)
+    }
+}
+
+/// Ordering of values `diff` powers of ten apart, like [`EqAtScale`].
+pub trait OrdAtScale<Same>: EqAtScale<Same> {
+    fn partial_cmp_at_scale(lhs: &Self, rhs: &Self, diff: i8) -> Option<cmp::Ordering>;
+}
+
+impl<T: PartialOrd> OrdAtScale<typenum::True> for T {
+    #[inline]
+    fn partial_cmp_at_scale(lhs: &T, rhs: &T, _diff: i8) -> Option<cmp::Ordering> {
+        lhs.partial_cmp(rhs)
+    }
+}
+
+impl<T: Scalar + PartialOrd> OrdAtScale<typenum::False> for T {
+    #[inline]
+    fn partial_cmp_at_scale(lhs: &T, rhs: &T, diff: i8) -> Option<cmp::Ordering> {
+        crate::partial_cmp_at_common_scale(lhs, rhs, diff)
     }
 }
 
+/// Implemented for `Const<A>` when `A <= B`.
+pub trait LessOrEqual<B> {}
+
+impl<const A: i8, const B: i8> LessOrEqual<Const<B>> for Const<A>
+where
+    Self: ToTypenum,
+    Const<B>: ToTypenum,
+    <Self as ToTypenum>::Output:
+        typenum::IsLessOrEqual<<Const<B> as ToTypenum>::Output, Output = typenum::True>,
+{
+}
+
+#[diagnostic::on_unimplemented(
+    message = "exponent `{Self}` out of range (max ±30)",
+    note = "the power of ten and the base unit exponents of a unit must each be within -30..=30"
+)]
 pub trait ToTypenum {
     type Output;
     fn to_typenum(self) -> Self::Output;
 }
 
+#[diagnostic::on_unimplemented(
+    message = "prefix exponent out of range (max ±30)",
+    label = "an exponent of this unit is outside -30..=30",
+    note = "the power of ten and the base unit exponents of a unit must each be within -30..=30, so prefixes such as `quetta<kilo<U>>` can't be stacked past 10^30"
+)]
 pub trait ToConst {
     type Output;
     fn to_const(self) -> Self::Output;
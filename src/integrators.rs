@@ -0,0 +1,125 @@
+//! Fixed-step integrators for ordinary differential equations whose state is
+//! made of quantities.
+//!
+//! The derivative of each part of the state is checked to be that part per
+//! second, so a system that returns a velocity where an acceleration belongs
+//! doesn't compile:
+//!
+//! ```rust
+//! use uy::integrators::rk4;
+//! use uy::si::quantities::{Acceleration, Length, Time, Velocity};
+//! use uy::Quantity;
+//!
+//! // A mass on a spring, released 1 m from rest, with ω = 1 rad/s.
+//! let mut state: (Length<f64>, Velocity<f64>) = (Quantity::new(1.0), Quantity::new(0.0));
+//! let dt: Time<f64> = Quantity::new(0.01);
+//! let mut t: Time<f64> = Quantity::new(0.0);
+//! for _ in 0..314 {
+//!     state = rk4(&state, t, dt, |_, (x, v)| {
+//!         let a: Acceleration<f64> = Quantity::new(-x.into_value());
+//!         (*v, a)
+//!     });
+//!     t = t + dt;
+//! }
+//! // Half a period later, it's at the other end.
+//! assert!((state.0.into_value() + 1.0).abs() < 1e-4);
+//! ```
+//!
+//! ```rust,compile_fail
+//! use uy::integrators::euler;
+//! use uy::si::quantities::{Length, Time, Velocity};
+//! use uy::Quantity;
+//!
+//! let state: (Length<f64>, Velocity<f64>) = (Quantity::new(1.0), Quantity::new(0.0));
+//! let dt: Time<f64> = Quantity::new(0.01);
+//! euler(&state, Quantity::new(0.0), dt, |_, (_, v)| (*v, *v));
+//! ```
+
+use std::ops;
+
+use num_traits::{Float, One};
+
+use crate::{si, Div, Quantity, Unit};
+
+/// The state of a system of differential equations.
+///
+/// Implemented for quantities, whose derivative is the quantity per second,
+/// and for tuples of states with the same scalar type.
+pub trait State: Clone {
+    type Scalar: Float;
+    /// The rate of change of the state.
+    type Derivative;
+
+    /// The state after changing at the rate `d` for `dt`.
+    fn advance(&self, d: &Self::Derivative, dt: Quantity<Self::Scalar, si::s>) -> Self;
+}
+
+impl<T: Float, U: Unit> State for Quantity<T, U>
+where
+    U: ops::Div<si::s>,
+    Div<U, si::s>: Unit,
+{
+    type Scalar = T;
+    type Derivative = Quantity<T, Div<U, si::s>>;
+
+    fn advance(&self, d: &Self::Derivative, dt: Quantity<T, si::s>) -> Self {
+        Quantity::new(self.val + d.val * dt.val)
+    }
+}
+
+macro_rules! impl_state_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<T: Float, $($name: State<Scalar = T>),+> State for ($($name,)+) {
+            type Scalar = T;
+            type Derivative = ($($name::Derivative,)+);
+
+            fn advance(&self, d: &Self::Derivative, dt: Quantity<T, si::s>) -> Self {
+                ($(self.$idx.advance(&d.$idx, dt),)+)
+            }
+        }
+    };
+}
+
+impl_state_tuple!(A 0);
+impl_state_tuple!(A 0, B 1);
+impl_state_tuple!(A 0, B 1, C 2);
+impl_state_tuple!(A 0, B 1, C 2, D 3);
+
+/// Take one step of the forward Euler method from `state` at time `t`, where
+/// `f` gives the derivative of the state at a time.
+pub fn euler<S: State>(
+    state: &S,
+    t: Quantity<S::Scalar, si::s>,
+    dt: Quantity<S::Scalar, si::s>,
+    mut f: impl FnMut(Quantity<S::Scalar, si::s>, &S) -> S::Derivative,
+) -> S {
+    state.advance(&f(t, state), dt)
+}
+
+/// Take one step of the classic fourth-order Runge-Kutta method from `state`
+/// at time `t`, where `f` gives the derivative of the state at a time.
+pub fn rk4<S: State>(
+    state: &S,
+    t: Quantity<S::Scalar, si::s>,
+    dt: Quantity<S::Scalar, si::s>,
+    mut f: impl FnMut(Quantity<S::Scalar, si::s>, &S) -> S::Derivative,
+) -> S {
+    let two = S::Scalar::one() + S::Scalar::one();
+    let three = two + S::Scalar::one();
+    let half: Quantity<_, si::s> = Quantity::new(dt.val / two);
+    let third: Quantity<_, si::s> = Quantity::new(dt.val / three);
+    let sixth: Quantity<_, si::s> = Quantity::new(half.val / three);
+    let mid = t + half;
+
+    let k1 = f(t, state);
+    let k2 = f(mid, &state.advance(&k1, half));
+    let k3 = f(mid, &state.advance(&k2, half));
+    let k4 = f(t + dt, &state.advance(&k3, dt));
+
+    // The weighted sum of the slopes, applied one slope at a time.
+    state
+        .advance(&k1, sixth)
+        .advance(&k2, third)
+        .advance(&k3, third)
+        .advance(&k4, sixth)
+}
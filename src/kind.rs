@@ -0,0 +1,239 @@
+//! Units of the same dimension that measure different kinds of quantity,
+//! such as the sievert and the gray, which are both J·kg⁻¹.
+//!
+//! A [`Kinded`] unit behaves like the unit it wraps, with prefixes and
+//! arithmetic, but only converts to units of the same [`Kind`]. Use
+//! [`with_kind`](crate::Quantity::with_kind) and
+//! [`without_kind`](crate::Quantity::without_kind) to move between them
+//! explicitly.
+//!
+//! ```rust
+//! use uy::{si, Quantity};
+//!
+//! let dose: Quantity<f64, si::micro<si::Sv>> = Quantity::new(250.0);
+//! let msv: Quantity<f64, si::milli<si::Sv>> = dose.convert();
+//! assert_eq!(msv.into_value(), 0.25);
+//! assert_eq!(msv.to_string(), "0.25 mSv");
+//!
+//! let rate: Quantity<f64, uy::Div<si::Sv, si::s>> = Quantity::new(0.1);
+//! assert_eq!(rate.to_string(), "0.1 Sv·s⁻¹");
+//! ```
+//!
+//! ```rust,compile_fail
+//! use uy::{si, Quantity};
+//!
+//! let dose: Quantity<f64, si::Sv> = Quantity::new(1.0);
+//! let absorbed: Quantity<f64, si::Gy> = dose.convert();
+//! ```
+
+use std::marker::PhantomData;
+use std::{cmp, ops};
+
+use crate::symbol::{unit_symbol, Style, SymbolBuf};
+use crate::{
+    CommonScale, IsDimensionless, Quantity, Ratio, SameDimension, ScaleEq, ScaleOrd, Unit,
+    UnitConvertExact, UnitConvertLossy, UnitInfo, UnitNormalize, UnitScale,
+};
+
+/// A kind of quantity, named by its symbol, that is measured in multiples
+/// of `Base`.
+pub trait Kind {
+    type Base: UnitInfo;
+    const SYMBOL: &'static str;
+    /// The symbol in plain ASCII, for [`UnitInfo::UNIT_STR`].
+    const UNIT_STR: &'static str = Self::SYMBOL;
+}
+
+/// The unit `U` for quantities of kind `K`.
+pub struct Kinded<K, U>(PhantomData<(K, U)>);
+
+impl<K: Kind, U: Unit> Unit for Kinded<K, U> {
+    const DEBUG_INFO: Option<(&'static str, i8, &'static [i8])> = match &Self::DEBUG_BUF {
+        Some((symbol, prefix, dims)) => Some((symbol.as_str(), *prefix, dims)),
+        None => None,
+    };
+}
+
+impl<K: Kind, U: Unit> Kinded<K, U> {
+    // The same symbol as `UnitInfo`, where `U` has one.
+    const DEBUG_BUF: Option<(SymbolBuf, i8, &'static [i8])> = match U::DEBUG_INFO {
+        Some((_, prefix, dims)) => Some((symbol::<K>(dims, prefix, Style::Unicode), prefix, dims)),
+        None => None,
+    };
+}
+
+impl<K: Kind, U: UnitNormalize> UnitNormalize for Kinded<K, U> {
+    type Output = Kinded<K, U::Output>;
+}
+
+impl<K: Kind, U: UnitScale> UnitScale for Kinded<K, U> {
+    type Prefix = U::Prefix;
+    type Base = Kinded<K, U::Base>;
+}
+
+/// The most base units the symbol of a [`Kinded`] unit can refer to.
+const MAX_BASE_UNITS: usize = 16;
+
+impl<K: Kind, U: UnitInfo> Kinded<K, U> {
+    const SYMBOL_BUF: SymbolBuf = symbol::<K>(U::DIMENSION, U::PREFIX, Style::Unicode);
+    const UNIT_STR_BUF: SymbolBuf = symbol::<K>(U::DIMENSION, U::PREFIX, Style::Ascii);
+}
+
+// The symbol of a unit of kind `K` with the dimension `dims` and the power
+// of ten `prefix` is written in terms of the kind's symbol, which stands in
+// for its base unit, followed by any other base units.
+const fn symbol<K: Kind>(dims: &[i8], prefix: i8, style: Style) -> SymbolBuf {
+    let base_symbols = <K::Base as UnitInfo>::BASE_SYMBOLS;
+    let base = <K::Base as UnitInfo>::DIMENSION;
+    assert!(
+        base.len() == dims.len() && dims.len() < MAX_BASE_UNITS,
+        "a kind must be measured in the same unit system as its base"
+    );
+    let mut symbols = [""; MAX_BASE_UNITS];
+    let mut exponents = [0; MAX_BASE_UNITS];
+    symbols[0] = match style {
+        Style::Unicode => K::SYMBOL,
+        Style::Ascii | Style::Spaced => K::UNIT_STR,
+    };
+    exponents[0] = 1;
+    let mut i = 0;
+    while i < dims.len() {
+        symbols[i + 1] = base_symbols[i];
+        exponents[i + 1] = dims[i] - base[i];
+        i += 1;
+    }
+    unit_symbol(
+        symbols.split_at(dims.len() + 1).0,
+        exponents.split_at(dims.len() + 1).0,
+        prefix - <K::Base as UnitInfo>::PREFIX,
+        style,
+    )
+}
+
+impl<K: Kind, U: UnitInfo> UnitInfo for Kinded<K, U> {
+    const BASE_SYMBOLS: &'static [&'static str] = U::BASE_SYMBOLS;
+    const DIMENSION: &'static [i8] = U::DIMENSION;
+    const PREFIX: i8 = U::PREFIX;
+    const SYMBOL: &'static str = Self::SYMBOL_BUF.as_str();
+    const UNIT_STR: &'static str = Self::UNIT_STR_BUF.as_str();
+    const FACTOR: Ratio = U::FACTOR;
+    const KIND: Option<&'static str> = Some(K::SYMBOL);
+}
+
+impl<K: Kind, U: Unit, R> ops::Mul<R> for Kinded<K, U>
+where
+    U: ops::Mul<R>,
+    U::Output: Unit,
+{
+    type Output = Kinded<K, U::Output>;
+
+    #[inline]
+    fn mul(self, _: R) -> Self::Output {
+        Kinded(PhantomData)
+    }
+}
+
+impl<K: Kind, U: Unit, R> ops::Div<R> for Kinded<K, U>
+where
+    U: ops::Div<R>,
+    U::Output: Unit,
+{
+    type Output = Kinded<K, U::Output>;
+
+    #[inline]
+    fn div(self, _: R) -> Self::Output {
+        Kinded(PhantomData)
+    }
+}
+
+impl<K: Kind, U1: Unit, U2: Unit> CommonScale<Kinded<K, U2>> for Kinded<K, U1>
+where
+    U1: CommonScale<U2>,
+{
+    const SCALE_DIFF: i8 = U1::SCALE_DIFF;
+}
+
+impl<T, K: Kind, U1: Unit, U2: Unit> ScaleEq<T, Kinded<K, U2>> for Kinded<K, U1>
+where
+    U1: ScaleEq<T, U2>,
+{
+    #[inline]
+    fn scale_eq(lhs: &T, rhs: &T) -> bool {
+        U1::scale_eq(lhs, rhs)
+    }
+}
+
+impl<T, K: Kind, U1: Unit, U2: Unit> ScaleOrd<T, Kinded<K, U2>> for Kinded<K, U1>
+where
+    U1: ScaleOrd<T, U2>,
+{
+    #[inline]
+    fn scale_partial_cmp(lhs: &T, rhs: &T) -> Option<cmp::Ordering> {
+        U1::scale_partial_cmp(lhs, rhs)
+    }
+}
+
+impl<K: Kind, U1: UnitInfo, U2: SameDimension<U1>> SameDimension<Kinded<K, U1>> for Kinded<K, U2> {}
+
+impl<K: Kind, U: IsDimensionless> IsDimensionless for Kinded<K, U> {}
+
+impl<T, K: Kind, U1: Unit, U2: Unit> UnitConvertLossy<T, Kinded<K, U1>> for Kinded<K, U2>
+where
+    U2: UnitConvertLossy<T, U1>,
+{
+    #[inline]
+    fn unit_convert_lossy(val: T) -> T {
+        U2::unit_convert_lossy(val)
+    }
+}
+
+impl<T, K: Kind, U1: Unit, U2: Unit> UnitConvertExact<T, Kinded<K, U1>> for Kinded<K, U2>
+where
+    U2: UnitConvertExact<T, U1>,
+{
+    #[inline]
+    fn unit_convert(val: T) -> T {
+        U2::unit_convert(val)
+    }
+}
+
+impl<T, U: Unit> Quantity<T, U> {
+    /// Tag the quantity as being of kind `K`, keeping its value and unit.
+    ///
+    /// ```rust
+    /// use uy::{si, Quantity};
+    ///
+    /// // Gamma rays have a radiation weighting factor of 1.
+    /// let absorbed: Quantity<f64, si::milli<si::Gy>> = Quantity::new(2.0);
+    /// let equivalent: Quantity<f64, si::milli<si::Sv>> = absorbed.with_kind();
+    /// assert_eq!(equivalent.to_string(), "2 mSv");
+    /// ```
+    #[inline]
+    pub fn with_kind<K: Kind>(self) -> Quantity<T, Kinded<K, U>> {
+        Quantity::new(self.val)
+    }
+}
+
+impl<T, K: Kind, U: Unit> Quantity<T, Kinded<K, U>> {
+    /// Remove the kind of the quantity, keeping its value and unit.
+    #[inline]
+    pub fn without_kind(self) -> Quantity<T, U> {
+        Quantity::new(self.val)
+    }
+}
+
+/// Define a [`Kind`] named `$name` measured in `$base` with the symbol
+/// `$symbol`, and optionally a different ASCII symbol `$unit_str`.
+macro_rules! kind {
+    ($(#[$attr:meta])* $name:ident: $base:ty, $symbol:literal $(, $unit_str:literal)?) => {
+        $(#[$attr])*
+        pub struct $name;
+
+        impl crate::Kind for $name {
+            type Base = $base;
+            const SYMBOL: &'static str = $symbol;
+            $(const UNIT_STR: &'static str = $unit_str;)?
+        }
+    };
+}
+pub(crate) use kind;
@@ -1,12 +1,133 @@
 use std::cmp;
+use std::fmt;
 use std::hash;
+use std::iter;
 use std::marker::PhantomData;
+use std::num;
 use std::ops;
+#[cfg(feature = "deref")]
 use std::ops::Deref;
+#[cfg(feature = "deref")]
 use std::ops::DerefMut;
 
+pub mod adc;
+mod any_prefix;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+mod assert;
+pub mod battery;
+mod bytes;
+pub mod calibration;
+pub mod cgs;
+pub mod chem;
+mod composite;
+pub mod contrib;
+#[cfg(feature = "control")]
+pub mod control;
+mod convert_iter;
+#[cfg(feature = "csv")]
+pub mod csv;
+mod dimensionless;
+pub mod drivetrain;
+#[cfg(feature = "encase")]
+mod encase;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fixed;
+mod formula;
+pub mod geodesy;
+#[cfg(feature = "geom")]
+pub mod geom;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "half")]
+mod half;
+#[cfg(feature = "information")]
+pub mod information;
 mod inner;
+#[cfg(feature = "integrators")]
+pub mod integrators;
+mod kind;
+pub mod locale;
+pub mod lookup;
+pub mod map;
+#[cfg(feature = "mechanics")]
+pub mod mechanics;
+#[cfg(feature = "media")]
+pub mod media;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+mod mixed;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+mod nonzero;
+pub mod optics;
+mod option;
+mod parse;
+#[cfg(feature = "plotters")]
+pub mod plotters;
+mod point;
+mod promote;
+pub mod psychrometrics;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+pub mod range;
+#[cfg(feature = "num-rational")]
+mod rational;
+#[cfg(feature = "rayon")]
+pub mod rayon;
+pub mod registry;
+mod scaled;
+mod scaled_unit;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod shared;
 pub mod si;
+mod sig_figs;
+pub mod slice;
+pub mod solve;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+pub mod stats;
+mod symbol;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod time;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+mod total_ord;
+pub mod units;
+#[cfg(feature = "uom")]
+mod uom;
+pub mod vec;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+pub use any_prefix::{
+    AnyCurrent, AnyFrequency, AnyLength, AnyPower, AnyPrefix, AnyTime, AnyVoltage, Prefixes,
+};
+#[doc(hidden)]
+pub use assert::__private;
+pub use composite::Composite;
+pub use convert_iter::{ConvertUnits, QuantityIterator};
+pub use error::{Bound, DisplayWithUnit, Error, LimitError};
+pub use fixed::DisplayFixed;
+pub use kind::{Kind, Kinded};
+pub use mixed::{Mixed, MixedPrefix};
+pub use point::{Delta, Point};
+pub use promote::{Promote, Promoted};
+pub use range::{QuantityRange, QuantityRangeInclusive, Toleranced};
+pub use scaled_unit::ScaledUnit;
+pub use symbol::{DisplayStyled, Style as SymbolStyle};
+pub use total_ord::OrdQuantity;
+#[cfg(feature = "macros")]
+pub use uy_macros::{assert_dimension, no_raw_math, Quantities};
+pub use vec::{QuantitySlice, QuantityVec};
 
 /// Used for multiplying a unit by 10ⁿ.
 ///
@@ -16,19 +137,363 @@ pub mod si;
 pub struct TenTo<const N: i8>;
 
 /// Multiply by a power of ten.
+///
+/// Besides the primitive numbers, this is implemented for [`Wrapping`] and
+/// [`Saturating`] integers, which scale with wrapping or saturating
+/// multiplication, and for [`NonZero`](std::num::NonZero) integers.
+///
+/// Integers are scaled exactly to finer units, but can overflow, and are
+/// truncated towards zero when scaled to coarser units. [`Quantity`] has a
+/// conversion for each way of handling this:
+///
+/// - [`convert`](Quantity::convert) only converts to finer units, so it
+///   never truncates.
+/// - [`checked_convert`](Quantity::checked_convert) also checks for
+///   overflow.
+/// - [`convert_widen`](Quantity::convert_widen) widens to a larger type first.
+/// - [`try_convert`](Quantity::try_convert) converts to any unit, failing
+///   if the value would be truncated or overflow.
+/// - [`convert_saturating`](Quantity::convert_saturating) only converts to
+///   finer units, clamping to the bounds of the type on overflow.
+/// - [`convert_lossy`](Quantity::convert_lossy) truncates towards zero.
+/// - [`convert_with`](Quantity::convert_with) rounds as chosen by the
+///   caller.
+///
+/// ```rust
+/// use std::num::{Saturating, Wrapping};
+/// use uy::{si, Quantity};
+///
+/// let ticks: Quantity<Wrapping<u32>, si::s> = Quantity::new(Wrapping(u32::MAX));
+/// let ticks = ticks + Quantity::new(Wrapping(2));
+/// assert_eq!(ticks.into_value(), Wrapping(1));
+///
+/// let a: Quantity<Saturating<u16>, si::s> = Quantity::new(Saturating(100));
+/// let b: Quantity<Saturating<u16>, si::milli<si::s>> = a.convert();
+/// assert_eq!(b.into_value(), Saturating(u16::MAX));
+/// ```
+///
+/// [`Wrapping`]: std::num::Wrapping
+/// [`Saturating`]: std::num::Saturating
 pub trait MulPowerOfTen {
+    /// Whether dividing by a power of ten can truncate, as it does for
+    /// integers.
+    const TRUNCATES: bool = false;
+
     fn mul_power_of_ten(self, exp: i8) -> Self;
+
+    /// Like [`mul_power_of_ten`](MulPowerOfTen::mul_power_of_ten), but
+    /// `None` if the result doesn't fit in the type. Types that never
+    /// overflow, or that wrap or saturate instead, always return `Some`.
+    ///
+    /// ```rust
+    /// use uy::MulPowerOfTen;
+    ///
+    /// assert_eq!(3i16.checked_mul_power_of_ten(-4), Some(30000));
+    /// assert_eq!(4i16.checked_mul_power_of_ten(-4), None);
+    /// assert_eq!(1i16.checked_mul_power_of_ten(6), Some(0));
+    /// ```
+    #[inline]
+    fn checked_mul_power_of_ten(self, exp: i8) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Some(self.mul_power_of_ten(exp))
+    }
+
+    /// Like [`mul_power_of_ten`](MulPowerOfTen::mul_power_of_ten), but
+    /// clamped to the bounds of the type if the result doesn't fit. Types
+    /// that never overflow, or that wrap or saturate instead, are scaled as
+    /// usual.
+    ///
+    /// ```rust
+    /// use uy::MulPowerOfTen;
+    ///
+    /// assert_eq!(3i16.saturating_mul_power_of_ten(-4), 30000);
+    /// assert_eq!(4i16.saturating_mul_power_of_ten(-4), i16::MAX);
+    /// assert_eq!((-4i16).saturating_mul_power_of_ten(-40), i16::MIN);
+    /// ```
+    #[inline]
+    fn saturating_mul_power_of_ten(self, exp: i8) -> Self
+    where
+        Self: Sized,
+    {
+        self.mul_power_of_ten(exp)
+    }
+
+    /// Like [`mul_power_of_ten`](MulPowerOfTen::mul_power_of_ten), but
+    /// dividing integers rounds as chosen by `rounding` rather than towards
+    /// zero. Types that don't truncate ignore `rounding`.
+    ///
+    /// ```rust
+    /// use uy::{MulPowerOfTen, Rounding};
+    ///
+    /// assert_eq!((-1250i32).mul_power_of_ten_rounded(3, Rounding::TowardZero), -1);
+    /// assert_eq!((-1250i32).mul_power_of_ten_rounded(3, Rounding::Floor), -2);
+    /// assert_eq!(1250u32.mul_power_of_ten_rounded(3, Rounding::Ceil), 2);
+    /// assert_eq!(1500u32.mul_power_of_ten_rounded(3, Rounding::Nearest), 2);
+    /// assert_eq!(60_000u16.mul_power_of_ten_rounded(5, Rounding::Nearest), 1);
+    /// ```
+    #[inline]
+    fn mul_power_of_ten_rounded(self, exp: i8, rounding: Rounding) -> Self
+    where
+        Self: Sized,
+    {
+        let _ = rounding;
+        self.mul_power_of_ten(exp)
+    }
+
+    /// Like [`mul_power_of_ten`](MulPowerOfTen::mul_power_of_ten), but with
+    /// the `strict-conversions` feature, integers panic in debug builds if
+    /// the result overflows or truncates. This is what
+    /// [`Quantity::convert`] scales with.
+    #[doc(hidden)]
+    #[inline]
+    #[track_caller]
+    fn strict_mul_power_of_ten(self, exp: i8) -> Self
+    where
+        Self: Sized,
+    {
+        self.mul_power_of_ten(exp)
+    }
+}
+
+/// Scaling a value from a unit with the power of ten `FROM` to one with the
+/// power of ten `TO` without truncating it, which is what
+/// [`Quantity::convert`] requires of its value.
+///
+/// Types that never truncate, such as floats, implement this for every pair
+/// of exponents. Integers only implement it when `TO <= FROM`, so they can
+/// only be converted to finer units:
+///
+/// ```rust,compile_fail
+/// use uy::{si, Quantity};
+///
+/// let t: Quantity<i32, si::milli<si::s>> = Quantity::new(1500);
+/// let _: Quantity<i32, si::s> = t.convert();
+/// ```
+///
+/// A storage type of your own that implements [`MulPowerOfTen`] without
+/// truncating can implement this for every pair:
+///
+/// ```rust
+/// use uy::{si, ExactPowerOfTen, MulPowerOfTen, Quantity};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Meters(f64);
+///
+/// impl MulPowerOfTen for Meters {
+///     fn mul_power_of_ten(self, exp: i8) -> Self {
+///         Meters(self.0.mul_power_of_ten(exp))
+///     }
+/// }
+///
+/// impl<const FROM: i8, const TO: i8> ExactPowerOfTen<FROM, TO> for Meters {}
+///
+/// let d: Quantity<Meters, si::milli<si::m>> = Quantity::new(Meters(1500.0));
+/// assert_eq!(d.convert::<si::m>().into_value(), Meters(1.5));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "converting `{Self}` from 10^{FROM} to 10^{TO} can truncate",
+    note = "integers can only be converted to finer units; use `try_convert` or `convert_lossy`"
+)]
+pub trait ExactPowerOfTen<const FROM: i8, const TO: i8>: MulPowerOfTen {}
+
+/// How to round an integer that is scaled to a coarser unit. See
+/// [`Quantity::convert_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// To the nearest integer, with halves rounded away from zero.
+    Nearest,
+    /// Towards negative infinity.
+    Floor,
+    /// Towards positive infinity.
+    Ceil,
+    /// Towards zero, as integer division does.
+    TowardZero,
 }
 
 macro_rules! impl_mul_power_of_ten {
     ($($ty:ty),*) => {
         $(
             impl MulPowerOfTen for $ty {
+                const TRUNCATES: bool = true;
+
+                #[inline]
                 fn mul_power_of_ten(self, exp: i8) -> Self {
                     if exp < 0 {
                         self * (10 as $ty).pow(-exp as u32)
                     } else {
-                        self / (10 as $ty).pow(exp as u32)
+                        (10 as $ty).checked_pow(exp as u32).map_or(0, |p| self / p)
+                    }
+                }
+
+                #[inline]
+                fn checked_mul_power_of_ten(self, exp: i8) -> Option<Self> {
+                    if exp < 0 {
+                        (10 as $ty).checked_pow(-exp as u32).and_then(|p| self.checked_mul(p))
+                    } else {
+                        Some((10 as $ty).checked_pow(exp as u32).map_or(0, |p| self / p))
+                    }
+                }
+
+                #[inline]
+                fn saturating_mul_power_of_ten(self, exp: i8) -> Self {
+                    if exp >= 0 {
+                        return self.mul_power_of_ten(exp);
+                    }
+                    match (10 as $ty).checked_pow(-exp as u32) {
+                        Some(p) => self.saturating_mul(p),
+                        None if self == 0 => 0,
+                        None if self > 0 => <$ty>::MAX,
+                        None => <$ty>::MIN,
+                    }
+                }
+
+                #[inline]
+                #[track_caller]
+                fn strict_mul_power_of_ten(self, exp: i8) -> Self {
+                    #[cfg(feature = "strict-conversions")]
+                    debug_assert!(
+                        match self.checked_mul_power_of_ten(exp) {
+                            Some(v) => exp <= 0 || v.mul_power_of_ten(-exp) == self,
+                            None => false,
+                        },
+                        "scaling {} by 10^{} overflowed or truncated, use `try_convert` or `checked_convert`",
+                        self,
+                        -i16::from(exp),
+                    );
+                    self.mul_power_of_ten(exp)
+                }
+
+                #[inline]
+                fn mul_power_of_ten_rounded(self, exp: i8, rounding: Rounding) -> Self {
+                    if exp <= 0 {
+                        return self.mul_power_of_ten(exp);
+                    }
+                    let zero = 0 as $ty;
+                    // The truncated quotient, and a remainder with the sign of
+                    // `self`. A power of ten that doesn't fit is larger than
+                    // `self`.
+                    let (q, r) = match (10 as $ty).checked_pow(exp as u32) {
+                        Some(p) => (self / p, self % p),
+                        None => (zero, self),
+                    };
+                    let sign = r.cmp(&zero);
+                    let away = match rounding {
+                        Rounding::TowardZero => false,
+                        Rounding::Floor => sign == cmp::Ordering::Less,
+                        Rounding::Ceil => sign == cmp::Ordering::Greater,
+                        Rounding::Nearest => {
+                            let half = (10 as $ty)
+                                .checked_pow(exp as u32 - 1)
+                                .and_then(|p| p.checked_mul(5));
+                            match (half, sign) {
+                                (Some(half), cmp::Ordering::Greater) => r >= half,
+                                (Some(half), cmp::Ordering::Less) => r <= zero - half,
+                                _ => false,
+                            }
+                        }
+                    };
+                    match (away, sign) {
+                        (true, cmp::Ordering::Greater) => q + 1,
+                        (true, cmp::Ordering::Less) => q - 1,
+                        _ => q,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_mul_power_of_ten!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Implement [`ExactPowerOfTen`] for integer types, which only scale exactly
+/// to finer units.
+macro_rules! impl_exact_power_of_ten_int {
+    ($($ty:ty),*) => {
+        $(
+            impl<const FROM: i8, const TO: i8> ExactPowerOfTen<FROM, TO> for $ty where
+                crate::inner::Const<TO>: crate::inner::LessOrEqual<crate::inner::Const<FROM>>
+            {
+            }
+        )*
+    };
+}
+pub(crate) use impl_exact_power_of_ten_int;
+
+impl_exact_power_of_ten_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_exact_power_of_ten_int!(
+    num::Wrapping<i8>,
+    num::Wrapping<i16>,
+    num::Wrapping<i32>,
+    num::Wrapping<i64>,
+    num::Wrapping<i128>,
+    num::Wrapping<isize>,
+    num::Wrapping<u8>,
+    num::Wrapping<u16>,
+    num::Wrapping<u32>,
+    num::Wrapping<u64>,
+    num::Wrapping<u128>,
+    num::Wrapping<usize>
+);
+impl_exact_power_of_ten_int!(
+    num::Saturating<i8>,
+    num::Saturating<i16>,
+    num::Saturating<i32>,
+    num::Saturating<i64>,
+    num::Saturating<i128>,
+    num::Saturating<isize>,
+    num::Saturating<u8>,
+    num::Saturating<u16>,
+    num::Saturating<u32>,
+    num::Saturating<u64>,
+    num::Saturating<u128>,
+    num::Saturating<usize>
+);
+
+macro_rules! impl_mul_power_of_ten_wrappers {
+    ($($ty:ty),*) => {
+        $(
+            /// Scales with wrapping multiplication. Dividing by a power of ten
+            /// that doesn't fit in the integer type gives zero.
+            impl MulPowerOfTen for num::Wrapping<$ty> {
+                const TRUNCATES: bool = true;
+
+                #[inline]
+                fn mul_power_of_ten(self, exp: i8) -> Self {
+                    if exp < 0 {
+                        num::Wrapping(self.0.wrapping_mul((10 as $ty).wrapping_pow(-exp as u32)))
+                    } else {
+                        num::Wrapping((10 as $ty).checked_pow(exp as u32).map_or(0, |p| self.0.wrapping_div(p)))
+                    }
+                }
+
+                #[inline]
+                fn mul_power_of_ten_rounded(self, exp: i8, rounding: Rounding) -> Self {
+                    if exp < 0 {
+                        self.mul_power_of_ten(exp)
+                    } else {
+                        num::Wrapping(self.0.mul_power_of_ten_rounded(exp, rounding))
+                    }
+                }
+            }
+
+            /// Scales with saturating multiplication. Dividing by a power of
+            /// ten that doesn't fit in the integer type gives zero.
+            impl MulPowerOfTen for num::Saturating<$ty> {
+                const TRUNCATES: bool = true;
+
+                #[inline]
+                fn mul_power_of_ten(self, exp: i8) -> Self {
+                    num::Saturating(self.0.saturating_mul_power_of_ten(exp))
+                }
+
+                #[inline]
+                fn mul_power_of_ten_rounded(self, exp: i8, rounding: Rounding) -> Self {
+                    if exp < 0 {
+                        self.mul_power_of_ten(exp)
+                    } else {
+                        num::Saturating(self.0.mul_power_of_ten_rounded(exp, rounding))
                     }
                 }
             }
@@ -36,210 +501,2006 @@ macro_rules! impl_mul_power_of_ten {
     };
 }
 
-impl_mul_power_of_ten!(i8, i16, i32, i64, isize, u8, u16, u32, u64, u128);
+impl_mul_power_of_ten_wrappers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
 
 impl MulPowerOfTen for f32 {
+    #[inline]
     fn mul_power_of_ten(self, exp: i8) -> Self {
         self * 10f32.powi(-exp as i32)
     }
 }
 
 impl MulPowerOfTen for f64 {
+    #[inline]
     fn mul_power_of_ten(self, exp: i8) -> Self {
         self * 10f64.powi(-exp as i32)
     }
 }
 
+impl<const FROM: i8, const TO: i8> ExactPowerOfTen<FROM, TO> for f32 {}
+
+impl<const FROM: i8, const TO: i8> ExactPowerOfTen<FROM, TO> for f64 {}
+
+/// The bounds a storage type needs for a quantity to be converted, compared,
+/// and used in arithmetic, so generic code over `T` can name them at once.
+///
+/// This is implemented for every type that meets them, including all the
+/// types that implement [`MulPowerOfTen`] in this crate.
+///
+/// ```rust
+/// use uy::{si, Quantity, Scalar, Unit};
+///
+/// fn midpoint<T: Scalar + From<u8>, U: Unit>(a: Quantity<T, U>, b: Quantity<T, U>) -> Quantity<T, U> {
+///     (a + b).map(|v| v / T::from(2))
+/// }
+///
+/// let a: Quantity<i32, si::milli<si::m>> = Quantity::new(10);
+/// assert_eq!(midpoint(a, Quantity::new(20)), Quantity::<i32, si::milli<si::m>>::new(15));
+/// assert_eq!(midpoint::<f64, si::V>(Quantity::new(1.0), Quantity::new(2.0)).into_value(), 1.5);
+/// ```
+pub trait Scalar:
+    Clone
+    + PartialEq
+    + MulPowerOfTen
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+{
+}
+
+impl<T> Scalar for T where
+    T: Clone
+        + PartialEq
+        + MulPowerOfTen
+        + ops::Add<Output = T>
+        + ops::Sub<Output = T>
+        + ops::Mul<Output = T>
+        + ops::Div<Output = T>
+{
+}
+
 /// Marker trait for unit systems.
-pub trait Unit {}
+pub trait Unit {
+    /// The symbol, prefix, and dimension of units that implement
+    /// [`UnitInfo`], for the `Debug` output of quantities, which only needs
+    /// `Unit`.
+    #[doc(hidden)]
+    const DEBUG_INFO: Option<(&'static str, i8, &'static [i8])> = None;
+
+    /// The factor a value in this unit is multiplied by to express it in
+    /// `U`, for handing a scale factor to code outside the type system.
+    ///
+    /// ```rust
+    /// use uy::{si, Unit};
+    ///
+    /// assert_eq!(si::m::factor_to::<si::milli<si::m>>(), 1e3);
+    /// assert_eq!(<si::micro<si::s>>::factor_to::<si::s>(), 1e-6);
+    /// ```
+    fn factor_to<U: Unit>() -> f64
+    where
+        Self: CommonScale<U> + Sized,
+    {
+        10f64.powi(Self::SCALE_DIFF as i32)
+    }
+
+    /// The exact form of [`factor_to`](Unit::factor_to).
+    ///
+    /// Panics if either side of the ratio doesn't fit in a `u128`, which
+    /// happens when the scales differ by more than 10³⁸.
+    ///
+    /// ```rust
+    /// use uy::{si, Ratio, Unit};
+    ///
+    /// assert_eq!(si::m::ratio_to::<si::kilo<si::m>>(), Ratio { numer: 1, denom: 1000 });
+    /// ```
+    fn ratio_to<U: Unit>() -> Ratio
+    where
+        Self: CommonScale<U> + Sized,
+    {
+        let pow = 10u128
+            .checked_pow(Self::SCALE_DIFF.unsigned_abs().into())
+            .expect("conversion factor doesn't fit in a `u128`");
+        if Self::SCALE_DIFF < 0 {
+            Ratio {
+                numer: 1,
+                denom: pow,
+            }
+        } else {
+            Ratio {
+                numer: pow,
+                denom: 1,
+            }
+        }
+    }
+}
+
+/// An exact conversion factor of `numer / denom`, returned by
+/// [`Unit::ratio_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    pub numer: u128,
+    pub denom: u128,
+}
+
+impl Ratio {
+    pub const ONE: Ratio = Ratio { numer: 1, denom: 1 };
+
+    /// The ratio `numer / denom` in lowest terms.
+    pub const fn new(numer: u128, denom: u128) -> Self {
+        assert!(denom != 0, "ratio has a zero denominator");
+        let (mut a, mut b) = (numer, denom);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        Ratio {
+            numer: numer / a,
+            denom: denom / a,
+        }
+    }
+
+    /// The product of two ratios, or `None` if it doesn't fit in a `u128`.
+    pub const fn checked_mul(self, rhs: Ratio) -> Option<Ratio> {
+        let a = Ratio::new(self.numer, rhs.denom);
+        let b = Ratio::new(rhs.numer, self.denom);
+        match (a.numer.checked_mul(b.numer), a.denom.checked_mul(b.denom)) {
+            (Some(numer), Some(denom)) => Some(Ratio { numer, denom }),
+            _ => None,
+        }
+    }
+
+    /// The reciprocal of the ratio.
+    pub const fn recip(self) -> Ratio {
+        Ratio::new(self.denom, self.numer)
+    }
+
+    /// The factor as the nearest `f64`.
+    pub fn to_f64(self) -> f64 {
+        self.numer as f64 / self.denom as f64
+    }
+}
 
+/// Define a unit system whose units are scaled by powers of ten, with one
+/// exponent for each base unit, such as [`si::Si`].
+///
+/// This defines the unit type `$system<EXP, ...>`, generic over the power of
+/// ten and the exponent of each base unit in the order given, and a
+/// `Dimension` struct for the dimension of a unit at runtime, so it should
+/// be invoked in a module of its own. The units get the same arithmetic,
+/// prefixes, conversions, and formatting as the built-in systems, but can't
+/// be mixed with the units of any other system.
+///
+/// Each base unit may be followed by its name, as in `s = "second"`, which
+/// becomes a search alias in the documentation of its field of `Dimension`.
+///
+/// ```rust
+/// mod inner {
+///     uy::power_of_ten_unit_system!(Money { usd = "dollar", s = "second" });
+/// }
+///
+/// use inner::Money;
+/// use uy::{si, Quantity};
+///
+/// #[allow(non_camel_case_types)]
+/// type usd = Money<0, 1, 0>;
+/// #[allow(non_camel_case_types)]
+/// type s = Money<0, 0, 1>;
+///
+/// let rate: Quantity<i64, uy::Div<si::milli<usd>, s>> = Quantity::new(25);
+/// let cost: Quantity<i64, si::milli<usd>> = rate * Quantity::<i64, s>::new(60);
+/// assert_eq!(cost.convert_lossy::<usd>().into_value(), 1);
+/// assert_eq!(cost.to_string(), "1500 musd");
+/// assert_eq!(inner::Dimension { exp: 0, usd: 1, s: 0 }, usd::dimension());
+/// ```
+#[macro_export]
 macro_rules! power_of_ten_unit_system {
-    ($system:ident { $($unit:ident),* }) => {
-        ::paste::paste! {
+    ($system:ident { $($unit:ident $(= $name:literal)?),* $(,)? }) => {
+        $crate::__private::paste::paste! {
             pub struct [<Typenum $system>]<EXP, $([<$unit:camel>]),*>(std::marker::PhantomData<(EXP, $([<$unit:camel>]),*)>);
 
-            impl<const EXP: i8, $(const [<$unit:upper>]: i8),*> crate::inner::ToConst for [<Typenum $system>]<crate::inner::Const<EXP>, $(crate::inner::Const<{ [<$unit:upper>] }>),*> {
-                type Output = $system<EXP, $({ [<$unit:upper>] }),*>;
-                fn to_const(self) -> Self::Output { Si }
-            }
+            impl<const EXP: i8, $(const [<$unit:upper>]: i8),*> $crate::__private::ToConst for [<Typenum $system>]<$crate::__private::Const<EXP>, $($crate::__private::Const<{ [<$unit:upper>] }>),*>
+            where
+                $crate::__private::Const<EXP>: $crate::__private::ToTypenum,
+                $( $crate::__private::Const<{ [<$unit:upper>] }>: $crate::__private::ToTypenum, )*
+            {
+                type Output = $system<EXP, $({ [<$unit:upper>] }),*>;
+                fn to_const(self) -> Self::Output { $system }
+            }
+
+            #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $system<const EXP: i8, $(const [<$unit:upper>]: i8),*>;
+
+            impl<const EXP: i8, $(const [<$unit:upper>]: i8),*> $crate::Unit for $system<EXP, $({ [<$unit:upper>] }),*> {
+                const DEBUG_INFO: Option<(&'static str, i8, &'static [i8])> = Some((
+                    <Self as $crate::UnitInfo>::SYMBOL,
+                    EXP,
+                    <Self as $crate::UnitInfo>::DIMENSION,
+                ));
+            }
+
+            impl<const EXP: i8, $(const [<$unit:upper>]: i8),*> $crate::UnitNormalize for $system<EXP, $({ [<$unit:upper>] }),*> {
+                type Output = Self;
+            }
+
+            impl<const EXP: i8, $(const [<$unit:upper>]: i8),*> $crate::UnitScale for $system<EXP, $({ [<$unit:upper>] }),*> {
+                type Prefix = $crate::TenTo<EXP>;
+                type Base = $system<0, $({ [<$unit:upper>] }),*>;
+            }
+
+            /// The scale and base unit exponents of a unit, at runtime.
+            #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+            pub struct Dimension {
+                /// The power of ten the unit is scaled by.
+                pub exp: i8,
+                $(
+                    #[doc = concat!("The exponent of `", stringify!($unit), "`.")]
+                    $(#[doc(alias = $name)])?
+                    pub [<$unit:lower>]: i8,
+                )*
+            }
+
+            impl Dimension {
+                /// The dimension of a unit with no base units and no scale.
+                pub const UNITLESS: Self = Self { exp: 0, $([<$unit:lower>]: 0),* };
+
+                /// Whether all base unit exponents are zero, regardless of scale.
+                pub const fn is_dimensionless(&self) -> bool {
+                    true $(&& self.[<$unit:lower>] == 0)*
+                }
+
+                /// Whether the base unit exponents match, regardless of scale.
+                pub const fn same_dimension(&self, other: &Self) -> bool {
+                    true $(&& self.[<$unit:lower>] == other.[<$unit:lower>])*
+                }
+
+                /// The dimension of the product of two units.
+                pub const fn mul(self, rhs: Self) -> Self {
+                    Self {
+                        exp: self.exp + rhs.exp,
+                        $([<$unit:lower>]: self.[<$unit:lower>] + rhs.[<$unit:lower>]),*
+                    }
+                }
+
+                /// The dimension of the quotient of two units.
+                pub const fn div(self, rhs: Self) -> Self {
+                    Self {
+                        exp: self.exp - rhs.exp,
+                        $([<$unit:lower>]: self.[<$unit:lower>] - rhs.[<$unit:lower>]),*
+                    }
+                }
+
+                /// A registry of the base units of the unit system, for looking
+                /// up units by their symbols at runtime.
+                pub fn registry() -> $crate::registry::Registry {
+                    $crate::registry::Registry::with_base_units(&[$(stringify!($unit)),*])
+                }
+
+                /// The exponent of each base unit, in declaration order.
+                pub const fn exponents(&self) -> [i8; [$(stringify!($unit)),*].len()] {
+                    [$(self.[<$unit:lower>]),*]
+                }
+            }
+
+            impl std::fmt::Display for Dimension {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    let base = [$(stringify!($unit)),*];
+                    f.write_str($crate::__private::unit_symbol(&base, &self.exponents(), self.exp, $crate::__private::Style::Unicode).as_str())
+                }
+            }
+
+            impl<const EXP: i8, $(const [<$unit:upper>]: i8),*> $system<EXP, $({ [<$unit:upper>] }),*> {
+                /// The scale and base unit exponents of this unit.
+                pub const fn dimension() -> Dimension {
+                    Dimension { exp: EXP, $([<$unit:lower>]: [<$unit:upper>]),* }
+                }
+
+                const SYMBOL_BUF: $crate::__private::SymbolBuf = $crate::__private::unit_symbol(
+                    <Self as $crate::UnitInfo>::BASE_SYMBOLS,
+                    <Self as $crate::UnitInfo>::DIMENSION,
+                    EXP,
+                    $crate::__private::Style::Unicode,
+                );
+                const UNIT_STR_BUF: $crate::__private::SymbolBuf = $crate::__private::unit_symbol(
+                    <Self as $crate::UnitInfo>::BASE_SYMBOLS,
+                    <Self as $crate::UnitInfo>::DIMENSION,
+                    EXP,
+                    $crate::__private::Style::Ascii,
+                );
+            }
+
+            impl<const EXP: i8, $(const [<$unit:upper>]: i8),*> $crate::UnitInfo for $system<EXP, $({ [<$unit:upper>] }),*> {
+                const BASE_SYMBOLS: &'static [&'static str] = &[$(stringify!($unit)),*];
+                const DIMENSION: &'static [i8] = &[$([<$unit:upper>]),*];
+                const PREFIX: i8 = EXP;
+                const SYMBOL: &'static str = Self::SYMBOL_BUF.as_str();
+                const UNIT_STR: &'static str = Self::UNIT_STR_BUF.as_str();
+            }
+
+            impl<
+                const EXP: i8,
+                const N: i8,
+                $(const [<$unit:upper>]: i8),*
+            > std::ops::Mul<$crate::TenTo<{ N }>> for $system<EXP, $({ [<$unit:upper>] }),*>
+            where
+                $crate::__private::Const<EXP>: std::ops::Add<$crate::__private::Const<N>>,
+                [<Typenum $system>]<
+                    <$crate::__private::Const<EXP> as std::ops::Add<$crate::__private::Const<N>>>::Output,
+                    $( $crate::__private::Const<{ [<$unit:upper>] }> ),*
+                >: $crate::__private::ToConst,
+            {
+                type Output = <[<Typenum $system>]<
+                    <$crate::__private::Const<EXP> as std::ops::Add<$crate::__private::Const<N>>>::Output,
+                    $( $crate::__private::Const<{ [<$unit:upper>] }> ),*
+                > as $crate::__private::ToConst>::Output;
+
+                #[inline]
+                fn mul(self, _rhs: $crate::TenTo<N>) -> Self::Output {
+                    $crate::__private::ToConst::to_const([<Typenum $system>](std::marker::PhantomData))
+                }
+            }
+
+            impl<
+                const EXP: i8,
+                const N: i8,
+                $(const [<$unit:upper>]: i8),*
+            > std::ops::Div<$crate::TenTo<N>> for $system<EXP, $({ [<$unit:upper>] }),*>
+            where
+                $crate::__private::Const<EXP>: std::ops::Sub<$crate::__private::Const<N>>,
+                [<Typenum $system>]<
+                    <$crate::__private::Const<EXP> as std::ops::Sub<$crate::__private::Const<N>>>::Output,
+                    $( $crate::__private::Const<{ [<$unit:upper>] }> ),*
+                >: $crate::__private::ToConst,
+            {
+                type Output = <[<Typenum $system>]<
+                    <$crate::__private::Const<EXP> as std::ops::Sub<$crate::__private::Const<N>>>::Output,
+                    $( $crate::__private::Const<{ [<$unit:upper>] }> ),*
+                > as $crate::__private::ToConst>::Output;
+
+                #[inline]
+                fn div(self, _rhs: $crate::TenTo<N>) -> Self::Output {
+                    $crate::__private::ToConst::to_const([<Typenum $system>](std::marker::PhantomData))
+                }
+            }
+
+            impl<
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper 1>]: i8, const [<$unit:upper 2>]: i8),*
+            > std::ops::Mul<$system<EXP2, $({ [<$unit:upper 2>] }),*>> for $system<EXP1, $({ [<$unit:upper 1>] }),*>
+            where
+                $crate::__private::Const<EXP1>: std::ops::Add<$crate::__private::Const<EXP2>>,
+
+                $( $crate::__private::Const<{ [<$unit:upper 1>] }>: std::ops::Add<$crate::__private::Const<{ [<$unit:upper 2>] }>>, )*
+                [<Typenum $system>]<
+                    <$crate::__private::Const<EXP1> as std::ops::Add<$crate::__private::Const<EXP2>>>::Output,
+                    $( <$crate::__private::Const<{ [<$unit:upper 1>] }> as std::ops::Add<$crate::__private::Const<{ [<$unit:upper 2>] }>>>::Output ),*
+                >: $crate::__private::ToConst,
+            {
+                type Output = <[<Typenum $system>]<
+                    <$crate::__private::Const<EXP1> as std::ops::Add<$crate::__private::Const<EXP2>>>::Output,
+                    $( <$crate::__private::Const<{ [<$unit:upper 1>] }> as std::ops::Add<$crate::__private::Const<{ [<$unit:upper 2>] }>>>::Output ),*
+                > as $crate::__private::ToConst>::Output;
+
+                #[inline]
+                fn mul(self, _rhs: $system<EXP2, $({ [<$unit:upper 2>] }),*>) -> Self::Output {
+                    $crate::__private::ToConst::to_const([<Typenum $system>](std::marker::PhantomData))
+                }
+            }
+
+            impl<
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper 1>]: i8, const [<$unit:upper 2>]: i8),*
+            > std::ops::Div<$system<EXP2, $([<$unit:upper 2>]),*>> for $system<EXP1, $([<$unit:upper 1>]),*>
+            where
+                $crate::__private::Const<EXP1>: std::ops::Sub<$crate::__private::Const<EXP2>>,
+
+                $( $crate::__private::Const<[<$unit:upper 1>]>: std::ops::Sub<$crate::__private::Const<[<$unit:upper 2>]>>, )*
+                [<Typenum $system>]<
+                    <$crate::__private::Const<EXP1> as std::ops::Sub<$crate::__private::Const<EXP2>>>::Output,
+                    $( <$crate::__private::Const<[<$unit:upper 1>]> as std::ops::Sub<$crate::__private::Const<[<$unit:upper 2>]>>>::Output ),*
+                >: $crate::__private::ToConst,
+            {
+                type Output = <[<Typenum $system>]<
+                    <$crate::__private::Const<EXP1> as std::ops::Sub<$crate::__private::Const<EXP2>>>::Output,
+                    $( <$crate::__private::Const<[<$unit:upper 1>]> as std::ops::Sub<$crate::__private::Const<[<$unit:upper 2>]>>>::Output ),*
+                > as $crate::__private::ToConst>::Output;
+
+                #[inline]
+                fn div(self, _rhs: $system<EXP2, $([<$unit:upper 2>]),*>) -> Self::Output {
+                    $crate::__private::ToConst::to_const([<Typenum $system>](std::marker::PhantomData))
+                }
+            }
+
+            impl<
+                T,
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::UnitConvertLossy<T, $system<EXP1, $([<$unit:upper>]),*>> for $system<EXP2, $([<$unit:upper>]),*>
+            where
+                T: $crate::MulPowerOfTen,
+            {
+                #[inline]
+                fn unit_convert_lossy(val: T) -> T {
+                    val.mul_power_of_ten(EXP2 - EXP1)
+                }
+            }
+
+            impl<
+                T,
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::UnitConvertExact<T, $system<EXP1, $([<$unit:upper>]),*>> for $system<EXP2, $([<$unit:upper>]),*>
+            where
+                T: $crate::ExactPowerOfTen<EXP1, EXP2>,
+            {
+                #[inline]
+                #[track_caller]
+                fn unit_convert(val: T) -> T {
+                    val.strict_mul_power_of_ten(EXP2 - EXP1)
+                }
+            }
+
+            impl<
+                T: $crate::__private::num_traits::Float,
+                X: $crate::ScaledUnit,
+                const EXP: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::UnitConvertLossy<T, X> for $system<EXP, $([<$unit:upper>]),*>
+            where
+                Self: $crate::UnitConvertLossy<T, X::Base>,
+            {
+                #[inline]
+                fn unit_convert_lossy(val: T) -> T {
+                    <Self as $crate::UnitConvertLossy<T, X::Base>>::unit_convert_lossy(val * $crate::__private::scaled_factor::<T, X>())
+                }
+            }
+
+            impl<
+                T: $crate::__private::num_traits::Float,
+                X: $crate::ScaledUnit,
+                const EXP: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::UnitConvertExact<T, X> for $system<EXP, $([<$unit:upper>]),*>
+            where
+                Self: $crate::UnitConvertLossy<T, X::Base>,
+            {
+                #[inline]
+                fn unit_convert(val: T) -> T {
+                    <Self as $crate::UnitConvertLossy<T, X>>::unit_convert_lossy(val)
+                }
+            }
+
+            impl<
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::CommonScale<$system<EXP2, $([<$unit:upper>]),*>> for $system<EXP1, $([<$unit:upper>]),*> {
+                const SCALE_DIFF: i8 = EXP1 - EXP2;
+            }
+
+            impl<
+                T,
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::ScaleEq<T, $system<EXP2, $([<$unit:upper>]),*>> for $system<EXP1, $([<$unit:upper>]),*>
+            where
+                $crate::__private::Const<EXP1>: $crate::__private::Equal<$crate::__private::Const<EXP2>>,
+                T: $crate::__private::EqAtScale<<$crate::__private::Const<EXP1> as $crate::__private::Equal<$crate::__private::Const<EXP2>>>::Output>,
+            {
+                #[inline]
+                fn scale_eq(lhs: &T, rhs: &T) -> bool {
+                    $crate::__private::EqAtScale::eq_at_scale(lhs, rhs, EXP1 - EXP2)
+                }
+            }
+
+            impl<
+                T,
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::ScaleOrd<T, $system<EXP2, $([<$unit:upper>]),*>> for $system<EXP1, $([<$unit:upper>]),*>
+            where
+                $crate::__private::Const<EXP1>: $crate::__private::Equal<$crate::__private::Const<EXP2>>,
+                T: $crate::__private::OrdAtScale<<$crate::__private::Const<EXP1> as $crate::__private::Equal<$crate::__private::Const<EXP2>>>::Output>,
+            {
+                #[inline]
+                fn scale_partial_cmp(lhs: &T, rhs: &T) -> Option<std::cmp::Ordering> {
+                    $crate::__private::OrdAtScale::partial_cmp_at_scale(lhs, rhs, EXP1 - EXP2)
+                }
+            }
+
+            impl<
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::SameDimension<$system<EXP2, $([<$unit:upper>]),*>> for $system<EXP1, $([<$unit:upper>]),*> {}
+
+            impl<const EXP: i8> $crate::IsDimensionless for $system<EXP, $({ $crate::__private::zero(stringify!($unit)) }),*> {}
+
+            impl<
+                X: $crate::ScaledUnit,
+                const EXP: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::SameDimension<X> for $system<EXP, $([<$unit:upper>]),*>
+            where
+                Self: $crate::SameDimension<X::Base>,
+            {
+            }
+
+            impl<
+                const N: i8,
+                const EXP: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::UnitRoot<N> for $system<EXP, $({ [<$unit:upper>] }),*>
+            where
+                $crate::__private::Const<EXP>: $crate::__private::typenum::PartialDiv<$crate::__private::Const<N>>,
+                $( $crate::__private::Const<{ [<$unit:upper>] }>: $crate::__private::typenum::PartialDiv<$crate::__private::Const<N>>, )*
+                [<Typenum $system>]<
+                    <$crate::__private::Const<EXP> as $crate::__private::typenum::PartialDiv<$crate::__private::Const<N>>>::Output,
+                    $( <$crate::__private::Const<{ [<$unit:upper>] }> as $crate::__private::typenum::PartialDiv<$crate::__private::Const<N>>>::Output ),*
+                >: $crate::__private::ToConst,
+                <[<Typenum $system>]<
+                    <$crate::__private::Const<EXP> as $crate::__private::typenum::PartialDiv<$crate::__private::Const<N>>>::Output,
+                    $( <$crate::__private::Const<{ [<$unit:upper>] }> as $crate::__private::typenum::PartialDiv<$crate::__private::Const<N>>>::Output ),*
+                > as $crate::__private::ToConst>::Output: $crate::Unit,
+            {
+                type Output = <[<Typenum $system>]<
+                    <$crate::__private::Const<EXP> as $crate::__private::typenum::PartialDiv<$crate::__private::Const<N>>>::Output,
+                    $( <$crate::__private::Const<{ [<$unit:upper>] }> as $crate::__private::typenum::PartialDiv<$crate::__private::Const<N>>>::Output ),*
+                > as $crate::__private::ToConst>::Output;
+            }
+
+            impl<
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > $crate::FinerScale<$system<EXP2, $([<$unit:upper>]),*>> for $system<EXP1, $([<$unit:upper>]),*>
+            where
+                $crate::__private::Const<EXP1>: $crate::__private::typenum::Min<$crate::__private::Const<EXP2>>,
+                [<Typenum $system>]<
+                    <$crate::__private::Const<EXP1> as $crate::__private::typenum::Min<$crate::__private::Const<EXP2>>>::Output,
+                    $( $crate::__private::Const<{ [<$unit:upper>] }> ),*
+                >: $crate::__private::ToConst,
+                <[<Typenum $system>]<
+                    <$crate::__private::Const<EXP1> as $crate::__private::typenum::Min<$crate::__private::Const<EXP2>>>::Output,
+                    $( $crate::__private::Const<{ [<$unit:upper>] }> ),*
+                > as $crate::__private::ToConst>::Output: $crate::Unit,
+            {
+                type Output = <[<Typenum $system>]<
+                    <$crate::__private::Const<EXP1> as $crate::__private::typenum::Min<$crate::__private::Const<EXP2>>>::Output,
+                    $( $crate::__private::Const<{ [<$unit:upper>] }> ),*
+                > as $crate::__private::ToConst>::Output;
+            }
+        }
+    }
+}
+
+/// Implement conversions in both directions between the units of two unit
+/// systems with the same number of base units, given how many of each base
+/// unit of `$to` make up the corresponding base unit of `$from`.
+///
+/// Since the factors are generally not powers of ten, these conversions are
+/// only available for floating-point values.
+macro_rules! system_conversion {
+    ($from:path => $to:path { $($unit:ident: $factor:expr),* $(,)? }) => {
+        ::paste::paste! {
+            impl<
+                T: ::num_traits::Float,
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > crate::UnitConvertLossy<T, $from<EXP1, $([<$unit:upper>]),*>> for $to<EXP2, $([<$unit:upper>]),*> {
+                #[inline]
+                fn unit_convert_lossy(val: T) -> T {
+                    let mut factor = 10f64.powi(EXP1 as i32 - EXP2 as i32);
+                    $(factor *= ($factor as f64).powi([<$unit:upper>] as i32);)*
+                    val * T::from(factor).unwrap()
+                }
+            }
+
+            impl<
+                T: ::num_traits::Float,
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > crate::UnitConvertExact<T, $from<EXP1, $([<$unit:upper>]),*>> for $to<EXP2, $([<$unit:upper>]),*> {
+                #[inline]
+                fn unit_convert(val: T) -> T {
+                    <Self as crate::UnitConvertLossy<T, $from<EXP1, $([<$unit:upper>]),*>>>::unit_convert_lossy(val)
+                }
+            }
+
+            impl<
+                T: ::num_traits::Float,
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > crate::UnitConvertLossy<T, $to<EXP1, $([<$unit:upper>]),*>> for $from<EXP2, $([<$unit:upper>]),*> {
+                #[inline]
+                fn unit_convert_lossy(val: T) -> T {
+                    let mut factor = 10f64.powi(EXP1 as i32 - EXP2 as i32);
+                    $(factor /= ($factor as f64).powi([<$unit:upper>] as i32);)*
+                    val * T::from(factor).unwrap()
+                }
+            }
+
+            impl<
+                T: ::num_traits::Float,
+                const EXP1: i8,
+                const EXP2: i8,
+                $(const [<$unit:upper>]: i8),*
+            > crate::UnitConvertExact<T, $to<EXP1, $([<$unit:upper>]),*>> for $from<EXP2, $([<$unit:upper>]),*> {
+                #[inline]
+                fn unit_convert(val: T) -> T {
+                    <Self as crate::UnitConvertLossy<T, $to<EXP1, $([<$unit:upper>]),*>>>::unit_convert_lossy(val)
+                }
+            }
+        }
+    };
+}
+pub(crate) use system_conversion;
+
+/// Multiply a unit by another unit or [`TenTo`].
+pub type Mul<A, B> = <A as ops::Mul<B>>::Output;
+/// Divide a unit by another unit or [`TenTo`].
+pub type Div<A, B> = <A as ops::Div<B>>::Output;
+/// The inverse of a unit, such as `si::Hz` for `si::s`.
+pub type Inv<U> = Div<si::unitless, U>;
+/// `U` per second, such as `Rate<si::mol>` for a reaction rate.
+pub type Rate<U> = Div<U, si::s>;
+/// `U` per cubic metre, such as `Density<si::kg>` for a mass density.
+///
+/// ```rust
+/// use uy::{si, Density, Quantity};
+///
+/// let mass: Quantity<f64, si::kg> = Quantity::new(3.0);
+/// let volume: si::quantities::Volume<f64> = Quantity::new(2.0);
+/// let density: Quantity<f64, Density<si::kg>> = mass.per(volume);
+/// assert_eq!(density.to_string(), "1.5 kg·m⁻³");
+/// ```
+pub type Density<U> = Div<U, Mul<si::m, Mul<si::m, si::m>>>;
+/// The canonical form of a unit. See [`UnitNormalize`].
+pub type Normalize<U> = <U as UnitNormalize>::Output;
+/// Another name for [`Normalize`].
+pub type Canonical<U> = Normalize<U>;
+/// The power of ten a unit is scaled by, as a [`TenTo`]. See [`UnitScale`].
+pub type PrefixOf<U> = <U as UnitScale>::Prefix;
+/// A unit without its power of ten, such as `si::m` for `si::milli<si::m>`.
+/// See [`UnitScale`].
+pub type BaseOf<U> = <U as UnitScale>::Base;
+/// A unit with its power of ten replaced by `10^N`, such as
+/// `si::kilo<si::m>` for `Rescale<si::milli<si::m>, 3>`.
+pub type Rescale<U, const N: i8> = Mul<BaseOf<U>, TenTo<N>>;
+
+/// Reduce a unit to its canonical form.
+///
+/// Unit expressions that are equal, such as `Mul<si::kg, si::m>` and
+/// `Mul<si::m, si::kg>`, already resolve to the same type once their
+/// operands are known. In generic code they can't be resolved, so bounding
+/// the canonical form of an expression lets it unify with a concrete unit:
+///
+/// ```rust
+/// use uy::{si, Quantity, Unit, UnitNormalize};
+///
+/// fn work<A: Unit, B: Unit>(a: Quantity<f64, A>, b: Quantity<f64, B>) -> Quantity<f64, si::J>
+/// where
+///     A: std::ops::Mul<B>,
+///     uy::Mul<A, B>: UnitNormalize<Output = si::J>,
+/// {
+///     Quantity::new(a.into_value() * b.into_value())
+/// }
+///
+/// let f: Quantity<f64, si::N> = Quantity::new(2.0);
+/// let d: Quantity<f64, si::m> = Quantity::new(3.0);
+/// assert_eq!(work(f, d), work(d, f));
+/// ```
+pub trait UnitNormalize: Unit {
+    type Output: Unit;
+}
+
+/// Split a unit into its power of ten and the unit without it.
+///
+/// Bounding the [`BaseOf`] a unit lets generic code accept a unit with any
+/// prefix, rather than enumerating the prefixes:
+///
+/// ```rust
+/// use uy::{si, BaseOf, Quantity, UnitConvertLossy, UnitScale};
+///
+/// fn total_length<U>(parts: &[Quantity<f64, U>]) -> Quantity<f64, si::m>
+/// where
+///     U: UnitScale<Base = si::m>,
+///     si::m: UnitConvertLossy<f64, U>,
+/// {
+///     parts.iter().map(|&p| p.convert_lossy::<si::m>()).sum()
+/// }
+///
+/// let mm: [Quantity<f64, si::milli<si::m>>; 2] = [Quantity::new(250.0), Quantity::new(750.0)];
+/// let km: [Quantity<f64, si::kilo<si::m>>; 1] = [Quantity::new(1.5)];
+/// assert_eq!(total_length(&mm).into_value(), 1.0);
+/// assert_eq!(total_length(&km).into_value(), 1500.0);
+///
+/// uy::assert_same_unit!(BaseOf<si::micro<si::s>>, si::s);
+/// uy::assert_same_unit!(uy::Rescale<si::milli<si::V>, 3>, si::kilo<si::V>);
+/// ```
+///
+/// Units that aren't a power of ten of their base, such as the ones in
+/// [`units`], are their own base.
+pub trait UnitScale: Unit {
+    /// The power of ten the unit is scaled by, as a [`TenTo`].
+    type Prefix;
+    /// The unit without its power of ten.
+    type Base: Unit;
+}
+
+/// Convert a value between different units, truncating integers when
+/// converting to a coarser unit.
+pub trait UnitConvertLossy<T, From>: Unit {
+    fn unit_convert_lossy(val: T) -> T;
+}
+
+/// Convert a value between different units without truncating it.
+///
+/// Integers can only be converted to finer units, such as from `si::m` to
+/// `si::milli<si::m>`; converting them to a coarser unit is a compile-time
+/// error.
+pub trait UnitConvertExact<T, From>: UnitConvertLossy<T, From> {
+    fn unit_convert(val: T) -> T;
+}
+
+/// Compile-time information about a unit, for display and reflection.
+///
+/// ```rust
+/// use uy::{si, UnitInfo};
+///
+/// assert_eq!(si::kilo::<si::m>::SYMBOL, "km");
+/// assert_eq!(si::milli::<si::kg>::SYMBOL, "g");
+/// assert_eq!(<uy::Div<si::m, si::s>>::SYMBOL, "m·s⁻¹");
+/// assert_eq!(si::N::DIMENSION, &[-2, 1, 1, 0, 0, 0, 0, 0]);
+/// assert_eq!(si::micro::<si::s>::PREFIX, -6);
+/// ```
+pub trait UnitInfo: Unit {
+    /// Symbols of the base units of the unit's system, in declaration order.
+    const BASE_SYMBOLS: &'static [&'static str];
+    /// The exponent of each base unit, in the same order as `BASE_SYMBOLS`.
+    const DIMENSION: &'static [i8];
+    /// The power of ten the unit is scaled by.
+    const PREFIX: i8;
+    /// The symbol of the unit, built from its prefix and base units.
+    const SYMBOL: &'static str;
+    /// The symbol in plain ASCII, such as `m*kg/s^2`, for logging and file
+    /// formats that can't hold Unicode.
+    ///
+    /// ```rust
+    /// use uy::{si, UnitInfo};
+    ///
+    /// assert_eq!(<uy::Div<si::m, si::s>>::UNIT_STR, "m/s");
+    /// assert_eq!(si::N::UNIT_STR, "m*kg/s^2");
+    /// assert_eq!(si::micro::<si::F>::UNIT_STR, "10^-6*s^4*A^2/m^2/kg");
+    /// assert_eq!(si::milli::<si::Sv>::UNIT_STR, "mSv");
+    /// ```
+    const UNIT_STR: &'static str = Self::SYMBOL;
+    /// A factor other than a power of ten that the unit is scaled by, on top
+    /// of `PREFIX`, such as 9.80665 for the kilogram-force. See
+    /// [`ScaledUnit`].
+    const FACTOR: Ratio = Ratio::ONE;
+    /// The symbol of the [`Kind`] of the unit, such as `Sv` for the sievert,
+    /// which tells it apart from other units of the same dimension.
+    const KIND: Option<&'static str> = None;
+}
+
+/// Implemented for pairs of units that only differ by a power-of-ten scale,
+/// such as `si::m` and `si::milli<si::m>`.
+pub trait CommonScale<Rhs: Unit>: Unit {
+    /// The exponent of `Self`'s scale minus the exponent of `Rhs`'s scale.
+    const SCALE_DIFF: i8;
+}
+
+/// Comparing a value of `T` in `Self` with a value in `Rhs`, which is what
+/// `PartialEq` between quantities uses.
+///
+/// Every unit compares with itself for any `T: PartialEq`. Units that only
+/// differ in scale, such as `si::m` and `si::milli<si::m>`, also compare
+/// with each other when `T` is a [`Scalar`]. Generic code comparing
+/// quantities of one unit names this bound:
+///
+/// ```rust
+/// use std::num::NonZeroU32;
+/// use uy::{si, Quantity, ScaleEq};
+///
+/// fn all_equal<T: PartialEq, U: ScaleEq<T, U>>(qs: &[Quantity<T, U>]) -> bool {
+///     qs.windows(2).all(|w| w[0] == w[1])
+/// }
+///
+/// let p: Quantity<NonZeroU32, si::s> = Quantity::new(NonZeroU32::MIN);
+/// assert!(all_equal(&[p, p]));
+/// ```
+pub trait ScaleEq<T, Rhs: Unit>: Unit {
+    fn scale_eq(lhs: &T, rhs: &T) -> bool;
+}
+
+/// Ordering a value of `T` in `Self` against a value in `Rhs`, which is
+/// what `PartialOrd` between quantities uses. Like [`ScaleEq`], every unit
+/// compares with itself for any `T: PartialOrd`.
+pub trait ScaleOrd<T, Rhs: Unit>: ScaleEq<T, Rhs> {
+    fn scale_partial_cmp(lhs: &T, rhs: &T) -> Option<cmp::Ordering>;
+}
+
+/// Implemented for pairs of units with the same base unit exponents,
+/// regardless of their scale, such as `si::m`, `si::milli<si::m>`, and
+/// [`uy::contrib::mil`](crate::contrib::mil).
+///
+/// This lets generic code accept quantities in different units of one
+/// dimension and convert them itself with
+/// [`convert_generic`](Quantity::convert_generic):
+///
+/// ```rust
+/// use uy::{si, units, Quantity, SameDimension, UnitInfo};
+///
+/// fn span<U1: UnitInfo, U2: SameDimension<U1>>(
+///     a: Quantity<f64, U1>,
+///     b: Quantity<f64, U2>,
+/// ) -> Quantity<f64, U1> {
+///     let b = b.convert_generic::<U1>();
+///     Quantity::new((a.into_value() - b.into_value()).abs())
+/// }
+///
+/// let a: Quantity<f64, si::m> = Quantity::new(3.0);
+/// let b: Quantity<f64, si::milli<si::m>> = Quantity::new(500.0);
+/// assert_eq!(span(a, b).into_value(), 2.5);
+///
+/// let force: Quantity<f64, si::N> = Quantity::new(9.80665);
+/// let kgf: Quantity<f64, units::kgf> = Quantity::new(1.0);
+/// assert!(span(force, kgf).into_value() < 1e-12);
+/// ```
+///
+/// ```rust,compile_fail
+/// use uy::{si, Quantity, SameDimension, UnitInfo};
+///
+/// fn span<U1: UnitInfo, U2: SameDimension<U1>>(a: Quantity<f64, U1>, b: Quantity<f64, U2>) {}
+///
+/// span(Quantity::<f64, si::m>::new(3.0), Quantity::<f64, si::s>::new(1.0));
+/// ```
+pub trait SameDimension<Rhs: UnitInfo>: UnitInfo {}
+
+/// Implemented for units whose base units all have an exponent of zero, such
+/// as `si::unitless`, `uy::units::percent`, and `uy::chem::ppm`, at any
+/// scale.
+///
+/// Bounding a unit by this limits generic code to ratios:
+///
+/// ```rust
+/// use uy::{si, units, IsDimensionless, Quantity, SameDimension};
+///
+/// fn gain_db<U>(ratio: Quantity<f64, U>) -> f64
+/// where
+///     U: IsDimensionless + SameDimension<si::unitless>,
+/// {
+///     20.0 * ratio.convert_generic::<si::unitless>().into_value().log10()
+/// }
+///
+/// assert_eq!(gain_db(Quantity::<f64, si::unitless>::new(10.0)), 20.0);
+/// assert!((gain_db(Quantity::<f64, units::percent>::new(1000.0)) - 20.0).abs() < 1e-12);
+/// ```
+///
+/// ```rust,compile_fail
+/// use uy::{si, IsDimensionless, Quantity};
+///
+/// fn ratio<U: IsDimensionless>(q: Quantity<f64, U>) {}
+///
+/// ratio(Quantity::<f64, si::m>::new(3.0));
+/// ```
+pub trait IsDimensionless: UnitInfo {}
+
+/// Implemented for pairs of base units that convert by a factor only known
+/// at runtime, such as currencies and their exchange rates, or raw counts
+/// from devices that are each calibrated differently.
+///
+/// Quantities convert with
+/// [`convert_with_factor`](Quantity::convert_with_factor), which takes the
+/// factor as an argument. Only pairs of units with an impl can be converted,
+/// so the units are still checked at compile time:
+///
+/// ```rust
+/// mod inner {
+///     uy::power_of_ten_unit_system!(Money { USD, EUR, GBP });
+/// }
+///
+/// use inner::Money;
+/// use uy::{si, Quantity, RuntimeConvert};
+///
+/// type USD = Money<0, 1, 0, 0>;
+/// type EUR = Money<0, 0, 1, 0>;
+/// type GBP = Money<0, 0, 0, 1>;
+///
+/// impl RuntimeConvert<EUR> for USD {}
+/// impl RuntimeConvert<GBP> for USD {}
+///
+/// // Euros per dollar, from a live feed.
+/// let usd_eur = 0.92;
+/// let price: Quantity<f64, USD> = Quantity::new(19.99);
+/// let eur: Quantity<f64, si::centi<EUR>> = price.convert_with_factor(usd_eur).unwrap();
+/// assert!((eur.into_value() - 1839.08).abs() < 1e-9);
+///
+/// let fee: Quantity<i64, si::centi<USD>> = Quantity::new(250);
+/// let pence: Quantity<i64, si::centi<GBP>> = fee.convert_with_factor(3).unwrap();
+/// assert_eq!(pence.into_value(), 750);
+///
+/// // Integers that can't hold the converted value exactly are an error.
+/// let pounds = fee.convert_with_factor::<GBP>(3);
+/// assert_eq!(pounds.unwrap_err(), uy::Error::Truncation);
+/// ```
+///
+/// ```rust,compile_fail
+/// mod inner {
+///     uy::power_of_ten_unit_system!(Money { USD, EUR });
+/// }
+///
+/// use inner::Money;
+/// use uy::{Quantity, RuntimeConvert};
+///
+/// type USD = Money<0, 1, 0>;
+/// type EUR = Money<0, 0, 1>;
+///
+/// impl RuntimeConvert<EUR> for USD {}
+///
+/// let price: Quantity<f64, EUR> = Quantity::new(18.39);
+/// let usd: Quantity<f64, USD> = price.convert_with_factor(1.087).unwrap();
+/// ```
+pub trait RuntimeConvert<Y: Unit>: Unit {}
+
+/// The finer of two units that only differ by a power-of-ten scale, such as
+/// `si::milli<si::W>` for `si::W` and `si::milli<si::W>`.
+pub trait FinerScale<Rhs: Unit>: CommonScale<Rhs> {
+    type Output: Unit;
+}
+
+/// The `N`th root of a unit, for units whose scale and base unit exponents
+/// are all divisible by `N`, such as `si::m` for `Mul<si::m, si::m>`.
+pub trait UnitRoot<const N: i8>: Unit {
+    type Output: Unit;
+}
+
+/// Bring two values that are `diff` powers of ten apart to the scale of the
+/// finer one. Scaling towards the finer unit never truncates integers.
+#[inline]
+fn to_common_scale<T: MulPowerOfTen>(lhs: T, rhs: T, diff: i8) -> (T, T) {
+    if diff < 0 {
+        (lhs, rhs.mul_power_of_ten(diff))
+    } else {
+        (lhs.mul_power_of_ten(-diff), rhs)
+    }
+}
+
+/// Scale a value in the coarser of two units `diff` powers of ten apart to
+/// the finer one, or `None` if it doesn't fit. Saturating and wrapping
+/// integers scale without failing, so a result that doesn't scale back to
+/// the value was clamped or wrapped and doesn't fit either.
+#[inline]
+fn to_finer_scale<T: Scalar>(coarse: &T, diff: i8) -> Option<T> {
+    let scaled = coarse.clone().checked_mul_power_of_ten(-diff.abs())?;
+    if T::TRUNCATES
+        && scaled.clone().checked_mul_power_of_ten(diff.abs()).as_ref() != Some(coarse)
+    {
+        return None;
+    }
+    Some(scaled)
+}
+
+/// Whether two values that are `diff` powers of ten apart are equal. A value
+/// too large to scale to the finer unit can't equal anything in it.
+#[inline]
+pub(crate) fn eq_at_common_scale<T: Scalar>(lhs: &T, rhs: &T, diff: i8) -> bool {
+    let (coarse, fine) = if diff < 0 { (rhs, lhs) } else { (lhs, rhs) };
+    to_finer_scale(coarse, diff).is_some_and(|scaled| scaled == *fine)
+}
+
+/// Compare two values that are `diff` powers of ten apart at the scale of
+/// the finer one. A value too large to scale is larger in magnitude than any
+/// value in the finer unit, so its sign decides.
+#[inline]
+pub(crate) fn partial_cmp_at_common_scale<T: Scalar + PartialOrd>(
+    lhs: &T,
+    rhs: &T,
+    diff: i8,
+) -> Option<cmp::Ordering> {
+    let (coarse, fine) = if diff < 0 { (rhs, lhs) } else { (lhs, rhs) };
+    let ord = match to_finer_scale(coarse, diff) {
+        Some(scaled) => scaled.partial_cmp(fine)?,
+        None => coarse.partial_cmp(&(coarse.clone() - coarse.clone()))?,
+    };
+    Some(if diff < 0 { ord.reverse() } else { ord })
+}
+
+/// The Euclidean norm of a two-component vector of quantities.
+///
+/// ```rust
+/// # use uy::{si, Quantity};
+/// let n = uy::norm2::<f32, si::N>(Quantity::new(6.0), Quantity::new(8.0));
+/// assert_eq!(n.into_value(), 10.0);
+/// ```
+#[inline]
+pub fn norm2<T: num_traits::Float, U: Unit>(
+    x: Quantity<T, U>,
+    y: Quantity<T, U>,
+) -> Quantity<T, U> {
+    x.hypot(y)
+}
+
+/// The Euclidean norm of a three-component vector of quantities.
+///
+/// ```rust
+/// # use uy::{si, Quantity};
+/// let n = uy::norm3::<f64, si::m>(Quantity::new(2.0), Quantity::new(3.0), Quantity::new(6.0));
+/// assert_eq!(n.into_value(), 7.0);
+/// ```
+#[inline]
+pub fn norm3<T: num_traits::Float, U: Unit>(
+    x: Quantity<T, U>,
+    y: Quantity<T, U>,
+    z: Quantity<T, U>,
+) -> Quantity<T, U> {
+    x.hypot(y).hypot(z)
+}
+
+/// Linearly interpolate between `a` and `b`, where `t = 0` gives `a` and
+/// `t = 1` gives `b`.
+///
+/// ```rust
+/// # use uy::{si, Quantity};
+/// let a: Quantity<f64, si::V> = Quantity::new(2.0);
+/// let b: Quantity<f64, si::V> = Quantity::new(4.0);
+/// assert_eq!(uy::lerp(a, b, Quantity::new(0.25)).into_value(), 2.5);
+/// ```
+#[inline]
+pub fn lerp<T: num_traits::Float, U: Unit>(
+    a: Quantity<T, U>,
+    b: Quantity<T, U>,
+    t: Quantity<T, si::unitless>,
+) -> Quantity<T, U> {
+    Quantity::new(a.val + (b.val - a.val) * t.val)
+}
+
+/// The inverse of [`lerp`]: where `x` lies between `a` and `b`, as a ratio.
+///
+/// ```rust
+/// # use uy::{si, Quantity};
+/// let a: Quantity<f64, si::K> = Quantity::new(273.15);
+/// let b: Quantity<f64, si::K> = Quantity::new(373.15);
+/// let t = uy::inv_lerp(a, b, Quantity::new(298.15));
+/// assert!((t.into_value() - 0.25).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn inv_lerp<T: num_traits::Float, U: Unit>(
+    a: Quantity<T, U>,
+    b: Quantity<T, U>,
+    x: Quantity<T, U>,
+) -> Quantity<T, si::unitless> {
+    Quantity::new((x.val - a.val) / (b.val - a.val))
+}
+
+/// A physical quantity with a defined unit.
+///
+/// A quantity is stored exactly like its value, so wrapping values in
+/// quantities costs nothing at runtime:
+///
+/// ```rust
+/// use std::mem::{align_of, size_of};
+/// use uy::{si, Quantity};
+///
+/// assert_eq!(size_of::<Quantity<f64, si::m>>(), size_of::<f64>());
+/// assert_eq!(align_of::<Quantity<u8, si::kilo<si::N>>>(), align_of::<u8>());
+/// assert_eq!(size_of::<[Quantity<f32, si::s>; 4]>(), size_of::<[f32; 4]>());
+/// assert_eq!(size_of::<Option<Quantity<Box<f32>, si::s>>>(), size_of::<Box<f32>>());
+/// ```
+#[repr(transparent)]
+pub struct Quantity<T, U: Unit> {
+    val: T,
+    _marker: PhantomData<U>,
+}
+
+impl<T> Quantity<T, si::unitless> {
+    /// Create a quantity in the unit `U`, for when it can't be inferred.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let d = Quantity::of::<si::kilo<si::m>>(4.2);
+    /// assert_eq!(d.to_string(), "4.2 km");
+    /// ```
+    #[inline]
+    pub fn of<U: Unit>(val: T) -> Quantity<T, U> {
+        Quantity::new(val)
+    }
+}
+
+impl<T, U: Unit> Quantity<T, U> {
+    /// Create a quantity from a value.
+    #[inline]
+    pub fn new(val: T) -> Self {
+        Self {
+            val,
+            _marker: PhantomData,
+        }
+    }
+
+    /// A quantity with a value of zero.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<f64, si::J> = Quantity::zero();
+    /// assert_eq!(a.into_value(), 0.0);
+    /// ```
+    #[inline]
+    pub fn zero() -> Self
+    where
+        T: num_traits::Zero,
+    {
+        Self::new(T::zero())
+    }
+
+    /// The reciprocal of the quantity, in the inverse unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let r: Quantity<f64, si::Ohm> = Quantity::new(4.0);
+    /// let g: Quantity<f64, si::S> = r.recip();
+    /// assert_eq!(g.into_value(), 0.25);
+    /// ```
+    #[inline]
+    pub fn recip(self) -> Quantity<T, Inv<U>>
+    where
+        T: num_traits::Float,
+        si::unitless: ops::Div<U>,
+        Inv<U>: Unit,
+    {
+        Quantity::new(self.val.recip())
+    }
+
+    /// The ratio of two quantities, such as a rate or a gain. This is the
+    /// same as `self / rhs`, but reads better in a chain.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let dose: Quantity<f64, si::milli<si::kg>> = Quantity::new(500.0);
+    /// let time: Quantity<f64, si::s> = Quantity::new(4.0);
+    /// let rate: Quantity<f64, uy::Rate<si::milli<si::kg>>> = dose.per(time);
+    /// assert_eq!(rate.into_value(), 125.0);
+    /// ```
+    #[inline]
+    pub fn per<U2: Unit>(self, rhs: Quantity<T, U2>) -> Quantity<T, Div<U, U2>>
+    where
+        T: ops::Div<Output = T>,
+        U: ops::Div<U2>,
+        Div<U, U2>: Unit,
+    {
+        Quantity::new(self.val / rhs.val)
+    }
+
+    /// The square of the quantity, in the square of its unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let r: Quantity<f64, si::m> = Quantity::new(2.0);
+    /// let area: Quantity<f64, uy::Mul<si::m, si::m>> = r.squared();
+    /// assert_eq!(area.into_value(), 4.0);
+    /// ```
+    #[inline]
+    pub fn squared(self) -> Quantity<T, Mul<U, U>>
+    where
+        T: Clone + ops::Mul<Output = T>,
+        U: ops::Mul<U>,
+        Mul<U, U>: Unit,
+    {
+        Quantity::new(self.val.clone() * self.val)
+    }
+
+    /// The cube of the quantity, in the cube of its unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let side: Quantity<i32, si::centi<si::m>> = Quantity::new(3);
+    /// let volume = side.cubed();
+    /// assert_eq!(volume.to_string(), "27 10⁻⁶·m³");
+    /// ```
+    #[inline]
+    pub fn cubed(self) -> Quantity<T, Mul<Mul<U, U>, U>>
+    where
+        T: Clone + ops::Mul<Output = T>,
+        U: ops::Mul<U>,
+        Mul<U, U>: Unit + ops::Mul<U>,
+        Mul<Mul<U, U>, U>: Unit,
+    {
+        Quantity::new(self.val.clone() * self.val.clone() * self.val)
+    }
+
+    /// The square root of the quantity, in the square root of its unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let v2: Quantity<f64, uy::Div<uy::Mul<si::m, si::m>, uy::Mul<si::s, si::s>>> = Quantity::new(9.0);
+    /// let v: Quantity<f64, uy::Div<si::m, si::s>> = v2.sqrt();
+    /// assert_eq!(v.into_value(), 3.0);
+    /// ```
+    ///
+    /// Units with an odd exponent have no square root:
+    ///
+    /// ```rust,compile_fail
+    /// # use uy::{si, Quantity};
+    /// let x: Quantity<f64, si::J> = Quantity::new(9.0);
+    /// let _: Quantity<f64, _> = x.sqrt();
+    /// ```
+    #[inline]
+    pub fn sqrt(self) -> Quantity<T, <U as UnitRoot<2>>::Output>
+    where
+        T: num_traits::Float,
+        U: UnitRoot<2>,
+    {
+        Quantity::new(self.val.sqrt())
+    }
+
+    /// The cube root of the quantity, in the cube root of its unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let volume: Quantity<f64, uy::Mul<si::milli<si::m>, uy::Mul<si::milli<si::m>, si::milli<si::m>>>> =
+    ///     Quantity::new(27.0);
+    /// let side: Quantity<f64, si::milli<si::m>> = volume.cbrt();
+    /// assert_eq!(side.into_value(), 3.0);
+    /// ```
+    #[inline]
+    pub fn cbrt(self) -> Quantity<T, <U as UnitRoot<3>>::Output>
+    where
+        T: num_traits::Float,
+        U: UnitRoot<3>,
+    {
+        Quantity::new(self.val.cbrt())
+    }
+
+    /// Fused multiply-add: `self * a + b` with a single rounding.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let v: Quantity<f64, uy::Div<si::m, si::s>> = Quantity::new(3.0);
+    /// let dt: Quantity<f64, si::s> = Quantity::new(0.5);
+    /// let x: Quantity<f64, si::m> = Quantity::new(10.0);
+    /// let x: Quantity<f64, si::m> = v.mul_add(dt, x);
+    /// assert_eq!(x.into_value(), 11.5);
+    /// ```
+    #[inline]
+    pub fn mul_add<U2: Unit>(
+        self,
+        a: Quantity<T, U2>,
+        b: Quantity<T, Mul<U, U2>>,
+    ) -> Quantity<T, Mul<U, U2>>
+    where
+        T: num_traits::Float,
+        U: ops::Mul<U2>,
+        Mul<U, U2>: Unit,
+    {
+        Quantity::new(self.val.mul_add(a.val, b.val))
+    }
+
+    /// The length of the hypotenuse of a right triangle with legs `self` and
+    /// `other`, computed without intermediate overflow.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let x: Quantity<f64, si::m> = Quantity::new(3.0);
+    /// let y: Quantity<f64, si::m> = Quantity::new(4.0);
+    /// assert_eq!(x.hypot(y).into_value(), 5.0);
+    /// ```
+    #[inline]
+    pub fn hypot(self, other: Self) -> Self
+    where
+        T: num_traits::Float,
+    {
+        Quantity::new(self.val.hypot(other.val))
+    }
+
+    /// Convert between quantities with different units or the same units
+    /// with different scales.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<i32, si::m> = Quantity::new(3);
+    /// let b: Quantity<i32, si::milli<si::m>> = a.convert();
+    /// assert_eq!(b.into_value(), 3000);
+    /// ```
+    ///
+    /// Integers can't be converted to a coarser unit this way, since that
+    /// could truncate them. Use [`try_convert`](Self::try_convert) or
+    /// [`convert_lossy`](Self::convert_lossy) instead. With the
+    /// `strict-conversions` feature, integers that overflow the finer unit
+    /// panic in debug builds, pointing at the call that should become
+    /// [`checked_convert`](Self::checked_convert).
+    ///
+    /// ```rust,compile_fail
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<i32, si::milli<si::m>> = Quantity::new(3500);
+    /// let b: Quantity<i32, si::m> = a.convert();
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn convert<Y: UnitConvertExact<T, U>>(self) -> Quantity<T, Y> {
+        Quantity::new(Y::unit_convert(self.val))
+    }
+
+    /// Convert to another spelling of the same unit, one with the same
+    /// [canonical form](UnitNormalize), without changing the value.
+    ///
+    /// Concrete spellings such as `Mul<si::s, si::A>` and `si::C` are
+    /// already the same type, but in generic code the compiler can't see
+    /// that `Mul<A, B>` is `si::C` even when bounds say so. This converts
+    /// between them, for helper functions that build a unit one way and
+    /// return it another.
+    ///
+    /// ```rust
+    /// use std::ops;
+    /// use uy::{si, Quantity, Unit, UnitNormalize};
+    ///
+    /// fn charge<A: Unit, B: Unit>(a: Quantity<f64, A>, b: Quantity<f64, B>) -> Quantity<f64, si::C>
+    /// where
+    ///     A: ops::Mul<B>,
+    ///     uy::Mul<A, B>: UnitNormalize<Output = si::C>,
+    /// {
+    ///     (a * b).convert_equivalent()
+    /// }
+    ///
+    /// let t: Quantity<f64, si::s> = Quantity::new(3.0);
+    /// let i: Quantity<f64, si::A> = Quantity::new(2.0);
+    /// assert_eq!(charge(t, i).into_value(), 6.0);
+    /// assert_eq!(charge(i, t).into_value(), 6.0);
+    /// ```
+    ///
+    /// A unit with a different scale isn't another spelling of the same
+    /// unit, so it needs [`convert`](Self::convert):
+    ///
+    /// ```rust,compile_fail
+    /// use uy::{si, Quantity};
+    ///
+    /// let t: Quantity<f64, si::s> = Quantity::new(3.0);
+    /// let _: Quantity<f64, si::milli<si::s>> = t.convert_equivalent();
+    /// ```
+    #[inline]
+    pub fn convert_equivalent<Y>(self) -> Quantity<T, Y>
+    where
+        U: UnitNormalize,
+        Y: UnitNormalize<Output = Normalize<U>>,
+    {
+        Quantity::new(self.val)
+    }
+
+    /// Convert to the canonical form of the unit. See [`UnitNormalize`].
+    #[inline]
+    pub fn normalize(self) -> Quantity<T, Normalize<U>>
+    where
+        U: UnitNormalize,
+    {
+        Quantity::new(self.val)
+    }
+
+    /// Convert to another unit like [`convert`](Quantity::convert), but
+    /// `None` if the value overflows its type.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<i32, si::s> = Quantity::new(2_000_000);
+    /// assert_eq!(a.checked_convert::<si::milli<si::s>>().unwrap().into_value(), 2_000_000_000);
+    /// assert!(a.checked_convert::<si::micro<si::s>>().is_none());
+    /// ```
+    #[inline]
+    pub fn checked_convert<Y>(self) -> Option<Quantity<T, Y>>
+    where
+        T: MulPowerOfTen,
+        Y: UnitConvertExact<T, U>,
+        U: CommonScale<Y>,
+    {
+        self.val
+            .checked_mul_power_of_ten(-U::SCALE_DIFF)
+            .map(Quantity::new)
+    }
+
+    /// Convert to another unit like [`convert`](Quantity::convert), but
+    /// clamped to the bounds of the type if the value overflows it, for code
+    /// that can neither fail nor panic.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<u32, si::s> = Quantity::new(5);
+    /// assert_eq!(a.convert_saturating::<si::nano<si::s>>().into_value(), u32::MAX);
+    /// assert_eq!(a.convert_saturating::<si::milli<si::s>>().into_value(), 5000);
+    ///
+    /// let b: Quantity<i16, si::m> = Quantity::new(-40);
+    /// assert_eq!(b.convert_saturating::<si::milli<si::m>>().into_value(), i16::MIN);
+    /// ```
+    #[inline]
+    pub fn convert_saturating<Y>(self) -> Quantity<T, Y>
+    where
+        T: MulPowerOfTen,
+        Y: UnitConvertExact<T, U>,
+        U: CommonScale<Y>,
+    {
+        Quantity::new(self.val.saturating_mul_power_of_ten(-U::SCALE_DIFF))
+    }
+
+    /// Convert to another unit, failing with [`Error::Truncation`] if an
+    /// integer value isn't a whole number in the new unit, and with
+    /// [`Error::Overflow`] if the value doesn't fit in its type.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<i32, si::milli<si::m>> = Quantity::new(3000);
+    /// assert_eq!(a.try_convert::<si::m>().unwrap().into_value(), 3);
+    ///
+    /// let b: Quantity<i32, si::milli<si::m>> = Quantity::new(3500);
+    /// assert_eq!(b.try_convert::<si::m>().unwrap_err(), uy::Error::Truncation);
+    ///
+    /// let c: Quantity<i32, si::s> = Quantity::new(3_000_000);
+    /// assert_eq!(c.try_convert::<si::nano<si::s>>().unwrap_err(), uy::Error::Overflow);
+    /// ```
+    #[inline]
+    pub fn try_convert<Y>(self) -> Result<Quantity<T, Y>, Error>
+    where
+        T: Scalar,
+        Y: UnitConvertLossy<T, U>,
+        U: CommonScale<Y>,
+    {
+        let converted = self
+            .val
+            .clone()
+            .checked_mul_power_of_ten(-U::SCALE_DIFF)
+            .ok_or(Error::Overflow)?;
+        if T::TRUNCATES
+            && converted.clone().checked_mul_power_of_ten(U::SCALE_DIFF) != Some(self.val)
+        {
+            return Err(Error::Truncation);
+        }
+        Ok(Quantity::new(converted))
+    }
+
+    /// Convert a floating-point quantity to another unit, failing if the
+    /// result doesn't convert back to the original value to within a
+    /// relative error of `2 * T::epsilon()`, which allows for the rounding
+    /// of the conversion each way. A result that overflows to infinity is an
+    /// [`Error::Overflow`], and one that underflows to zero or a subnormal,
+    /// or that doesn't convert back, is an [`Error::PrecisionLoss`].
+    ///
+    /// ```rust
+    /// # use uy::{si, Error, Quantity};
+    /// let a: Quantity<f32, si::kilo<si::m>> = Quantity::new(1.5);
+    /// assert_eq!(a.convert_exact::<si::milli<si::m>>().unwrap().into_value(), 1.5e6);
+    ///
+    /// let far: Quantity<f32, si::kilo<si::m>> = Quantity::new(1e10);
+    /// assert_eq!(far.convert_exact::<si::quecto<si::m>>().unwrap_err(), Error::Overflow);
+    ///
+    /// let tiny: Quantity<f32, si::quecto<si::m>> = Quantity::new(1e-20);
+    /// assert_eq!(tiny.convert_exact::<si::kilo<si::m>>().unwrap_err(), Error::PrecisionLoss);
+    /// ```
+    #[inline]
+    pub fn convert_exact<Y>(self) -> Result<Quantity<T, Y>, Error>
+    where
+        T: num_traits::Float,
+        Y: UnitConvertLossy<T, U>,
+        U: UnitConvertLossy<T, Y>,
+    {
+        let converted = Y::unit_convert_lossy(self.val);
+        if !self.val.is_finite() || self.val.is_zero() {
+            return Ok(Quantity::new(converted));
+        }
+        if converted.is_infinite() {
+            return Err(Error::Overflow);
+        }
+        if !converted.is_normal() {
+            return Err(Error::PrecisionLoss);
+        }
+        let back = U::unit_convert_lossy(converted);
+        // The round trip rounds twice, so allow an epsilon each way.
+        let two = T::one() + T::one();
+        if (back - self.val).abs() > two * self.val.abs() * T::epsilon() {
+            return Err(Error::PrecisionLoss);
+        }
+        Ok(Quantity::new(converted))
+    }
+
+    /// Convert to another unit, rounding integers as chosen by `rounding`
+    /// when converting to a coarser unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity, Rounding};
+    /// let a: Quantity<i32, si::milli<si::m>> = Quantity::new(-2500);
+    /// assert_eq!(a.convert_with::<si::m>(Rounding::Nearest).into_value(), -3);
+    /// assert_eq!(a.convert_with::<si::m>(Rounding::Ceil).into_value(), -2);
+    /// ```
+    #[inline]
+    pub fn convert_with<Y>(self, rounding: Rounding) -> Quantity<T, Y>
+    where
+        T: MulPowerOfTen,
+        Y: UnitConvertLossy<T, U>,
+        U: CommonScale<Y>,
+    {
+        Quantity::new(self.val.mul_power_of_ten_rounded(-U::SCALE_DIFF, rounding))
+    }
+
+    /// Convert to another unit, truncating integers towards zero when
+    /// converting to a coarser unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<i32, si::milli<si::m>> = Quantity::new(3500);
+    /// let b: Quantity<i32, si::m> = a.convert_lossy();
+    /// assert_eq!(b.into_value(), 3);
+    /// ```
+    #[inline]
+    pub fn convert_lossy<Y: UnitConvertLossy<T, U>>(self) -> Quantity<T, Y> {
+        Quantity::new(Y::unit_convert_lossy(self.val))
+    }
+
+    /// Convert a floating-point quantity to any unit of the same dimension.
+    ///
+    /// This only needs the [`SameDimension`] bound, so it works in generic
+    /// code where [`convert`](Quantity::convert) would need a bound for each
+    /// pair of units. The scale factor is computed at runtime as an `f64`.
+    #[inline]
+    pub fn convert_generic<Y: UnitInfo>(self) -> Quantity<T, Y>
+    where
+        T: num_traits::Float,
+        U: SameDimension<Y>,
+    {
+        let factor = 10f64.powi(i32::from(U::PREFIX) - i32::from(Y::PREFIX)) * U::FACTOR.to_f64()
+            / Y::FACTOR.to_f64();
+        Quantity::new(self.val * T::from(factor).unwrap())
+    }
 
-            #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-            pub struct $system<const EXP: i8, $(const [<$unit:upper>]: i8),*>;
+    /// Convert to a unit whose base unit is `factor` times smaller than this
+    /// one's, for units whose conversion is only known at runtime. See
+    /// [`RuntimeConvert`].
+    ///
+    /// The factor is between the base units, such as an exchange rate in
+    /// euros per dollar, and any difference in prefixes is applied on top.
+    /// Like parsing, this fails with [`Error::Overflow`] if the prefixes
+    /// overflow the value, or [`Error::Truncation`] if an integer would be
+    /// truncated.
+    #[inline]
+    pub fn convert_with_factor<Y: UnitScale + UnitInfo>(
+        self,
+        factor: T,
+    ) -> Result<Quantity<T, Y>, Error>
+    where
+        T: Scalar,
+        U: UnitScale + UnitInfo,
+        BaseOf<U>: RuntimeConvert<BaseOf<Y>> + UnitInfo,
+        BaseOf<Y>: UnitInfo,
+    {
+        let exp = (Y::PREFIX - BaseOf::<Y>::PREFIX) - (U::PREFIX - BaseOf::<U>::PREFIX);
+        let scaled = self.val * factor;
+        let converted = scaled
+            .clone()
+            .checked_mul_power_of_ten(exp)
+            .ok_or(Error::Overflow)?;
+        if T::TRUNCATES && exp > 0 && converted.clone().mul_power_of_ten(-exp) != scaled {
+            return Err(Error::Truncation);
+        }
+        Ok(Quantity::new(converted))
+    }
 
-            impl<const EXP: i8, $(const [<$unit:upper>]: i8),*> crate::Unit for $system<EXP, $({ [<$unit:upper>] }),*> {}
+    /// Borrow the raw value, discarding the unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<f32, si::m> = Quantity::new(1.5);
+    /// assert_eq!(*a.value(), 1.5);
+    /// ```
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.val
+    }
 
-            impl<
-                const EXP: i8,
-                const N: i8,
-                $(const [<$unit:upper>]: i8),*
-            > std::ops::Mul<crate::TenTo<{ N }>> for $system<EXP, $({ [<$unit:upper>] }),*>
-            where
-                crate::inner::Const<EXP>: std::ops::Add<crate::inner::Const<N>>,
-                [<Typenum $system>]<
-                    <crate::inner::Const<EXP> as std::ops::Add<crate::inner::Const<N>>>::Output,
-                    $( crate::inner::Const<{ [<$unit:upper>] }> ),*
-                >: crate::inner::ToConst,
-            {
-                type Output = <[<Typenum $system>]<
-                    <crate::inner::Const<EXP> as std::ops::Add<crate::inner::Const<N>>>::Output,
-                    $( crate::inner::Const<{ [<$unit:upper>] }> ),*
-                > as crate::inner::ToConst>::Output;
+    /// Mutably borrow the raw value.
+    #[inline]
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.val
+    }
 
-                fn mul(self, _rhs: crate::TenTo<N>) -> Self::Output {
-                    crate::inner::ToConst::to_const([<Typenum $system>](std::marker::PhantomData))
-                }
-            }
+    /// Update the raw value in place, keeping the unit. Unlike writing
+    /// through [`value_mut`](Quantity::value_mut) or `DerefMut`, the
+    /// mutation is scoped to the closure, so it stands out in review and
+    /// can't be used to swap in a value in another unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let mut v: Quantity<f64, si::V> = Quantity::new(3.3);
+    /// v.update(|v| *v *= 2.0);
+    /// assert_eq!(v.into_value(), 6.6);
+    /// ```
+    #[inline]
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self.val)
+    }
 
-            impl<
-                const EXP: i8,
-                const N: i8,
-                $(const [<$unit:upper>]: i8),*
-            > std::ops::Div<crate::TenTo<N>> for $system<EXP, $({ [<$unit:upper>] }),*>
-            where
-                crate::inner::Const<EXP>: std::ops::Sub<crate::inner::Const<N>>,
-                [<Typenum $system>]<
-                    <crate::inner::Const<EXP> as std::ops::Sub<crate::inner::Const<N>>>::Output,
-                    $( crate::inner::Const<{ [<$unit:upper>] }> ),*
-                >: crate::inner::ToConst,
-            {
-                type Output = <[<Typenum $system>]<
-                    <crate::inner::Const<EXP> as std::ops::Sub<crate::inner::Const<N>>>::Output,
-                    $( crate::inner::Const<{ [<$unit:upper>] }> ),*
-                > as crate::inner::ToConst>::Output;
+    /// Replace the quantity with another in the same unit, returning the
+    /// old one.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let mut setpoint: Quantity<f64, si::K> = Quantity::new(293.15);
+    /// let old = setpoint.set(Quantity::new(298.15));
+    /// assert_eq!(old.into_value(), 293.15);
+    /// assert_eq!(setpoint.into_value(), 298.15);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use uy::{si, Quantity};
+    /// let mut setpoint: Quantity<f64, si::K> = Quantity::new(293.15);
+    /// setpoint.set(Quantity::<f64, si::milli<si::K>>::new(298_150.0));
+    /// ```
+    #[inline]
+    pub fn set(&mut self, q: Self) -> Self {
+        std::mem::replace(self, q)
+    }
 
-                fn div(self, _rhs: crate::TenTo<N>) -> Self::Output {
-                    crate::inner::ToConst::to_const([<Typenum $system>](std::marker::PhantomData))
-                }
-            }
+    /// Unwrap the quantity into its raw value, discarding the unit.
+    #[inline]
+    pub fn into_value(self) -> T {
+        self.val
+    }
 
-            impl<
-                const EXP1: i8,
-                const EXP2: i8,
-                $(const [<$unit:upper 1>]: i8, const [<$unit:upper 2>]: i8),*
-            > std::ops::Mul<$system<EXP2, $({ [<$unit:upper 2>] }),*>> for $system<EXP1, $({ [<$unit:upper 1>] }),*>
-            where
-                crate::inner::Const<EXP1>: std::ops::Add<crate::inner::Const<EXP2>>,
+    /// Attach the unit `U` to a raw value, for FFI and serialization layers
+    /// that store quantities as plain numbers.
+    ///
+    /// This is the unchecked escape hatch: nothing checks that `val` is
+    /// actually in `U`, so it should only be used where the unit of the raw
+    /// value is fixed by a format or an ABI. It doesn't depend on the
+    /// `deref` or `from` features, and a quantity is always
+    /// `#[repr(transparent)]` over its value, so
+    /// `Quantity::from_raw(q.into_raw())` is the identity and the two have
+    /// the same layout.
+    ///
+    /// ```rust
+    /// use uy::{si, Quantity};
+    ///
+    /// const TIMEOUT: Quantity<u32, si::milli<si::s>> = Quantity::from_raw(250);
+    ///
+    /// // The wire format stores the timeout as milliseconds.
+    /// let wire: u32 = TIMEOUT.into_raw();
+    /// assert_eq!(Quantity::<u32, si::milli<si::s>>::from_raw(wire).into_value(), 250);
+    /// ```
+    #[inline]
+    pub const fn from_raw(val: T) -> Self {
+        Self {
+            val,
+            _marker: PhantomData,
+        }
+    }
 
-                $( crate::inner::Const<{ [<$unit:upper 1>] }>: std::ops::Add<crate::inner::Const<{ [<$unit:upper 2>] }>>, )*
-                [<Typenum $system>]<
-                    <crate::inner::Const<EXP1> as std::ops::Add<crate::inner::Const<EXP2>>>::Output,
-                    $( <crate::inner::Const<{ [<$unit:upper 1>] }> as std::ops::Add<crate::inner::Const<{ [<$unit:upper 2>] }>>>::Output ),*
-                >: crate::inner::ToConst,
-            {
-                type Output = <[<Typenum $system>]<
-                    <crate::inner::Const<EXP1> as std::ops::Add<crate::inner::Const<EXP2>>>::Output,
-                    $( <crate::inner::Const<{ [<$unit:upper 1>] }> as std::ops::Add<crate::inner::Const<{ [<$unit:upper 2>] }>>>::Output ),*
-                > as crate::inner::ToConst>::Output;
+    /// Unwrap the quantity into its raw value in `U`, the inverse of
+    /// [`from_raw`](Quantity::from_raw). This is `const`, so it needs a
+    /// `Copy` value; use [`into_value`](Quantity::into_value) for others.
+    #[inline]
+    pub const fn into_raw(self) -> T
+    where
+        T: Copy,
+    {
+        self.val
+    }
 
-                fn mul(self, _rhs: $system<EXP2, $({ [<$unit:upper 2>] }),*>) -> Self::Output {
-                    crate::inner::ToConst::to_const([<Typenum $system>](std::marker::PhantomData))
-                }
-            }
+    /// Apply a function to the value, keeping the unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<f64, si::m> = Quantity::new(-2.5);
+    /// let b: Quantity<f64, si::m> = a.map(f64::abs);
+    /// assert_eq!(b.into_value(), 2.5);
+    /// ```
+    #[inline]
+    pub fn map<T2>(self, f: impl FnOnce(T) -> T2) -> Quantity<T2, U> {
+        Quantity::new(f(self.val))
+    }
 
-            impl<
-                const EXP1: i8,
-                const EXP2: i8,
-                $(const [<$unit:upper 1>]: i8, const [<$unit:upper 2>]: i8),*
-            > std::ops::Div<$system<EXP2, $([<$unit:upper 2>]),*>> for $system<EXP1, $([<$unit:upper 1>]),*>
-            where
-                crate::inner::Const<EXP1>: std::ops::Sub<crate::inner::Const<EXP2>>,
+    /// Apply a fallible function to the value, keeping the unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<i64, si::s> = Quantity::new(300);
+    /// let b: Result<Quantity<u8, si::s>, _> = a.try_map(u8::try_from);
+    /// assert!(b.is_err());
+    /// ```
+    #[inline]
+    pub fn try_map<T2, E>(
+        self,
+        f: impl FnOnce(T) -> Result<T2, E>,
+    ) -> Result<Quantity<T2, U>, E> {
+        f(self.val).map(Quantity::new)
+    }
 
-                $( crate::inner::Const<[<$unit:upper 1>]>: std::ops::Sub<crate::inner::Const<[<$unit:upper 2>]>>, )*
-                [<Typenum $system>]<
-                    <crate::inner::Const<EXP1> as std::ops::Sub<crate::inner::Const<EXP2>>>::Output,
-                    $( <crate::inner::Const<[<$unit:upper 1>]> as std::ops::Sub<crate::inner::Const<[<$unit:upper 2>]>>>::Output ),*
-                >: crate::inner::ToConst,
-            {
-                type Output = <[<Typenum $system>]<
-                    <crate::inner::Const<EXP1> as std::ops::Sub<crate::inner::Const<EXP2>>>::Output,
-                    $( <crate::inner::Const<[<$unit:upper 1>]> as std::ops::Sub<crate::inner::Const<[<$unit:upper 2>]>>>::Output ),*
-                > as crate::inner::ToConst>::Output;
+    /// Combine the values of two quantities with the same unit.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<f32, si::V> = Quantity::new(3.3);
+    /// let b: Quantity<f32, si::V> = Quantity::new(5.0);
+    /// assert_eq!(a.zip_with(b, f32::max).into_value(), 5.0);
+    /// ```
+    #[inline]
+    pub fn zip_with<T2, T3>(
+        self,
+        other: Quantity<T2, U>,
+        f: impl FnOnce(T, T2) -> T3,
+    ) -> Quantity<T3, U> {
+        Quantity::new(f(self.val, other.val))
+    }
 
-                fn div(self, _rhs: $system<EXP2, $([<$unit:upper 2>]),*>) -> Self::Output {
-                    crate::inner::ToConst::to_const([<Typenum $system>](std::marker::PhantomData))
-                }
-            }
+    /// Cast the value to another primitive numeric type, keeping the unit.
+    ///
+    /// This has the semantics of an `as` cast, so narrowing casts may
+    /// truncate or saturate. Use [`Quantity::try_cast`] to detect that.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<f64, si::m> = Quantity::new(1.25);
+    /// let b: Quantity<f32, si::m> = a.cast();
+    /// assert_eq!(b.into_value(), 1.25f32);
+    /// ```
+    #[inline]
+    pub fn cast<T2>(self) -> Quantity<T2, U>
+    where
+        T: num_traits::AsPrimitive<T2>,
+        T2: Copy + 'static,
+    {
+        Quantity::new(self.val.as_())
+    }
 
-            impl<
-                T,
-                const EXP1: i8,
-                const EXP2: i8,
-                $(const [<$unit:upper>]: i8),*
-            > crate::UnitConvert<T, $system<EXP1, $([<$unit:upper>]),*>> for $system<EXP2, $([<$unit:upper>]),*>
-            where
-                T: crate::MulPowerOfTen,
-            {
-                fn unit_convert(val: T) -> T {
-                    val.mul_power_of_ten(EXP2 - EXP1)
-                }
-            }
+    /// Cast the value to another numeric type, keeping the unit. Returns
+    /// `None` if the value cannot be represented in the new type.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<i32, si::milli<si::s>> = Quantity::new(70_000);
+    /// assert!(a.try_cast::<i16>().is_none());
+    /// assert_eq!(a.try_cast::<i64>().map(Quantity::into_value), Some(70_000));
+    /// ```
+    #[inline]
+    pub fn try_cast<T2>(self) -> Option<Quantity<T2, U>>
+    where
+        T: num_traits::ToPrimitive,
+        T2: num_traits::NumCast,
+    {
+        T2::from(self.val).map(Quantity::new)
+    }
+}
+
+impl<T: num_traits::ToPrimitive, U: UnitInfo> Quantity<T, U> {
+    /// The value rescaled to the SI prefix that puts it between 1 and 1000,
+    /// and the symbol of that prefix, for human-readable output. The prefix
+    /// takes the unit's own scale into account, so it applies to the
+    /// unprefixed unit, which for the kilogram is the gram. Units scaled by
+    /// a factor other than a power of ten, such as the kilogram-force, get
+    /// no prefix and keep their value.
+    ///
+    /// ```rust
+    /// # use uy::{si, units, Quantity};
+    /// let p: Quantity<f64, si::kilo<si::W>> = Quantity::new(1_210_000.0);
+    /// let (value, prefix) = p.humanize();
+    /// assert_eq!(format!("{:.2} {}W", value, prefix), "1.21 GW");
+    ///
+    /// let t: Quantity<u32, si::micro<si::s>> = Quantity::new(250);
+    /// assert_eq!(t.humanize(), (250.0, "µ"));
+    /// assert_eq!(Quantity::<u32, si::s>::new(0).humanize(), (0.0, ""));
+    ///
+    /// let m: Quantity<f64, si::kg> = Quantity::new(1500.0);
+    /// assert_eq!(m.humanize(), (1.5, "M"));
+    /// let f: Quantity<f64, units::kgf> = Quantity::new(1500.0);
+    /// assert_eq!(f.humanize(), (1500.0, ""));
+    /// ```
+    pub fn humanize(&self) -> (f64, &'static str) {
+        let val = self.val.to_f64().unwrap_or(f64::NAN);
+        if val == 0.0 || !val.is_finite() || U::FACTOR != Ratio::ONE {
+            return (val, "");
         }
+        // The kilogram's prefixes are prefixes of the gram.
+        let grams = U::DIMENSION.iter().filter(|&&d| d != 0).count() == 1
+            && U::BASE_SYMBOLS
+                .iter()
+                .zip(U::DIMENSION)
+                .any(|(&b, &d)| b == "kg" && d == 1);
+        let unit_exp = i32::from(U::PREFIX) + if grams { 3 } else { 0 };
+        let magnitude = val.abs().log10().floor() as i32 + unit_exp;
+        let exp = (magnitude.div_euclid(3) * 3).clamp(-30, 30);
+        let scaled = val * 10f64.powi(unit_exp - exp);
+        (scaled, symbol::prefix_symbol(exp as i8).unwrap_or(""))
     }
 }
-pub(crate) use power_of_ten_unit_system;
 
-/// Multiply a unit by another unit or [`TenTo`].
-pub type Mul<A, B> = <A as ops::Mul<B>>::Output;
-/// Divide a unit by another unit or [`TenTo`].
-pub type Div<A, B> = <A as ops::Div<B>>::Output;
+/// Write a formatted value followed by the unit symbol, padding the whole
+/// thing to the formatter's width.
+fn fmt_with_symbol(f: &mut fmt::Formatter<'_>, value: &str, symbol: &str) -> fmt::Result {
+    let mut len = value.chars().count();
+    if !symbol.is_empty() {
+        len += 1 + symbol.chars().count();
+    }
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
 
-/// Convert a value between different units.
-pub trait UnitConvert<T, From>: Unit {
-    fn unit_convert(val: T) -> T;
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    f.write_str(value)?;
+    if !symbol.is_empty() {
+        write!(f, " {}", symbol)?;
+    }
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
 }
 
-/// A physical quantity with a defined unit.
-#[derive(Debug)]
-#[repr(transparent)]
-pub struct Quantity<T, U: Unit> {
-    val: T,
-    _marker: PhantomData<U>,
+macro_rules! impl_fmt {
+    ($($(#[$attr:meta])* $trait:ident => $spec:literal;)*) => {
+        $(
+            $(#[$attr])*
+            impl<T: fmt::$trait, U: UnitInfo> fmt::$trait for Quantity<T, U> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let value = match (f.precision(), f.sign_plus()) {
+                        (Some(p), true) => format!(concat!("{:+.*", $spec, "}"), p, self.val),
+                        (Some(p), false) => format!(concat!("{:.*", $spec, "}"), p, self.val),
+                        (None, true) => format!(concat!("{:+", $spec, "}"), self.val),
+                        (None, false) => format!(concat!("{:", $spec, "}"), self.val),
+                    };
+                    fmt_with_symbol(f, &value, U::SYMBOL)
+                }
+            }
+        )*
+    };
 }
 
-impl<T, U: Unit> Quantity<T, U> {
-    /// Create a quantity from a value.
-    pub fn new(val: T) -> Self {
-        Self {
-            val,
-            _marker: PhantomData,
-        }
-    }
-
-    /// Convert between quantities with different units or the same units
-    /// with different scales.
+impl_fmt! {
+    /// Formats the value followed by the unit symbol. The sign and precision
+    /// flags apply to the value, while width and alignment apply to the
+    /// value and symbol together.
     ///
     /// ```rust
     /// # use uy::{si, Quantity};
-    /// let a: Quantity<i32, si::m> = Quantity::new(3);
-    /// let b: Quantity<i32, si::milli<si::m>> = a.convert();
-    /// assert_eq!(*b, 3000);
+    /// let a: Quantity<f32, si::kilo<si::m>> = Quantity::new(1.5);
+    /// assert_eq!(a.to_string(), "1.5 km");
+    /// assert_eq!(format!("{:<9.2}|", a), "1.50 km  |");
     /// ```
-    pub fn convert<Y: UnitConvert<T, U>>(self) -> Quantity<T, Y> {
-        Quantity::new(Y::unit_convert(self.val))
+    Display => "";
+    /// Formats the value in scientific notation followed by the unit symbol.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<f64, si::s> = Quantity::new(1234.5);
+    /// assert_eq!(format!("{:>12.3e}", a), "   1.234e3 s");
+    /// ```
+    LowerExp => "e";
+    /// Formats the value in scientific notation followed by the unit symbol.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<f64, si::s> = Quantity::new(-0.001);
+    /// assert_eq!(format!("{:E}", a), "-1E-3 s");
+    /// ```
+    UpperExp => "E";
+}
+
+/// Formats the value followed by the unit symbol, such as
+/// `Quantity(3.5 m·s⁻¹)`. The alternate form, `{:#?}`, also shows the prefix
+/// and the exponent of each base unit.
+///
+/// ```rust
+/// # use uy::{si, Quantity};
+/// let v: Quantity<f64, uy::Div<si::m, si::s>> = Quantity::new(3.5);
+/// assert_eq!(format!("{:?}", v), "Quantity(3.5 m·s⁻¹)");
+///
+/// let t: Quantity<i32, si::milli<si::s>> = Quantity::new(20);
+/// assert_eq!(
+///     format!("{:#?}", t),
+///     "Quantity {
+///     value: 20,
+///     unit: \"ms\",
+///     prefix: -3,
+///     dimension: [
+///         1,
+///         0,
+///         0,
+///         0,
+///         0,
+///         0,
+///         0,
+///         0,
+///     ],
+/// }",
+/// );
+/// ```
+///
+/// Generic code only needs `U: Unit`, and only the value is shown for units
+/// that don't implement [`UnitInfo`]:
+///
+/// ```rust
+/// # use uy::{si, Quantity, Unit};
+/// fn show<U: Unit>(q: Quantity<f64, U>) -> String {
+///     format!("{:?}", q)
+/// }
+/// assert_eq!(show(Quantity::<f64, si::kilo<si::Sv>>::new(1.5)), "Quantity(1.5 kSv)");
+///
+/// struct Coins;
+/// impl Unit for Coins {}
+/// assert_eq!(show(Quantity::<f64, Coins>::new(3.0)), "Quantity(3.0)");
+/// ```
+impl<T: fmt::Debug, U: Unit> fmt::Debug for Quantity<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (U::DEBUG_INFO, f.alternate()) {
+            (Some((symbol, prefix, dimension)), true) => f
+                .debug_struct("Quantity")
+                .field("value", &self.val)
+                .field("unit", &symbol)
+                .field("prefix", &prefix)
+                .field("dimension", &dimension)
+                .finish(),
+            (Some((symbol, _, _)), false) => write!(f, "Quantity({:?} {})", self.val, symbol),
+            (None, _) => f.debug_tuple("Quantity").field(&self.val).finish(),
+        }
     }
 }
 
+/// Only available with the `deref` feature (enabled by default).
+#[cfg(feature = "deref")]
 impl<T, U: Unit> Deref for Quantity<T, U> {
     type Target = T;
 
+    #[inline]
     fn deref(&self) -> &T {
         &self.val
     }
 }
 
+/// Only available with the `deref` feature (enabled by default).
+#[cfg(feature = "deref")]
 impl<T, U: Unit> DerefMut for Quantity<T, U> {
+    #[inline]
     fn deref_mut(&mut self) -> &mut T {
         &mut self.val
     }
 }
 
+/// Only available with the `from` feature (enabled by default).
+#[cfg(feature = "from")]
 impl<T, U: Unit> From<T> for Quantity<T, U> {
+    #[inline]
     fn from(val: T) -> Self {
         Self::new(val)
     }
 }
 
+/// Uses the default of the value, so structs of quantities can derive
+/// `Default`.
+///
+/// ```rust
+/// # use uy::{si, Quantity};
+/// #[derive(Default)]
+/// struct Motor {
+///     speed: Quantity<f32, uy::Div<si::rad, si::s>>,
+///     current: Quantity<f32, si::A>,
+/// }
+///
+/// assert_eq!(Motor::default().current.into_value(), 0.0);
+/// ```
+impl<T: Default, U: Unit> Default for Quantity<T, U> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
 impl<T: Clone, U: Unit> Clone for Quantity<T, U> {
+    #[inline]
     fn clone(&self) -> Self {
         Self {
             val: self.val.clone(),
@@ -250,27 +2511,70 @@ impl<T: Clone, U: Unit> Clone for Quantity<T, U> {
 
 impl<T: Copy, U: Unit> Copy for Quantity<T, U> {}
 
-impl<T: PartialEq, U: Unit> PartialEq for Quantity<T, U> {
-    fn eq(&self, other: &Self) -> bool {
-        self.val == other.val
+/// Quantities are comparable when their units only differ in scale. The
+/// value in the coarser unit is converted to the finer unit first, and if
+/// it's too large for the finer unit, it's larger than any value there.
+/// Quantities in the same unit compare like their values, so any
+/// `T: PartialEq` will do. See [`ScaleEq`].
+///
+/// ```rust
+/// # use std::num::Saturating;
+/// # use uy::{si, Quantity};
+/// let a: Quantity<i32, si::m> = Quantity::new(1);
+/// let b: Quantity<i32, si::milli<si::m>> = Quantity::new(1000);
+/// let c: Quantity<i32, si::milli<si::m>> = Quantity::new(1500);
+/// assert!(a == b);
+/// assert!(a < c);
+///
+/// let far: Quantity<i32, si::kilo<si::m>> = Quantity::new(-5_000_000);
+/// assert!(far != c);
+/// assert!(far < c);
+///
+/// let clamped: Quantity<Saturating<i16>, si::kilo<si::m>> = Quantity::new(Saturating(100));
+/// let max: Quantity<Saturating<i16>, si::m> = Quantity::new(Saturating(i16::MAX));
+/// assert!(clamped != max);
+/// assert!(clamped > max);
+///
+/// let none: Quantity<Option<u32>, si::s> = Quantity::new(None);
+/// let five: Quantity<Option<u32>, si::s> = Quantity::new(Some(5));
+/// assert!(none == none);
+/// assert!(none < five);
+/// ```
+impl<T, U1: Unit, U2: Unit> PartialEq<Quantity<T, U2>> for Quantity<T, U1>
+where
+    U1: ScaleEq<T, U2>,
+{
+    #[inline]
+    fn eq(&self, other: &Quantity<T, U2>) -> bool {
+        U1::scale_eq(&self.val, &other.val)
     }
 }
 
-impl<T: Eq, U: Unit> Eq for Quantity<T, U> {}
+impl<T: Eq, U: ScaleEq<T, U>> Eq for Quantity<T, U> {}
 
-impl<T: PartialOrd, U: Unit> PartialOrd for Quantity<T, U> {
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        self.val.partial_cmp(&other.val)
+impl<T, U1: Unit, U2: Unit> PartialOrd<Quantity<T, U2>> for Quantity<T, U1>
+where
+    U1: ScaleOrd<T, U2>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Quantity<T, U2>) -> Option<cmp::Ordering> {
+        U1::scale_partial_cmp(&self.val, &other.val)
     }
 }
 
-impl<T: Ord, U: Unit> Ord for Quantity<T, U> {
+impl<T: Ord, U: ScaleOrd<T, U>> Ord for Quantity<T, U> {
+    #[inline]
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         self.val.cmp(&other.val)
     }
 }
 
+/// Only the value is hashed. `Eq` only compares quantities of the same unit,
+/// so equal quantities always have the same unit and the unit adds nothing
+/// to the hash. To look up a key in a different unit, see
+/// [`QuantityMapExt`](crate::map::QuantityMapExt).
 impl<T: hash::Hash, U: Unit> hash::Hash for Quantity<T, U> {
+    #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.val.hash(state);
     }
@@ -282,6 +2586,7 @@ where
 {
     type Output = Self;
 
+    #[inline]
     fn add(self, rhs: Self) -> Self {
         Quantity {
             val: self.val + rhs.val,
@@ -296,6 +2601,7 @@ where
 {
     type Output = Self;
 
+    #[inline]
     fn sub(self, rhs: Self) -> Self {
         Quantity {
             val: self.val - rhs.val,
@@ -304,6 +2610,55 @@ where
     }
 }
 
+impl<T, U: Unit> num_traits::Zero for Quantity<T, U>
+where
+    T: num_traits::Zero,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.val.is_zero()
+    }
+}
+
+/// ```rust
+/// # use uy::{si, Quantity};
+/// let parts = [Quantity::<f64, si::kg>::new(1.5), Quantity::new(2.0)];
+/// let total: Quantity<f64, si::kg> = parts.iter().sum();
+/// assert_eq!(total.into_value(), 3.5);
+/// ```
+impl<T: iter::Sum, U: Unit> iter::Sum for Quantity<T, U> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::new(iter.map(|q| q.val).sum())
+    }
+}
+
+impl<'a, T: iter::Sum<&'a T> + 'a, U: Unit> iter::Sum<&'a Quantity<T, U>> for Quantity<T, U> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::new(iter.map(|q| &q.val).sum())
+    }
+}
+
+/// The unit of a product is always in canonical form, whatever order its
+/// factors are multiplied in:
+///
+/// ```rust
+/// use uy::{si, Canonical, Quantity};
+///
+/// let kg: Quantity<f64, si::kg> = Quantity::new(2.0);
+/// let m: Quantity<f64, si::m> = Quantity::new(3.0);
+/// let s: Quantity<f64, si::s> = Quantity::new(1.0);
+///
+/// let a: Quantity<f64, Canonical<uy::Mul<si::m, si::kg>>> = kg * m;
+/// assert_eq!(a, m * kg);
+/// let _: Quantity<f64, si::N> = a / (s * s);
+/// ```
 impl<T, U1: Unit, U2: Unit> ops::Mul<Quantity<T, U2>> for Quantity<T, U1>
 where
     T: ops::Mul<Output = T>,
@@ -312,6 +2667,7 @@ where
 {
     type Output = Quantity<T, U1::Output>;
 
+    #[inline]
     fn mul(self, rhs: Quantity<T, U2>) -> Self::Output {
         Quantity {
             val: self.val * rhs.val,
@@ -328,6 +2684,7 @@ where
 {
     type Output = Quantity<T, U1::Output>;
 
+    #[inline]
     fn div(self, rhs: Quantity<T, U2>) -> Self::Output {
         Quantity {
             val: self.val / rhs.val,
@@ -336,6 +2693,70 @@ where
     }
 }
 
+/// Quantities can also be added, subtracted, multiplied, and divided by
+/// reference, for values that are expensive to copy:
+///
+/// ```rust
+/// use uy::{si, Quantity};
+///
+/// let a: Quantity<i64, si::m> = Quantity::new(3);
+/// let b: Quantity<i64, si::m> = Quantity::new(4);
+/// assert_eq!((&a + &b).into_value(), 7);
+/// let area: Quantity<i64, uy::Mul<si::m, si::m>> = &a * &b;
+/// assert_eq!(area.into_value(), 12);
+/// ```
+impl<'a, 'b, T, U: Unit> ops::Add<&'b Quantity<T, U>> for &'a Quantity<T, U>
+where
+    &'a T: ops::Add<&'b T, Output = T>,
+{
+    type Output = Quantity<T, U>;
+
+    #[inline]
+    fn add(self, rhs: &'b Quantity<T, U>) -> Quantity<T, U> {
+        Quantity::new(&self.val + &rhs.val)
+    }
+}
+
+impl<'a, 'b, T, U: Unit> ops::Sub<&'b Quantity<T, U>> for &'a Quantity<T, U>
+where
+    &'a T: ops::Sub<&'b T, Output = T>,
+{
+    type Output = Quantity<T, U>;
+
+    #[inline]
+    fn sub(self, rhs: &'b Quantity<T, U>) -> Quantity<T, U> {
+        Quantity::new(&self.val - &rhs.val)
+    }
+}
+
+impl<'a, 'b, T, U1: Unit, U2: Unit> ops::Mul<&'b Quantity<T, U2>> for &'a Quantity<T, U1>
+where
+    &'a T: ops::Mul<&'b T, Output = T>,
+    U1: ops::Mul<U2>,
+    <U1 as ops::Mul<U2>>::Output: Unit,
+{
+    type Output = Quantity<T, U1::Output>;
+
+    #[inline]
+    fn mul(self, rhs: &'b Quantity<T, U2>) -> Self::Output {
+        Quantity::new(&self.val * &rhs.val)
+    }
+}
+
+impl<'a, 'b, T, U1: Unit, U2: Unit> ops::Div<&'b Quantity<T, U2>> for &'a Quantity<T, U1>
+where
+    &'a T: ops::Div<&'b T, Output = T>,
+    U1: ops::Div<U2>,
+    <U1 as ops::Div<U2>>::Output: Unit,
+{
+    type Output = Quantity<T, U1::Output>;
+
+    #[inline]
+    fn div(self, rhs: &'b Quantity<T, U2>) -> Self::Output {
+        Quantity::new(&self.val / &rhs.val)
+    }
+}
+
 #[cfg(doctest)]
 mod test_readme {
     #[doc = include_str!("../README.md")]
@@ -0,0 +1,167 @@
+//! Localized formatting of quantities.
+//!
+//! A [`Locale`] chooses the decimal and digit group separators and how units
+//! are written. [`Unicode`] writes units as [`UnitInfo::SYMBOL`], for user
+//! interfaces, and [`Ascii`] as [`UnitInfo::UNIT_STR`], for logs and
+//! terminals that can't be relied on to show `µ` or `²`:
+//!
+//! ```rust
+//! use uy::locale::{Ascii, Unicode};
+//! use uy::{si, Quantity};
+//!
+//! let d: Quantity<f64, si::micro<si::m>> = Quantity::new(2.5);
+//! assert_eq!(d.localized(&Unicode).to_string(), "2.5 µm");
+//! assert_eq!(d.localized(&Ascii).to_string(), "2.5 um");
+//!
+//! let a: Quantity<f64, uy::Div<si::m, uy::Mul<si::s, si::s>>> = Quantity::new(-9.8);
+//! assert_eq!(a.localized(&Unicode).to_string(), "-9.8 m·s⁻²");
+//! assert_eq!(a.localized(&Ascii).to_string(), "-9.8 m/s^2");
+//! ```
+//!
+//! Applications implement [`Locale`] for their own conventions:
+//!
+//! ```rust
+//! use std::borrow::Cow;
+//!
+//! use uy::locale::Locale;
+//! use uy::{si, Quantity, UnitInfo};
+//!
+//! struct British;
+//!
+//! impl Locale for British {
+//!     fn decimal_separator(&self) -> char {
+//!         '.'
+//!     }
+//!
+//!     fn group_separator(&self) -> Option<char> {
+//!         Some(',')
+//!     }
+//!
+//!     fn unit<U: UnitInfo>(&self) -> Cow<'static, str> {
+//!         match U::UNIT_STR {
+//!             "m" => "metres".into(),
+//!             "km" => "kilometres".into(),
+//!             _ => U::SYMBOL.into(),
+//!         }
+//!     }
+//! }
+//!
+//! struct German;
+//!
+//! impl Locale for German {
+//!     fn decimal_separator(&self) -> char {
+//!         ','
+//!     }
+//!
+//!     fn group_separator(&self) -> Option<char> {
+//!         Some('.')
+//!     }
+//! }
+//!
+//! let d: Quantity<f64, si::kilo<si::m>> = Quantity::new(12345.25);
+//! assert_eq!(d.localized(&British).to_string(), "12,345.25 kilometres");
+//! assert_eq!(format!("{:.1}", d.localized(&German)), "12.345,2 km");
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{Quantity, UnitInfo};
+
+/// Conventions for formatting quantities.
+pub trait Locale {
+    /// The separator between the whole and fractional parts of a number.
+    fn decimal_separator(&self) -> char {
+        '.'
+    }
+
+    /// The separator between groups of three digits in the whole part of a
+    /// number, or `None` to not group digits.
+    fn group_separator(&self) -> Option<char> {
+        None
+    }
+
+    /// How the unit `U` is written after the value.
+    fn unit<U: UnitInfo>(&self) -> Cow<'static, str> {
+        Cow::Borrowed(U::SYMBOL)
+    }
+}
+
+/// Units written with [`UnitInfo::SYMBOL`], such as `µm` or `m·s⁻²`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Unicode;
+
+impl Locale for Unicode {}
+
+/// Units written with [`UnitInfo::UNIT_STR`], such as `um` or `m/s^2`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ascii;
+
+impl Locale for Ascii {
+    fn unit<U: UnitInfo>(&self) -> Cow<'static, str> {
+        Cow::Borrowed(U::UNIT_STR)
+    }
+}
+
+/// A quantity formatted with a [`Locale`], made by [`Quantity::localized`].
+pub struct Localized<'a, T, U: UnitInfo, L> {
+    quantity: &'a Quantity<T, U>,
+    locale: &'a L,
+}
+
+impl<T: fmt::Display, U: UnitInfo> Quantity<T, U> {
+    /// Format the quantity with the conventions of `locale`. Like the
+    /// [`Display`](fmt::Display) impl of [`Quantity`], the sign and precision
+    /// flags apply to the value, while width and alignment apply to the
+    /// value and unit together.
+    pub fn localized<'a, L: Locale>(&'a self, locale: &'a L) -> Localized<'a, T, U, L> {
+        Localized {
+            quantity: self,
+            locale,
+        }
+    }
+}
+
+impl<T: fmt::Display, U: UnitInfo, L: Locale> fmt::Display for Localized<'_, T, U, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = &self.quantity.val;
+        let value = match (f.precision(), f.sign_plus()) {
+            (Some(p), true) => format!("{:+.*}", p, val),
+            (Some(p), false) => format!("{:.*}", p, val),
+            (None, true) => format!("{:+}", val),
+            (None, false) => format!("{}", val),
+        };
+        let value = localize_number(&value, self.locale);
+        crate::fmt_with_symbol(f, &value, &self.locale.unit::<U>())
+    }
+}
+
+/// Replace the separators of a number formatted by Rust with those of
+/// `locale`.
+fn localize_number(value: &str, locale: &impl Locale) -> String {
+    let digits_start = value.find(|c: char| c.is_ascii_digit()).unwrap_or(0);
+    let digits_end = value[digits_start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(value.len(), |i| digits_start + i);
+    let (sign, rest) = value.split_at(digits_start);
+    let (whole, rest) = rest.split_at(digits_end - digits_start);
+
+    let mut out = String::with_capacity(value.len() + whole.len() / 3);
+    out.push_str(sign);
+    for (i, c) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i) % 3 == 0 {
+            if let Some(separator) = locale.group_separator() {
+                out.push(separator);
+            }
+        }
+        out.push(c);
+    }
+    match rest.strip_prefix('.') {
+        Some(fraction) => {
+            out.push(locale.decimal_separator());
+            out.push_str(fraction);
+        }
+        None => out.push_str(rest),
+    }
+    out
+}
@@ -0,0 +1,112 @@
+//! Lookup tables that interpolate between breakpoints, such as engine maps
+//! and thermistor curves, with units on both axes.
+
+use num_traits::Float;
+
+use crate::{Quantity, Unit};
+
+/// A table of outputs in `Y` at increasing breakpoints in `X`, linearly
+/// interpolated between them.
+///
+/// ```rust
+/// use uy::lookup::LookupTable;
+/// use uy::{si, Quantity};
+///
+/// // An NTC thermistor's temperature against its resistance.
+/// let thermistor: LookupTable<f64, si::kilo<si::Ohm>, si::K> = LookupTable::new([
+///     (Quantity::new(3.6), Quantity::new(343.15)),
+///     (Quantity::new(10.0), Quantity::new(298.15)),
+///     (Quantity::new(32.6), Quantity::new(273.15)),
+/// ]);
+///
+/// assert_eq!(thermistor.get(Quantity::new(10.0)).into_value(), 298.15);
+/// assert!((thermistor.get(Quantity::new(6.8)).into_value() - 320.65).abs() < 1e-9);
+///
+/// // Outside the breakpoints, the output is clamped to the nearest end.
+/// assert_eq!(thermistor.get(Quantity::new(50.0)).into_value(), 273.15);
+/// assert!(thermistor.try_get(Quantity::new(50.0)).is_none());
+/// ```
+///
+/// ```rust,compile_fail
+/// use uy::lookup::LookupTable;
+/// use uy::{si, Quantity};
+///
+/// let table: LookupTable<f64, si::kilo<si::Ohm>, si::K> =
+///     LookupTable::new([(Quantity::new(3.6), Quantity::new(343.15))]);
+/// table.get(Quantity::<f64, si::V>::new(1.0));
+/// ```
+#[derive(Clone)]
+pub struct LookupTable<T, X: Unit, Y: Unit> {
+    breakpoints: Vec<Quantity<T, X>>,
+    outputs: Vec<Quantity<T, Y>>,
+}
+
+impl<T: Float, X: Unit, Y: Unit> LookupTable<T, X, Y> {
+    /// Create a table from pairs of a breakpoint and the output at it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no points, or if the breakpoints are not in
+    /// strictly increasing order.
+    pub fn new(points: impl IntoIterator<Item = (Quantity<T, X>, Quantity<T, Y>)>) -> Self {
+        let (breakpoints, outputs): (Vec<_>, Vec<_>) = points.into_iter().unzip();
+        assert!(
+            !breakpoints.is_empty(),
+            "a lookup table needs at least one point"
+        );
+        assert!(
+            breakpoints.windows(2).all(|w| w[0].val < w[1].val),
+            "lookup table breakpoints must be strictly increasing"
+        );
+        Self {
+            breakpoints,
+            outputs,
+        }
+    }
+
+    /// The breakpoints, in increasing order.
+    pub fn breakpoints(&self) -> &[Quantity<T, X>] {
+        &self.breakpoints
+    }
+
+    /// The outputs at each of the breakpoints.
+    pub fn outputs(&self) -> &[Quantity<T, Y>] {
+        &self.outputs
+    }
+
+    /// The output at `x`, interpolated between the breakpoints around it,
+    /// or the output at the first or last breakpoint if `x` is outside them.
+    /// A NaN `x` gives a NaN output.
+    ///
+    /// ```rust
+    /// # use uy::lookup::LookupTable;
+    /// # use uy::{si, Quantity};
+    /// let table: LookupTable<f64, si::s, si::m> =
+    ///     LookupTable::new([(Quantity::new(0.0), Quantity::new(1.0))]);
+    /// assert!(table.get(Quantity::new(f64::NAN)).into_value().is_nan());
+    /// ```
+    pub fn get(&self, x: Quantity<T, X>) -> Quantity<T, Y> {
+        if x.val.is_nan() {
+            return Quantity::new(T::nan());
+        }
+        // The first breakpoint at or above `x`, found by bisection.
+        let i = self.breakpoints.partition_point(|b| b.val < x.val);
+        if i == 0 {
+            return self.outputs[0];
+        }
+        if i == self.breakpoints.len() {
+            return self.outputs[i - 1];
+        }
+        let (x0, x1) = (self.breakpoints[i - 1].val, self.breakpoints[i].val);
+        let (y0, y1) = (self.outputs[i - 1].val, self.outputs[i].val);
+        Quantity::new(y0 + (y1 - y0) * (x.val - x0) / (x1 - x0))
+    }
+
+    /// The output at `x` like [`get`](LookupTable::get), or `None` if `x`
+    /// is outside the breakpoints.
+    pub fn try_get(&self, x: Quantity<T, X>) -> Option<Quantity<T, Y>> {
+        let first = self.breakpoints[0].val;
+        let last = self.breakpoints[self.breakpoints.len() - 1].val;
+        (first..=last).contains(&x.val).then(|| self.get(x))
+    }
+}
@@ -0,0 +1,103 @@
+//! Maps keyed by quantities.
+//!
+//! Quantities can be used as keys of a [`HashMap`] or a [`BTreeMap`] like
+//! their values, and serialize as their values, so a map keyed by integer
+//! quantities serializes as a JSON object keyed by numbers. Keys in another
+//! unit of the same dimension can be looked up with [`QuantityMapExt`].
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use crate::{CommonScale, Quantity, Scalar, ScaleEq, ScaleOrd, Unit, UnitConvertLossy};
+
+/// Lookups in a map keyed by quantities in `U` with a key in another unit.
+///
+/// The key is converted to `U` first, and isn't found if converting it
+/// would truncate it or overflow its type.
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use uy::map::QuantityMapExt;
+/// use uy::{si, Quantity};
+///
+/// let mut timeouts: HashMap<Quantity<i64, si::milli<si::s>>, &str> = HashMap::new();
+/// timeouts.insert(Quantity::new(1500), "slow");
+/// timeouts.insert(Quantity::new(2000), "slower");
+///
+/// assert_eq!(timeouts.get_in(Quantity::<i64, si::s>::new(2)), Some(&"slower"));
+/// assert_eq!(timeouts.get_in(Quantity::<i64, si::micro<si::s>>::new(1_500_000)), Some(&"slow"));
+/// assert_eq!(timeouts.get_in(Quantity::<i64, si::micro<si::s>>::new(1_500_001)), None);
+/// assert!(!timeouts.contains_key_in(Quantity::<i64, si::s>::new(1)));
+/// assert_eq!(timeouts.get_in(Quantity::<i64, si::s>::new(i64::MAX / 10)), None);
+/// ```
+pub trait QuantityMapExt<T, U: Unit, V> {
+    fn get_in<Y>(&self, key: Quantity<T, Y>) -> Option<&V>
+    where
+        T: Scalar,
+        Y: CommonScale<U>,
+        U: UnitConvertLossy<T, Y>;
+
+    fn get_mut_in<Y>(&mut self, key: Quantity<T, Y>) -> Option<&mut V>
+    where
+        T: Scalar,
+        Y: CommonScale<U>,
+        U: UnitConvertLossy<T, Y>;
+
+    fn contains_key_in<Y>(&self, key: Quantity<T, Y>) -> bool
+    where
+        T: Scalar,
+        Y: CommonScale<U>,
+        U: UnitConvertLossy<T, Y>,
+    {
+        self.get_in(key).is_some()
+    }
+}
+
+impl<T, U, V, S> QuantityMapExt<T, U, V> for HashMap<Quantity<T, U>, V, S>
+where
+    T: Scalar + Eq + Hash,
+    U: ScaleEq<T, U>,
+    S: BuildHasher,
+{
+    fn get_in<Y>(&self, key: Quantity<T, Y>) -> Option<&V>
+    where
+        T: Scalar,
+        Y: CommonScale<U>,
+        U: UnitConvertLossy<T, Y>,
+    {
+        self.get(&key.try_convert::<U>().ok()?)
+    }
+
+    fn get_mut_in<Y>(&mut self, key: Quantity<T, Y>) -> Option<&mut V>
+    where
+        T: Scalar,
+        Y: CommonScale<U>,
+        U: UnitConvertLossy<T, Y>,
+    {
+        self.get_mut(&key.try_convert::<U>().ok()?)
+    }
+}
+
+impl<T, U, V> QuantityMapExt<T, U, V> for BTreeMap<Quantity<T, U>, V>
+where
+    T: Scalar + Ord,
+    U: ScaleOrd<T, U>,
+{
+    fn get_in<Y>(&self, key: Quantity<T, Y>) -> Option<&V>
+    where
+        T: Scalar,
+        Y: CommonScale<U>,
+        U: UnitConvertLossy<T, Y>,
+    {
+        self.get(&key.try_convert::<U>().ok()?)
+    }
+
+    fn get_mut_in<Y>(&mut self, key: Quantity<T, Y>) -> Option<&mut V>
+    where
+        T: Scalar,
+        Y: CommonScale<U>,
+        U: UnitConvertLossy<T, Y>,
+    {
+        self.get_mut(&key.try_convert::<U>().ok()?)
+    }
+}
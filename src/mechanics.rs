@@ -0,0 +1,133 @@
+//! Energy, momentum, projectiles, and orbits, with the gravitational
+//! constant and standard gravity.
+//!
+//! ```rust
+//! use uy::mechanics;
+//! use uy::si::quantities::{Angle, Length, Mass, Velocity};
+//! use uy::{si, units, Quantity};
+//!
+//! // A 1200 kg car at 25 m/s.
+//! let e = mechanics::kinetic_energy(Mass::new(1200.0), Velocity::new(25.0));
+//! let kj: Quantity<f64, si::kilo<si::J>> = e.convert();
+//! assert_eq!(kj.into_value(), 375.0);
+//!
+//! // A ball thrown at 20 m/s, 45° above level ground.
+//! let (v, angle) = (Velocity::new(20.0), Angle::new(std::f64::consts::FRAC_PI_4));
+//! assert!((mechanics::projectile_range(v, angle).into_value() - 40.789).abs() < 1e-3);
+//! assert!((mechanics::projectile_max_height(v, angle).into_value() - 10.197).abs() < 1e-3);
+//!
+//! // The International Space Station, about 420 km above the Earth.
+//! let earth = Mass::new(5.972e24);
+//! let orbit = Length::new(6.371e6 + 420e3);
+//! let minutes: Quantity<f64, units::min> = mechanics::orbital_period(earth, orbit).convert();
+//! assert!((minutes.into_value() - 92.8).abs() < 0.1);
+//! assert!((mechanics::escape_velocity(earth, Length::new(6.371e6)).into_value() - 11_186.0).abs() < 1.0);
+//! ```
+//!
+//! ```rust,compile_fail
+//! use uy::mechanics;
+//! use uy::si::quantities::{Length, Mass};
+//!
+//! // The arguments are a mass and a speed, not a mass and a height.
+//! mechanics::kinetic_energy(Mass::new(2.0), Length::new(3.0));
+//! ```
+
+use num_traits::{Float, FloatConst};
+
+use crate::si::quantities::{Acceleration, Angle, Energy, Length, Mass, Time, Velocity};
+use crate::{si, Div, Mul, Quantity};
+
+/// A momentum, in kg·m·s⁻¹.
+pub type Momentum<T> = Quantity<T, Mul<si::kg, Div<si::m, si::s>>>;
+/// The unit of the gravitational constant, N·m²·kg⁻².
+pub type GravitationalUnit = Div<Mul<si::N, Mul<si::m, si::m>>, Mul<si::kg, si::kg>>;
+
+/// The gravitational constant, 6.674 30 × 10⁻¹¹ N·m²·kg⁻².
+pub fn gravitational_constant<T: Float>() -> Quantity<T, GravitationalUnit> {
+    Quantity::new(T::from(6.674_30e-11).unwrap())
+}
+
+/// Standard gravity, exactly 9.806 65 m·s⁻², the acceleration of free fall
+/// near the surface of the Earth.
+pub fn standard_gravity<T: Float>() -> Acceleration<T> {
+    Quantity::new(T::from(9.806_65).unwrap())
+}
+
+/// A plain number as a dimensionless quantity.
+fn number<T: Float>(n: f64) -> Quantity<T, si::unitless> {
+    Quantity::new(T::from(n).unwrap())
+}
+
+/// The sine of `angle`, as a dimensionless quantity.
+fn sin<T: Float>(angle: Angle<T>) -> Quantity<T, si::unitless> {
+    Quantity::new(angle.val.sin())
+}
+
+/// The kinetic energy of a mass `m` moving at speed `v`, ½·m·v².
+pub fn kinetic_energy<T: Float>(m: Mass<T>, v: Velocity<T>) -> Energy<T> {
+    number(0.5) * m * v * v
+}
+
+/// The momentum of a mass `m` moving at velocity `v`.
+pub fn momentum<T: Float>(m: Mass<T>, v: Velocity<T>) -> Momentum<T> {
+    m * v
+}
+
+/// The potential energy gained by lifting a mass `m` by a height `h` in
+/// standard gravity.
+pub fn potential_energy<T: Float>(m: Mass<T>, h: Length<T>) -> Energy<T> {
+    m * standard_gravity() * h
+}
+
+/// The gravitational potential energy of masses `m1` and `m2` a distance
+/// `r` apart, which is zero when they're infinitely far apart and negative
+/// otherwise.
+pub fn gravitational_potential_energy<T: Float>(
+    m1: Mass<T>,
+    m2: Mass<T>,
+    r: Length<T>,
+) -> Energy<T> {
+    number(-1.0) * gravitational_constant() * m1 * m2 / r
+}
+
+/// The force of gravity between masses `m1` and `m2` a distance `r` apart.
+pub fn gravitational_force<T: Float>(m1: Mass<T>, m2: Mass<T>, r: Length<T>) -> Quantity<T, si::N> {
+    gravitational_constant() * m1 * m2 / (r * r)
+}
+
+/// The horizontal distance travelled by a projectile launched at speed `v`
+/// and `angle` above level ground, in standard gravity without drag.
+pub fn projectile_range<T: Float>(v: Velocity<T>, angle: Angle<T>) -> Length<T> {
+    v * v * sin(angle + angle) / standard_gravity()
+}
+
+/// The time a projectile launched at speed `v` and `angle` above level
+/// ground takes to land, in standard gravity without drag.
+pub fn projectile_time_of_flight<T: Float>(v: Velocity<T>, angle: Angle<T>) -> Time<T> {
+    number(2.0) * v * sin(angle) / standard_gravity()
+}
+
+/// The greatest height reached by a projectile launched at speed `v` and
+/// `angle` above level ground, in standard gravity without drag.
+pub fn projectile_max_height<T: Float>(v: Velocity<T>, angle: Angle<T>) -> Length<T> {
+    let vertical = v * sin(angle);
+    vertical * vertical / (number(2.0) * standard_gravity())
+}
+
+/// The period of an orbit with semi-major axis `r` around a central mass
+/// `m`, much larger than the orbiting body, from Kepler's third law.
+pub fn orbital_period<T: Float + FloatConst>(m: Mass<T>, r: Length<T>) -> Time<T> {
+    let tau = Quantity::<T, si::unitless>::new(T::TAU());
+    tau * (r * r * r / (gravitational_constant() * m)).sqrt()
+}
+
+/// The speed of a circular orbit of radius `r` around a central mass `m`.
+pub fn orbital_speed<T: Float>(m: Mass<T>, r: Length<T>) -> Velocity<T> {
+    (gravitational_constant() * m / r).sqrt()
+}
+
+/// The speed needed to escape the gravity of a mass `m` from a distance
+/// `r`, without further thrust.
+pub fn escape_velocity<T: Float>(m: Mass<T>, r: Length<T>) -> Velocity<T> {
+    (number(2.0) * gravitational_constant() * m / r).sqrt()
+}
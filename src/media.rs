@@ -0,0 +1,89 @@
+//! A unit system for video, images, and audio, with frames, pixels, and
+//! samples as base units alongside seconds and meters.
+//!
+//! Counts of frames, pixels, and samples are all plain numbers to the SI, so
+//! a frame rate and a sample rate are both hertz there. Here they're
+//! different dimensions, and mixing them up is a compile-time error:
+//!
+//! ```rust
+//! use uy::media::{self, fps, frame, sample, s};
+//! use uy::{si, Quantity};
+//!
+//! let rate: Quantity<u32, fps> = Quantity::new(24);
+//! let clip: Quantity<u32, s> = Quantity::new(90);
+//! let frames: Quantity<u32, frame> = rate * clip;
+//! assert_eq!(frames.into_value(), 2160);
+//!
+//! let audio: Quantity<u32, si::kilo<media::sample_rate>> = Quantity::new(48);
+//! let per_frame: Quantity<u32, uy::Div<sample, frame>> = audio.convert::<media::sample_rate>() / rate;
+//! assert_eq!(per_frame.into_value(), 2000);
+//!
+//! let print: Quantity<f64, media::dpi> = Quantity::new(300.0);
+//! let density: Quantity<f64, media::px_per_mm> = print.convert();
+//! assert!((density.into_value() - 11.811).abs() < 1e-3);
+//! ```
+//!
+//! ```rust,compile_fail
+//! use uy::media::{fps, sample_rate};
+//! use uy::Quantity;
+//!
+//! let rate: Quantity<u32, fps> = Quantity::new(24);
+//! let audio: Quantity<u32, sample_rate> = rate.convert();
+//! ```
+
+#![allow(non_camel_case_types)]
+
+mod inner {
+    crate::power_of_ten_unit_system!(Media {
+        s = "second",
+        m = "meter",
+        frame,
+        px = "pixel",
+        sample
+    });
+    crate::scaled_unit::scaled_unit_conversions!(Media {
+        s,
+        m,
+        frame,
+        px,
+        sample
+    });
+}
+
+pub use self::base::*;
+pub use self::derived::*;
+pub use self::inner::{Dimension, Media};
+
+pub mod base {
+    //! Media base units.
+
+    use super::Media;
+
+    pub type unitless = Media<0, 0, 0, 0, 0, 0>;
+    pub type s = Media<0, 1, 0, 0, 0, 0>;
+    pub type m = Media<0, 0, 1, 0, 0, 0>;
+    pub type frame = Media<0, 0, 0, 1, 0, 0>;
+    pub type px = Media<0, 0, 0, 0, 1, 0>;
+    pub type sample = Media<0, 0, 0, 0, 0, 1>;
+}
+
+pub mod derived {
+    //! Media derived units.
+
+    use super::base::*;
+    use crate::scaled_unit::scaled_unit;
+    use crate::si::milli;
+    use crate::Div;
+
+    /// Frames per second.
+    pub type fps = Div<frame, s>;
+    /// Samples per second.
+    pub type sample_rate = Div<sample, s>;
+    /// Pixels per millimeter.
+    pub type px_per_mm = Div<px, milli<m>>;
+
+    scaled_unit!(
+        /// Dots per inch, taking a dot to be a pixel, 5000/127 px·m⁻¹.
+        dpi = 5000 / 127 Div<px, m>, "dpi"
+    );
+}
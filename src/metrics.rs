@@ -0,0 +1,238 @@
+//! Recording quantities with the [`metrics`](https://docs.rs/metrics) crate,
+//! such as for a Prometheus exporter.
+//!
+//! Each metric is named with its unit spelled out as a suffix, following the
+//! Prometheus convention, and takes quantities in that unit. Values are
+//! recorded in the unprefixed unit, so a gauge of `Quantity<f64,
+//! si::milli<si::s>>` records seconds.
+//!
+//! ```rust
+//! use uy::{si, Quantity};
+//!
+//! let speed = uy::metrics::gauge::<uy::Div<si::m, si::s>>("conveyor_speed");
+//! assert_eq!(speed.name(), "conveyor_speed_meters_per_second");
+//! speed.set(Quantity::<f32, uy::Div<si::milli<si::m>, si::s>>::new(250.0).convert());
+//!
+//! // Counts whole joules, carrying the 0.5 J left over to the next increment.
+//! let energy = uy::metrics::counter::<si::milli<si::J>>("heater_energy");
+//! assert_eq!(energy.name(), "heater_energy_joules");
+//! energy.increment(Quantity::<u32, _>::new(1500));
+//! ```
+
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use num_traits::ToPrimitive;
+
+use crate::{si, Quantity, Unit, UnitInfo};
+
+/// The plural and singular names of base units, by symbol.
+const BASE_NAMES: &[(&str, &str, &str)] = &[
+    ("s", "seconds", "second"),
+    ("m", "meters", "meter"),
+    ("cm", "centimeters", "centimeter"),
+    ("kg", "kilograms", "kilogram"),
+    ("g", "grams", "gram"),
+    ("A", "amperes", "ampere"),
+    ("K", "kelvins", "kelvin"),
+    ("mol", "moles", "mole"),
+    ("cd", "candelas", "candela"),
+    ("rad", "radians", "radian"),
+];
+
+/// SI derived units that are named rather than spelled out in base units.
+const DERIVED_NAMES: &[(&[i8], &str)] = &[
+    (<si::N as UnitInfo>::DIMENSION, "newtons"),
+    (<si::Pa as UnitInfo>::DIMENSION, "pascals"),
+    (<si::J as UnitInfo>::DIMENSION, "joules"),
+    (<si::W as UnitInfo>::DIMENSION, "watts"),
+    (<si::C as UnitInfo>::DIMENSION, "coulombs"),
+    (<si::V as UnitInfo>::DIMENSION, "volts"),
+    (<si::F as UnitInfo>::DIMENSION, "farads"),
+    (<si::Ohm as UnitInfo>::DIMENSION, "ohms"),
+    (<si::S as UnitInfo>::DIMENSION, "siemens"),
+    (<si::Wb as UnitInfo>::DIMENSION, "webers"),
+    (<si::T as UnitInfo>::DIMENSION, "teslas"),
+    (<si::H as UnitInfo>::DIMENSION, "henries"),
+];
+
+/// The unit of `U`, without its prefix, spelled out for a metric name, such
+/// as `meters_per_second` or `watts`. Dimensionless units are spelled
+/// `ratio`.
+///
+/// ```rust
+/// use uy::si;
+///
+/// assert_eq!(uy::metrics::unit_name::<si::milli<si::s>>(), "seconds");
+/// assert_eq!(uy::metrics::unit_name::<uy::Div<si::m, uy::Mul<si::s, si::s>>>(), "meters_per_second_squared");
+/// assert_eq!(uy::metrics::unit_name::<si::kilo<si::W>>(), "watts");
+/// assert_eq!(uy::metrics::unit_name::<si::unitless>(), "ratio");
+/// ```
+pub fn unit_name<U: UnitInfo>() -> String {
+    fn push(name: &mut String, word: &str, exp: i8) {
+        if !name.is_empty() {
+            name.push('_');
+        }
+        name.push_str(word);
+        match exp.unsigned_abs() {
+            1 => {}
+            2 => name.push_str("_squared"),
+            3 => name.push_str("_cubed"),
+            n => name.push_str(&format!("_pow{}", n)),
+        }
+    }
+
+    fn words(symbol: &'static str) -> (&'static str, &'static str) {
+        BASE_NAMES
+            .iter()
+            .find(|(s, ..)| *s == symbol)
+            .map_or((symbol, symbol), |&(_, plural, singular)| {
+                (plural, singular)
+            })
+    }
+
+    if U::BASE_SYMBOLS == <si::unitless as UnitInfo>::BASE_SYMBOLS {
+        if let Some((_, name)) = DERIVED_NAMES.iter().find(|(dims, _)| *dims == U::DIMENSION) {
+            return (*name).to_owned();
+        }
+    }
+
+    let mut name = String::new();
+    for (symbol, &exp) in U::BASE_SYMBOLS.iter().zip(U::DIMENSION) {
+        if exp > 0 {
+            push(&mut name, words(symbol).0, exp);
+        }
+    }
+    let mut per = true;
+    for (symbol, &exp) in U::BASE_SYMBOLS.iter().zip(U::DIMENSION) {
+        if exp < 0 {
+            if per {
+                push(&mut name, "per", 1);
+                per = false;
+            }
+            push(&mut name, words(symbol).1, exp);
+        }
+    }
+    if name.is_empty() {
+        name.push_str("ratio");
+    }
+    name
+}
+
+/// The value of a quantity in the unprefixed unit, as an `f64`.
+fn base_value<T: ToPrimitive, U: UnitInfo>(q: Quantity<T, U>) -> f64 {
+    q.val.to_f64().unwrap_or(f64::NAN) * 10f64.powi(U::PREFIX.into()) * U::FACTOR.to_f64()
+}
+
+/// Register a gauge named `name` followed by the [`unit_name`] of `U`.
+pub fn gauge<U: UnitInfo>(name: &str) -> QuantityGauge<U> {
+    let name = format!("{}_{}", name, unit_name::<U>());
+    QuantityGauge {
+        gauge: metrics::gauge!(name.clone()),
+        name,
+        _marker: PhantomData,
+    }
+}
+
+/// Register a histogram named `name` followed by the [`unit_name`] of `U`.
+pub fn histogram<U: UnitInfo>(name: &str) -> QuantityHistogram<U> {
+    let name = format!("{}_{}", name, unit_name::<U>());
+    QuantityHistogram {
+        histogram: metrics::histogram!(name.clone()),
+        name,
+        _marker: PhantomData,
+    }
+}
+
+/// Register a counter named `name` followed by the [`unit_name`] of `U`.
+pub fn counter<U: UnitInfo>(name: &str) -> QuantityCounter<U> {
+    let name = format!("{}_{}", name, unit_name::<U>());
+    QuantityCounter {
+        counter: metrics::counter!(name.clone()),
+        carry: AtomicU64::new(0f64.to_bits()),
+        name,
+        _marker: PhantomData,
+    }
+}
+
+/// A gauge of quantities in `U`.
+pub struct QuantityGauge<U: Unit> {
+    gauge: metrics::Gauge,
+    name: String,
+    _marker: PhantomData<U>,
+}
+
+impl<U: UnitInfo> QuantityGauge<U> {
+    /// The full name of the gauge, with its unit.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set<T: ToPrimitive>(&self, q: Quantity<T, U>) {
+        self.gauge.set(base_value(q));
+    }
+
+    pub fn increment<T: ToPrimitive>(&self, q: Quantity<T, U>) {
+        self.gauge.increment(base_value(q));
+    }
+
+    pub fn decrement<T: ToPrimitive>(&self, q: Quantity<T, U>) {
+        self.gauge.decrement(base_value(q));
+    }
+}
+
+/// A histogram of quantities in `U`.
+pub struct QuantityHistogram<U: Unit> {
+    histogram: metrics::Histogram,
+    name: String,
+    _marker: PhantomData<U>,
+}
+
+impl<U: UnitInfo> QuantityHistogram<U> {
+    /// The full name of the histogram, with its unit.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn record<T: ToPrimitive>(&self, q: Quantity<T, U>) {
+        self.histogram.record(base_value(q));
+    }
+}
+
+/// A counter of quantities in `U`.
+///
+/// Counters only count whole units, so the fractional part of each
+/// increment is carried over to the next one rather than lost.
+pub struct QuantityCounter<U: Unit> {
+    counter: metrics::Counter,
+    carry: AtomicU64,
+    name: String,
+    _marker: PhantomData<U>,
+}
+
+impl<U: UnitInfo> QuantityCounter<U> {
+    /// The full name of the counter, with its unit.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Add a quantity to the counter. Negative and non-finite quantities are
+    /// ignored, since counters only go up.
+    pub fn increment<T: ToPrimitive>(&self, q: Quantity<T, U>) {
+        let value = base_value(q);
+        if !value.is_finite() || value <= 0.0 {
+            return;
+        }
+        let mut whole = 0.0;
+        let _ = self
+            .carry
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+                let total = f64::from_bits(bits) + value;
+                whole = total.trunc();
+                Some((total - whole).to_bits())
+            });
+        if whole >= 1.0 {
+            self.counter.increment(whole as u64);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Sums and differences of quantities with different prefixes.
+
+use std::{fmt, ops};
+
+use crate::{CommonScale, FinerScale, MulPowerOfTen, Quantity, Unit};
+
+/// A quantity that may be added to or subtracted from a quantity with a
+/// different prefix, created by [`Quantity::mixed`].
+///
+/// Integer results are in the finer of the two units, so neither operand is
+/// truncated. Floating-point results are in the unit of the left operand.
+///
+/// ```rust
+/// use uy::{si, Quantity};
+///
+/// let radio: Quantity<u32, si::milli<si::W>> = Quantity::new(250);
+/// let heater: Quantity<u32, si::W> = Quantity::new(2);
+/// let total: Quantity<u32, si::milli<si::W>> = heater + radio.mixed();
+/// assert_eq!(total.into_value(), 2250);
+///
+/// let budget: Quantity<f32, si::W> = Quantity::new(5.0);
+/// let left: Quantity<f32, si::W> = budget - radio.cast::<f32>().mixed();
+/// assert_eq!(left.into_value(), 4.75);
+/// ```
+///
+/// Units of different dimensions still can't be mixed:
+///
+/// ```rust,compile_fail
+/// use uy::{si, Quantity};
+///
+/// let a: Quantity<u32, si::W> = Quantity::new(2);
+/// let b: Quantity<u32, si::V> = Quantity::new(2);
+/// let _ = a + b.mixed();
+/// ```
+#[derive(Clone, Copy)]
+pub struct Mixed<T, U: Unit>(Quantity<T, U>);
+
+impl<T: fmt::Debug, U: Unit> fmt::Debug for Mixed<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Mixed").field(&self.0).finish()
+    }
+}
+
+impl<T, U: Unit> Quantity<T, U> {
+    /// Opt in to adding or subtracting this quantity from one with a
+    /// different prefix. See [`Mixed`].
+    pub fn mixed(self) -> Mixed<T, U> {
+        Mixed(self)
+    }
+}
+
+/// The unit of the sum of a quantity in `U1` and a [`Mixed`] quantity in
+/// `U2`, for values of this type.
+pub trait MixedPrefix<U1: Unit, U2: Unit>: Sized {
+    type Output: Unit;
+
+    /// Convert both values to the output unit.
+    fn to_output(lhs: Self, rhs: Self) -> (Self, Self);
+}
+
+macro_rules! impl_mixed_prefix_int {
+    ($($ty:ty),*) => {
+        $(
+            impl<U1: FinerScale<U2>, U2: Unit> MixedPrefix<U1, U2> for $ty {
+                type Output = <U1 as FinerScale<U2>>::Output;
+
+                fn to_output(lhs: Self, rhs: Self) -> (Self, Self) {
+                    crate::to_common_scale(lhs, rhs, U1::SCALE_DIFF)
+                }
+            }
+        )*
+    };
+}
+
+impl_mixed_prefix_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_mixed_prefix_float {
+    ($($ty:ty),*) => {
+        $(
+            impl<U1: CommonScale<U2>, U2: Unit> MixedPrefix<U1, U2> for $ty {
+                type Output = U1;
+
+                fn to_output(lhs: Self, rhs: Self) -> (Self, Self) {
+                    (lhs, rhs.mul_power_of_ten(U1::SCALE_DIFF))
+                }
+            }
+        )*
+    };
+}
+
+impl_mixed_prefix_float!(f32, f64);
+
+impl<T, U1: Unit, U2: Unit> ops::Add<Mixed<T, U2>> for Quantity<T, U1>
+where
+    T: MixedPrefix<U1, U2> + ops::Add<Output = T>,
+{
+    type Output = Quantity<T, <T as MixedPrefix<U1, U2>>::Output>;
+
+    fn add(self, rhs: Mixed<T, U2>) -> Self::Output {
+        let (lhs, rhs) = T::to_output(self.val, rhs.0.val);
+        Quantity::new(lhs + rhs)
+    }
+}
+
+impl<T, U1: Unit, U2: Unit> ops::Sub<Mixed<T, U2>> for Quantity<T, U1>
+where
+    T: MixedPrefix<U1, U2> + ops::Sub<Output = T>,
+{
+    type Output = Quantity<T, <T as MixedPrefix<U1, U2>>::Output>;
+
+    fn sub(self, rhs: Mixed<T, U2>) -> Self::Output {
+        let (lhs, rhs) = T::to_output(self.val, rhs.0.val);
+        Quantity::new(lhs - rhs)
+    }
+}
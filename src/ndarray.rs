@@ -0,0 +1,66 @@
+//! Support for [`ndarray`](https://docs.rs/ndarray) arrays of quantities.
+//!
+//! Arrays of quantities can be added, subtracted, and summed like arrays of
+//! plain numbers, and quantities can be used as scalar operands:
+//!
+//! ```rust
+//! use ndarray::array;
+//! use uy::{si, Quantity};
+//!
+//! let a = array![[Quantity::<f64, si::m>::new(1.0), Quantity::new(2.0)]];
+//! let b = &a + Quantity::<f64, si::m>::new(0.5);
+//! assert_eq!(b.sum().into_value(), 4.0);
+//!
+//! let mm: ndarray::Array2<Quantity<f64, si::milli<si::m>>> = uy::ndarray::convert(&a);
+//! assert_eq!(mm[[0, 1]].into_value(), 2000.0);
+//! ```
+
+use ::ndarray::{Array, ArrayBase, Data, Dimension, ScalarOperand};
+
+use crate::{Quantity, Unit, UnitConvertExact};
+
+impl<T: Clone + 'static, U: Unit + Clone + 'static> ScalarOperand for Quantity<T, U> {}
+
+/// Convert every element of an array to a different unit. For primitive
+/// storage types the scale factor is a constant, so this compiles to a
+/// single multiply per element.
+pub fn convert<T, U, Y, S, D>(array: &ArrayBase<S, D>) -> Array<Quantity<T, Y>, D>
+where
+    T: Clone,
+    U: Unit,
+    Y: UnitConvertExact<T, U>,
+    S: Data<Elem = Quantity<T, U>>,
+    D: Dimension,
+{
+    array.map(|q| Quantity::new(Y::unit_convert(q.val.clone())))
+}
+
+/// Attach a unit to every element of an array of plain values.
+///
+/// ```rust
+/// use ndarray::array;
+/// use uy::si;
+///
+/// let volts = uy::ndarray::from_values::<_, si::V, _, _>(array![1.5f32, 3.3]);
+/// assert_eq!(volts[1].into_value(), 3.3);
+/// ```
+pub fn from_values<T, U, S, D>(array: ArrayBase<S, D>) -> Array<Quantity<T, U>, D>
+where
+    T: Clone,
+    U: Unit,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    array.mapv(Quantity::new)
+}
+
+/// Strip the unit from every element of an array.
+pub fn into_values<T, U, S, D>(array: ArrayBase<S, D>) -> Array<T, D>
+where
+    T: Clone,
+    U: Unit,
+    S: Data<Elem = Quantity<T, U>>,
+    D: Dimension,
+{
+    array.map(|q| q.val.clone())
+}
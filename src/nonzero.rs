@@ -0,0 +1,116 @@
+//! Quantities stored in [`NonZero`](std::num::NonZero) integers, so that
+//! `Option<Quantity<NonZeroU32, U>>` is the same size as `u32`.
+//!
+//! ```rust
+//! use std::num::NonZeroU32;
+//! use uy::{si, Quantity};
+//!
+//! type Period = Quantity<NonZeroU32, si::micro<si::s>>;
+//! assert_eq!(std::mem::size_of::<Option<Period>>(), 4);
+//!
+//! let p = Period::try_new(250).unwrap();
+//! assert_eq!(p.get().into_value(), 250);
+//! assert!(Period::try_new(0).is_none());
+//! assert!(Period::from_quantity(Quantity::new(0)).is_none());
+//!
+//! let ns: Quantity<NonZeroU32, si::nano<si::s>> = p.convert();
+//! assert_eq!(ns.get().into_value(), 250_000);
+//! assert!(p.checked_convert::<si::femto<si::s>>().is_none());
+//! ```
+
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::{
+    impl_exact_power_of_ten_int, ExactPowerOfTen, MulPowerOfTen, Quantity, Rounding, Unit,
+};
+
+macro_rules! impl_nonzero {
+    ($($nonzero:ident($int:ty)),*) => {
+        $(
+            /// Scales the integer value.
+            ///
+            /// # Panics
+            ///
+            /// [`mul_power_of_ten`](MulPowerOfTen::mul_power_of_ten) and its
+            /// rounded and saturating variants panic if the value is scaled
+            /// down to zero.
+            impl MulPowerOfTen for $nonzero {
+                const TRUNCATES: bool = true;
+
+                #[inline]
+                fn mul_power_of_ten(self, exp: i8) -> Self {
+                    $nonzero::new(self.get().mul_power_of_ten(exp))
+                        .expect("non-zero quantity scaled to zero")
+                }
+
+                #[inline]
+                fn checked_mul_power_of_ten(self, exp: i8) -> Option<Self> {
+                    $nonzero::new(self.get().checked_mul_power_of_ten(exp)?)
+                }
+
+                #[inline]
+                fn saturating_mul_power_of_ten(self, exp: i8) -> Self {
+                    $nonzero::new(self.get().saturating_mul_power_of_ten(exp))
+                        .expect("non-zero quantity scaled to zero")
+                }
+
+                #[inline]
+                fn mul_power_of_ten_rounded(self, exp: i8, rounding: Rounding) -> Self {
+                    $nonzero::new(self.get().mul_power_of_ten_rounded(exp, rounding))
+                        .expect("non-zero quantity scaled to zero")
+                }
+            }
+
+            impl<U: Unit> Quantity<$nonzero, U> {
+                /// Create a quantity from an integer, or `None` if it's zero.
+                pub fn try_new(val: $int) -> Option<Self> {
+                    $nonzero::new(val).map(Quantity::new)
+                }
+
+                /// Create a quantity from an integer quantity, or `None` if
+                /// it's zero.
+                pub fn from_quantity(q: Quantity<$int, U>) -> Option<Self> {
+                    Self::try_new(q.val)
+                }
+
+                /// The quantity as a plain integer quantity.
+                pub fn get(self) -> Quantity<$int, U> {
+                    Quantity::new(self.val.get())
+                }
+            }
+        )*
+    };
+}
+
+impl_nonzero!(
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroI128(i128),
+    NonZeroIsize(isize),
+    NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32),
+    NonZeroU64(u64),
+    NonZeroU128(u128),
+    NonZeroUsize(usize)
+);
+
+impl_exact_power_of_ten_int!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
@@ -0,0 +1,57 @@
+//! Light as waves and photons: converting between wavelength, frequency,
+//! and photon energy with the speed of light and the Planck constant.
+//!
+//! ```rust
+//! use uy::{optics, si, units, Quantity};
+//!
+//! // A green laser pointer.
+//! let green: Quantity<f64, si::nano<si::m>> = Quantity::new(532.0);
+//! let f = optics::frequency(green.convert());
+//! let thz: Quantity<f64, si::tera<si::Hz>> = f.convert();
+//! assert!((thz.into_value() - 563.5197).abs() < 1e-4);
+//!
+//! let e: Quantity<f64, units::eV> = optics::photon_energy(green.convert()).convert();
+//! assert!((e.into_value() - 2.3305).abs() < 1e-4);
+//! let back: Quantity<f64, si::nano<si::m>> = optics::photon_wavelength(e.convert()).convert();
+//! assert!((back.into_value() - 532.0).abs() < 1e-9);
+//!
+//! // The 2.4 GHz band used by Wi-Fi.
+//! let wifi: Quantity<f64, si::giga<si::Hz>> = Quantity::new(2.4);
+//! let lambda: Quantity<f64, si::centi<si::m>> = optics::wavelength(wifi.convert()).convert();
+//! assert!((lambda.into_value() - 12.491).abs() < 1e-3);
+//! ```
+
+use num_traits::Float;
+
+use crate::si::quantities::{Energy, Frequency, Length, Velocity};
+use crate::{si, Mul, Quantity};
+
+/// The speed of light in vacuum, exactly 299 792 458 m·s⁻¹.
+pub fn speed_of_light<T: Float>() -> Velocity<T> {
+    Quantity::new(T::from(299_792_458).unwrap())
+}
+
+/// The Planck constant, exactly 6.626 070 15 × 10⁻³⁴ J·s.
+pub fn planck_constant<T: Float>() -> Quantity<T, Mul<si::J, si::s>> {
+    Quantity::new(T::from(6.626_070_15e-34).unwrap())
+}
+
+/// The wavelength in vacuum of a wave with frequency `f`.
+pub fn wavelength<T: Float>(f: Frequency<T>) -> Length<T> {
+    speed_of_light() / f
+}
+
+/// The frequency of a wave with wavelength `lambda` in vacuum.
+pub fn frequency<T: Float>(lambda: Length<T>) -> Frequency<T> {
+    speed_of_light() / lambda
+}
+
+/// The energy of a photon with wavelength `lambda` in vacuum.
+pub fn photon_energy<T: Float>(lambda: Length<T>) -> Energy<T> {
+    planck_constant() * frequency(lambda)
+}
+
+/// The wavelength in vacuum of a photon with energy `e`.
+pub fn photon_wavelength<T: Float>(e: Energy<T>) -> Length<T> {
+    wavelength(e / planck_constant())
+}
@@ -0,0 +1,70 @@
+//! Helpers for optional values, such as sensor readings that may be missing.
+
+use crate::{Quantity, Unit};
+
+impl<T, U: Unit> Quantity<Option<T>, U> {
+    /// Convert a quantity of an optional value into an optional quantity.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<Option<f32>, si::K> = Quantity::new(Some(300.0));
+    /// assert_eq!(a.transpose().map(Quantity::into_value), Some(300.0));
+    /// ```
+    pub fn transpose(self) -> Option<Quantity<T, U>> {
+        self.val.map(Quantity::new)
+    }
+
+    /// Convert an optional quantity into a quantity of an optional value.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Option<Quantity<f32, si::K>> = None;
+    /// assert_eq!(Quantity::from_option(a).into_value(), None);
+    /// ```
+    pub fn from_option(q: Option<Quantity<T, U>>) -> Self {
+        Quantity::new(q.map(|q| q.val))
+    }
+
+    /// Whether the value is present.
+    pub fn is_some(&self) -> bool {
+        self.val.is_some()
+    }
+
+    /// Whether the value is missing.
+    pub fn is_none(&self) -> bool {
+        self.val.is_none()
+    }
+
+    /// The contained quantity, or `default` if the value is missing.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let reading: Quantity<Option<f32>, si::V> = Quantity::new(None);
+    /// let fallback: Quantity<f32, si::V> = Quantity::new(3.3);
+    /// assert_eq!(reading.unwrap_or(fallback).into_value(), 3.3);
+    /// ```
+    pub fn unwrap_or(self, default: Quantity<T, U>) -> Quantity<T, U> {
+        Quantity::new(self.val.unwrap_or(default.val))
+    }
+
+    /// The contained quantity, or one computed from `f` if the value is
+    /// missing.
+    pub fn unwrap_or_else(self, f: impl FnOnce() -> Quantity<T, U>) -> Quantity<T, U> {
+        Quantity::new(self.val.unwrap_or_else(|| f().val))
+    }
+
+    /// The contained quantity, or the default quantity if the value is
+    /// missing.
+    pub fn unwrap_or_default(self) -> Quantity<T, U>
+    where
+        T: Default,
+    {
+        Quantity::new(self.val.unwrap_or_default())
+    }
+}
+
+impl<T, U: Unit> From<Option<Quantity<T, U>>> for Quantity<Option<T>, U> {
+    fn from(q: Option<Quantity<T, U>>) -> Self {
+        Self::from_option(q)
+    }
+}
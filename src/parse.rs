@@ -0,0 +1,374 @@
+//! Parsing quantities and units from text, such as `3.5 m/s` or `12 kN·m`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::registry::{Registry, RuntimeUnit};
+use crate::{si, Error, Quantity, Ratio, Scalar, UnitInfo};
+
+/// Parse a unit such as `kg*m/s^2`, `m·s⁻¹`, `W/(m·K)`, or `10³·m` in the
+/// unit system with the given base unit symbols.
+pub(crate) fn parse_unit(text: &str, base: &'static [&'static str]) -> Result<RuntimeUnit, Error> {
+    if base == <si::unitless as UnitInfo>::BASE_SYMBOLS {
+        Registry::si().parse(text)
+    } else {
+        Registry::with_base_units(base).parse(text)
+    }
+}
+
+pub(crate) struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    registry: &'a Registry,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(text: &'a str, registry: &'a Registry) -> Self {
+        Self {
+            text,
+            pos: 0,
+            registry,
+        }
+    }
+
+    pub(crate) fn parse(mut self) -> Result<RuntimeUnit, Error> {
+        let base_len = self.registry.base_symbols().len();
+        if self.text.trim().is_empty() {
+            return Ok(RuntimeUnit::unitless(base_len));
+        }
+        let unit = self.expr()?;
+        self.skip_whitespace();
+        match self.peek() {
+            None => Ok(unit),
+            Some(c) => Err(self.error(&format!("unexpected `{}`", c))),
+        }
+    }
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn error(&self, msg: &str) -> Error {
+        Error::ParseError(format!("{} in unit `{}`", msg, self.text))
+    }
+
+    fn expr(&mut self) -> Result<RuntimeUnit, Error> {
+        let mut acc = self.factor()?;
+        loop {
+            self.skip_whitespace();
+            let sign = match self.peek() {
+                Some('*' | '·' | '⋅') => 1,
+                Some('/') => -1,
+                _ => return Ok(acc),
+            };
+            self.bump();
+            self.skip_whitespace();
+            acc = acc.mul(&self.factor()?, sign)?;
+        }
+    }
+
+    fn factor(&mut self) -> Result<RuntimeUnit, Error> {
+        let atom = match self.peek() {
+            Some('(') => {
+                self.bump();
+                self.skip_whitespace();
+                let inner = self.expr()?;
+                self.skip_whitespace();
+                if self.bump() != Some(')') {
+                    return Err(self.error("expected `)`"));
+                }
+                inner
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.bump();
+                }
+                match &self.text[start..self.pos] {
+                    "1" => RuntimeUnit::unitless(self.registry.base_symbols().len()),
+                    "10" => {
+                        let mut ten = RuntimeUnit::unitless(self.registry.base_symbols().len());
+                        ten.exp = 1;
+                        ten
+                    }
+                    n => return Err(self.error(&format!("unexpected factor `{}`", n))),
+                }
+            }
+            Some(c) if is_symbol_char(c) => {
+                let start = self.pos;
+                while self.peek().is_some_and(is_symbol_char) {
+                    self.bump();
+                }
+                let symbol = &self.text[start..self.pos];
+                self.registry
+                    .get(symbol)
+                    .ok_or_else(|| Error::UnknownUnit(symbol.to_string()))?
+            }
+            Some(c) => return Err(self.error(&format!("unexpected `{}`", c))),
+            None => return Err(self.error("expected a unit")),
+        };
+
+        match self.exponent()? {
+            Some(n) => atom.pow(n),
+            None => Ok(atom),
+        }
+    }
+
+    fn exponent(&mut self) -> Result<Option<i32>, Error> {
+        if self.peek() == Some('^') {
+            self.bump();
+            let negative = self.peek() == Some('-');
+            if negative {
+                self.bump();
+            }
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.bump();
+            }
+            let digits = &self.text[start..self.pos];
+            if digits.is_empty() {
+                return Err(self.error("expected an exponent after `^`"));
+            }
+            return self.signed_exponent(digits.bytes().map(|b| i32::from(b - b'0')), negative);
+        }
+
+        let negative = self.peek() == Some('⁻');
+        if negative {
+            self.bump();
+        }
+        let mut digits = Vec::new();
+        while let Some(d) = self.peek().and_then(superscript_digit) {
+            self.bump();
+            digits.push(d);
+        }
+        if digits.is_empty() {
+            return if negative {
+                Err(self.error("expected an exponent after `⁻`"))
+            } else {
+                Ok(None)
+            };
+        }
+        self.signed_exponent(digits.into_iter(), negative)
+    }
+
+    /// The exponent with the decimal `digits`, which must fit in an `i8`
+    /// like the exponents of units.
+    fn signed_exponent(
+        &self,
+        mut digits: impl Iterator<Item = i32>,
+        negative: bool,
+    ) -> Result<Option<i32>, Error> {
+        let n = digits
+            .try_fold(0i32, |n, d| n.checked_mul(10)?.checked_add(d))
+            .map(|n| if negative { -n } else { n })
+            .filter(|n| i8::try_from(*n).is_ok());
+        match n {
+            Some(n) => Ok(Some(n)),
+            None => Err(self.error("exponent out of range")),
+        }
+    }
+}
+
+fn is_symbol_char(c: char) -> bool {
+    c.is_alphabetic() || matches!(c, 'Ω' | '%' | '‰')
+}
+
+fn superscript_digit(c: char) -> Option<i32> {
+    Some(match c {
+        '⁰' => 0,
+        '¹' => 1,
+        '²' => 2,
+        '³' => 3,
+        '⁴' => 4,
+        '⁵' => 5,
+        '⁶' => 6,
+        '⁷' => 7,
+        '⁸' => 8,
+        '⁹' => 9,
+        _ => return None,
+    })
+}
+
+/// Split text such as `3.5 m/s` into the parsed value and the unit text.
+pub(crate) fn split_quantity<T>(s: &str) -> Result<(T, &str), Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let s = s.trim();
+    let (value, unit) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+    let val = value
+        .parse()
+        .map_err(|e| Error::ParseError(format!("invalid value `{}`: {}", value, e)))?;
+    Ok((val, unit.trim()))
+}
+
+/// Convert a value in a parsed unit to the unit `U`, checking that the
+/// dimensions match and that integers aren't truncated.
+pub(crate) fn convert_parsed<T, U>(val: T, unit: &RuntimeUnit, text: &str) -> Result<T, Error>
+where
+    T: Scalar,
+    U: UnitInfo,
+{
+    if unit.kind != U::KIND
+        || !unit
+            .dims
+            .iter()
+            .copied()
+            .eq(U::DIMENSION.iter().map(|&d| i32::from(d)))
+    {
+        return Err(Error::DimensionMismatch {
+            expected: U::SYMBOL.to_string(),
+            found: text.to_string(),
+        });
+    }
+    // Factors such as 1/3600 for `km/h` may differ from the factor of `U` by
+    // a power of ten, which is folded into the exponent.
+    let mut ratio = unit
+        .factor
+        .checked_mul(U::FACTOR.recip())
+        .ok_or(Error::Overflow)?;
+    let mut exp = unit.exp;
+    while ratio.numer % 10 == 0 {
+        ratio.numer /= 10;
+        exp += 1;
+    }
+    while ratio.denom % 10 == 0 {
+        ratio.denom /= 10;
+        exp -= 1;
+    }
+    if ratio != Ratio::ONE {
+        return Err(Error::ParseError(format!(
+            "`{}` can't be converted to {} by a power of ten",
+            text,
+            U::SYMBOL
+        )));
+    }
+    let diff = i8::try_from(i32::from(U::PREFIX) - exp).map_err(|_| Error::Overflow)?;
+    let converted = val
+        .clone()
+        .checked_mul_power_of_ten(diff)
+        .ok_or(Error::Overflow)?;
+    if T::TRUNCATES && diff > 0 && converted.clone().mul_power_of_ten(-diff) != val {
+        return Err(Error::Truncation);
+    }
+    Ok(converted)
+}
+
+impl<T: fmt::Display, U: UnitInfo> Quantity<T, U> {
+    /// The canonical text of the quantity: the value as formatted by
+    /// `Display`, a space, and [`UnitInfo::UNIT_STR`], or only the value if
+    /// the quantity is dimensionless.
+    ///
+    /// The unit is written the same way every time, with base units in the
+    /// order of their unit system, so the text can be used as a key in
+    /// configuration files and databases. Parsing it back into the same unit
+    /// gives the same quantity for integers and floats other than NaN, since
+    /// floats are formatted with as many digits as it takes to round-trip.
+    ///
+    /// ```rust
+    /// use uy::{si, units, Quantity};
+    ///
+    /// let t: Quantity<i64, si::milli<si::s>> = Quantity::new(-1500);
+    /// assert_eq!(t.to_canonical_string(), "-1500 ms");
+    /// assert_eq!(t.to_canonical_string().parse(), Ok(t));
+    ///
+    /// let v: Quantity<f64, uy::Div<si::m, si::s>> = Quantity::new(0.1 + 0.2);
+    /// assert_eq!(v.to_canonical_string(), "0.30000000000000004 m/s");
+    /// assert_eq!(v.to_canonical_string().parse(), Ok(v));
+    ///
+    /// let c: Quantity<f32, si::micro<si::F>> = Quantity::new(4.7);
+    /// assert_eq!(c.to_canonical_string(), "4.7 10^-6*s^4*A^2/m^2/kg");
+    /// assert_eq!(c.to_canonical_string().parse(), Ok(c));
+    ///
+    /// let s: Quantity<f64, units::kmh> = Quantity::new(88.5);
+    /// assert_eq!(s.to_canonical_string().parse::<Quantity<f64, units::kmh>>().unwrap().into_value(), 88.5);
+    ///
+    /// let r: Quantity<u8, si::unitless> = Quantity::new(3);
+    /// assert_eq!(r.to_canonical_string(), "3");
+    /// ```
+    pub fn to_canonical_string(&self) -> String {
+        if U::UNIT_STR.is_empty() {
+            self.val.to_string()
+        } else {
+            format!("{} {}", self.val, U::UNIT_STR)
+        }
+    }
+}
+
+/// Parses a value followed by a unit, separated by whitespace, converting
+/// compatible units to `U`. A value on its own is dimensionless.
+///
+/// Units are written with their symbols, optionally prefixed, and combined
+/// with `*` or `·`, `/`, parentheses, and exponents written either as `^2`
+/// or in superscript. The output of `Display` can always be parsed, and the
+/// [canonical text](Quantity::to_canonical_string) of a quantity parses
+/// back into the same quantity.
+///
+/// Units such as `kgf` that differ from the SI by a factor other than a
+/// power of ten only parse into a unit with the same factor, up to a power
+/// of ten.
+///
+/// ```rust
+/// use uy::{si, Error, Quantity};
+///
+/// let v: Quantity<f64, uy::Div<si::m, si::s>> = "3.5 m/s".parse().unwrap();
+/// assert_eq!(v.into_value(), 3.5);
+///
+/// let v: Quantity<i32, uy::Div<si::milli<si::m>, si::s>> = "3 m·s⁻¹".parse().unwrap();
+/// assert_eq!(v.into_value(), 3000);
+///
+/// let f: Quantity<f32, si::kilo<si::N>> = "1200 kg*m/s^2".parse().unwrap();
+/// assert_eq!(f.into_value(), 1.2);
+///
+/// let t: Result<Quantity<f32, si::s>, _> = "3.5 m".parse();
+/// assert!(matches!(t, Err(Error::DimensionMismatch { .. })));
+/// assert!(matches!("2 Sv".parse::<Quantity<f32, si::Gy>>(), Err(Error::DimensionMismatch { .. })));
+///
+/// let t: Result<Quantity<i32, si::s>, _> = "1500 ms".parse();
+/// assert_eq!(t.unwrap_err(), Error::Truncation);
+///
+/// let d: Result<Quantity<i8, si::milli<si::m>>, _> = "1 km".parse();
+/// assert_eq!(d.unwrap_err(), Error::Overflow);
+/// assert!(matches!("1 m^1000000000".parse::<Quantity<f64, si::m>>(), Err(Error::ParseError(_))));
+///
+/// let v: Quantity<f64, uy::units::kmh> = "90 km/h".parse().unwrap();
+/// assert_eq!(v.into_value(), 90.0);
+///
+/// let w: Quantity<f64, uy::units::kgf> = "2 kgf".parse().unwrap();
+/// assert_eq!(w.into_value(), 2.0);
+/// assert!("2 kgf".parse::<Quantity<f64, si::N>>().is_err());
+/// ```
+impl<T, U> FromStr for Quantity<T, U>
+where
+    T: Scalar + FromStr,
+    T::Err: fmt::Display,
+    U: UnitInfo,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (val, unit) = split_quantity::<T>(s)?;
+        // The canonical form always names `U` itself, even where the
+        // registry doesn't know its symbol.
+        if unit == U::UNIT_STR {
+            return Ok(Quantity::new(val));
+        }
+        let parsed = parse_unit(unit, U::BASE_SYMBOLS)?;
+        convert_parsed::<T, U>(val, &parsed, unit).map(Quantity::new)
+    }
+}
@@ -0,0 +1,113 @@
+//! Axes of quantities for [`plotters`](https://docs.rs/plotters) charts.
+//!
+//! A [`QuantityAxis`] can be passed to `build_cartesian_2d` in place of a
+//! range of `f64`s. Its tick labels carry the unit, with one SI prefix chosen
+//! for the whole axis so the numbers stay short:
+//!
+//! ```rust
+//! use plotters::coord::ranged1d::{Ranged, ValueFormatter};
+//! use uy::plotters::QuantityAxis;
+//! use uy::{si, Quantity};
+//!
+//! let axis: QuantityAxis<si::m> = (Quantity::new(0.0)..Quantity::new(2500.0)).into();
+//! let ticks = axis.key_points(6);
+//! assert_eq!(ticks[1].into_value(), 500.0);
+//! assert_eq!(axis.format_ext(&ticks[1]), "0.5 km");
+//!
+//! let axis: QuantityAxis<uy::Mul<si::m, si::m>> = (Quantity::new(0.0)..Quantity::new(2.0)).into();
+//! assert_eq!(axis.format_ext(&Quantity::new(1.5)), "1.5 m²");
+//! ```
+
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use ::plotters::coord::ranged1d::{KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter};
+use ::plotters::coord::types::RangedCoordf64;
+
+use crate::symbol::{unit_symbol, Style};
+use crate::{Quantity, Unit, UnitInfo};
+
+/// A linear axis over a range of quantities in `U`.
+pub struct QuantityAxis<U: Unit> {
+    inner: RangedCoordf64,
+    // The power of ten the tick labels are written in, relative to `U`.
+    exp: i32,
+    _marker: PhantomData<U>,
+}
+
+impl<U: UnitInfo> QuantityAxis<U> {
+    /// An axis from the start to the end of `range`.
+    pub fn new(range: Range<Quantity<f64, U>>) -> Self {
+        // Units with a symbol of their own, such as scaled units and kinds,
+        // are labelled as they are. Others are labelled with the prefix that
+        // suits the largest end of the range.
+        let prefixable = unit_symbol(U::BASE_SYMBOLS, U::DIMENSION, U::PREFIX, Style::Unicode)
+            .as_str()
+            == U::SYMBOL;
+        let max = range.start.val.abs().max(range.end.val.abs());
+        let exp = if prefixable && max.is_normal() {
+            let magnitude = max.log10().floor() as i32 + i32::from(U::PREFIX);
+            (magnitude.div_euclid(3) * 3).clamp(-30, 30) - i32::from(U::PREFIX)
+        } else {
+            0
+        };
+        Self {
+            inner: (range.start.val..range.end.val).into(),
+            exp,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The symbol the tick labels are written in.
+    pub fn symbol(&self) -> String {
+        if self.exp == 0 {
+            return U::SYMBOL.to_owned();
+        }
+        let exp = i32::from(U::PREFIX) + self.exp;
+        unit_symbol(U::BASE_SYMBOLS, U::DIMENSION, exp as i8, Style::Unicode)
+            .as_str()
+            .to_owned()
+    }
+}
+
+impl<U: UnitInfo> From<Range<Quantity<f64, U>>> for QuantityAxis<U> {
+    fn from(range: Range<Quantity<f64, U>>) -> Self {
+        Self::new(range)
+    }
+}
+
+impl<U: UnitInfo> Ranged for QuantityAxis<U> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = Quantity<f64, U>;
+
+    fn map(&self, value: &Quantity<f64, U>, limit: (i32, i32)) -> i32 {
+        self.inner.map(&value.val, limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Quantity<f64, U>> {
+        self.inner
+            .key_points(hint)
+            .into_iter()
+            .map(Quantity::new)
+            .collect()
+    }
+
+    fn range(&self) -> Range<Quantity<f64, U>> {
+        let range = self.inner.range();
+        Quantity::new(range.start)..Quantity::new(range.end)
+    }
+}
+
+impl<U: UnitInfo> ValueFormatter<Quantity<f64, U>> for QuantityAxis<U> {
+    fn format_ext(&self, value: &Quantity<f64, U>) -> String {
+        // Round away the noise left by rescaling, such as in 0.30000000000000004.
+        let scaled = value.val * 10f64.powi(-self.exp);
+        let rounded = (scaled * 1e9).round() / 1e9;
+        let symbol = self.symbol();
+        if symbol.is_empty() {
+            rounded.to_string()
+        } else {
+            format!("{} {}", rounded, symbol)
+        }
+    }
+}
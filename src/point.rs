@@ -0,0 +1,180 @@
+//! Points, such as timestamps and positions, as distinct from the
+//! quantities between them, such as durations and displacements.
+
+use std::{cmp, fmt, hash, ops};
+
+use crate::{Quantity, ScaleEq, ScaleOrd, Unit, UnitConvertExact, UnitConvertLossy, UnitInfo};
+
+/// The difference between two [`Point`]s, such as a duration or a
+/// displacement. This is an ordinary [`Quantity`]; the name is for
+/// signatures that take both.
+pub type Delta<T, U> = Quantity<T, U>;
+
+/// A point measured in `U` from an origin that isn't part of its type, such
+/// as a timestamp from an epoch or a position along an axis.
+///
+/// Points compare and convert like quantities. Subtracting two points gives
+/// the [`Delta`] between them, and adding a delta to a point moves it.
+/// Adding two points means nothing, so it doesn't compile:
+///
+/// ```rust
+/// use uy::{si, Delta, Point, Quantity};
+///
+/// let sent: Point<i64, si::milli<si::s>> = Point::from_origin(Quantity::new(1_700_000));
+/// let received = sent + Quantity::new(250);
+///
+/// let latency: Delta<i64, si::milli<si::s>> = received - sent;
+/// assert_eq!(latency.into_value(), 250);
+///
+/// let received: Point<i64, si::micro<si::s>> = received.convert();
+/// assert_eq!(received.since_origin().into_value(), 1_700_250_000);
+/// ```
+///
+/// ```rust,compile_fail
+/// use uy::{si, Point, Quantity};
+///
+/// let a: Point<f64, si::m> = Point::from_origin(Quantity::new(1.0));
+/// let b: Point<f64, si::m> = Point::from_origin(Quantity::new(2.0));
+/// let _ = a + b;
+/// ```
+#[repr(transparent)]
+pub struct Point<T, U: Unit> {
+    offset: Quantity<T, U>,
+}
+
+impl<T, U: Unit> Point<T, U> {
+    /// The point `offset` from the origin.
+    #[inline]
+    pub const fn from_origin(offset: Quantity<T, U>) -> Self {
+        Point { offset }
+    }
+
+    /// The quantity from the origin to the point.
+    #[inline]
+    pub fn since_origin(self) -> Quantity<T, U> {
+        self.offset
+    }
+
+    /// Convert to another unit from the same origin, like
+    /// [`Quantity::convert`].
+    #[inline]
+    pub fn convert<Y: UnitConvertExact<T, U>>(self) -> Point<T, Y> {
+        Point::from_origin(self.offset.convert())
+    }
+
+    /// Convert to another unit from the same origin, truncating integers
+    /// towards zero, like [`Quantity::convert_lossy`].
+    #[inline]
+    pub fn convert_lossy<Y: UnitConvertLossy<T, U>>(self) -> Point<T, Y> {
+        Point::from_origin(self.offset.convert_lossy())
+    }
+}
+
+impl<T: Clone, U: Unit> Clone for Point<T, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Point::from_origin(self.offset.clone())
+    }
+}
+
+impl<T: Copy, U: Unit> Copy for Point<T, U> {}
+
+impl<T, U1: Unit, U2: Unit> PartialEq<Point<T, U2>> for Point<T, U1>
+where
+    U1: ScaleEq<T, U2>,
+{
+    #[inline]
+    fn eq(&self, other: &Point<T, U2>) -> bool {
+        self.offset == other.offset
+    }
+}
+
+impl<T: Eq, U: ScaleEq<T, U>> Eq for Point<T, U> {}
+
+impl<T, U1: Unit, U2: Unit> PartialOrd<Point<T, U2>> for Point<T, U1>
+where
+    U1: ScaleOrd<T, U2>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Point<T, U2>) -> Option<cmp::Ordering> {
+        self.offset.partial_cmp(&other.offset)
+    }
+}
+
+impl<T: Ord, U: ScaleOrd<T, U>> Ord for Point<T, U> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.offset.cmp(&other.offset)
+    }
+}
+
+impl<T: hash::Hash, U: Unit> hash::Hash for Point<T, U> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.offset.hash(state);
+    }
+}
+
+impl<T: ops::Add<Output = T>, U: Unit> ops::Add<Delta<T, U>> for Point<T, U> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Delta<T, U>) -> Self {
+        Point::from_origin(self.offset + rhs)
+    }
+}
+
+impl<T: ops::Add<Output = T>, U: Unit> ops::Add<Point<T, U>> for Delta<T, U> {
+    type Output = Point<T, U>;
+
+    #[inline]
+    fn add(self, rhs: Point<T, U>) -> Point<T, U> {
+        Point::from_origin(self + rhs.offset)
+    }
+}
+
+impl<T: ops::Sub<Output = T>, U: Unit> ops::Sub<Delta<T, U>> for Point<T, U> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Delta<T, U>) -> Self {
+        Point::from_origin(self.offset - rhs)
+    }
+}
+
+impl<T: ops::Sub<Output = T>, U: Unit> ops::Sub for Point<T, U> {
+    type Output = Delta<T, U>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Delta<T, U> {
+        self.offset - rhs.offset
+    }
+}
+
+impl<T: ops::AddAssign, U: Unit> ops::AddAssign<Delta<T, U>> for Point<T, U> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Delta<T, U>) {
+        self.offset.val += rhs.val;
+    }
+}
+
+impl<T: ops::SubAssign, U: Unit> ops::SubAssign<Delta<T, U>> for Point<T, U> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Delta<T, U>) {
+        self.offset.val -= rhs.val;
+    }
+}
+
+impl<T: fmt::Debug, U: UnitInfo> fmt::Debug for Point<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Point({:?} {})", self.offset.val, U::SYMBOL)
+    }
+}
+
+/// Formats the offset from the origin, like the `Display` impl of
+/// [`Quantity`].
+impl<T: fmt::Display, U: UnitInfo> fmt::Display for Point<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.offset.fmt(f)
+    }
+}
@@ -0,0 +1,171 @@
+//! Arithmetic between quantities stored in different types.
+
+use std::{fmt, ops};
+
+use crate::{Quantity, Unit, UnitInfo};
+
+/// A quantity that may be combined with a quantity stored in a different
+/// type, created by [`Quantity::promote`].
+///
+/// Both values are converted to the type given by [`Promote`] first, which
+/// is the wider of the two types, so neither loses precision.
+///
+/// ```rust
+/// use uy::{si, Quantity};
+///
+/// let imu: Quantity<f32, uy::Div<si::m, si::s>> = Quantity::new(1.5);
+/// let gps: Quantity<f64, uy::Div<si::m, si::s>> = Quantity::new(2.25);
+/// let mean: Quantity<f64, uy::Div<si::m, si::s>> = (imu + gps.promote()) / Quantity::<f64, si::unitless>::new(2.0);
+/// assert_eq!(mean.into_value(), 1.875);
+///
+/// let ticks: Quantity<i32, si::unitless> = Quantity::new(40);
+/// let period: Quantity<i64, si::micro<si::s>> = Quantity::new(25);
+/// let elapsed: Quantity<i64, si::micro<si::s>> = ticks * period.promote();
+/// assert_eq!(elapsed.into_value(), 1000);
+///
+/// let count: Quantity<u8, si::unitless> = Quantity::new(3);
+/// let step: Quantity<f32, si::m> = Quantity::new(0.5);
+/// assert_eq!((count * step.promote()).into_value(), 1.5);
+/// ```
+///
+/// Types that can't hold each other's values exactly can't be combined:
+///
+/// ```rust,compile_fail
+/// use uy::{si, Quantity};
+///
+/// let a: Quantity<i64, si::m> = Quantity::new(1);
+/// let b: Quantity<f64, si::m> = Quantity::new(1.0);
+/// let _ = a + b.promote();
+/// ```
+#[derive(Clone, Copy)]
+pub struct Promoted<T, U: Unit>(Quantity<T, U>);
+
+impl<T: fmt::Debug, U: UnitInfo> fmt::Debug for Promoted<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Promoted").field(&self.0).finish()
+    }
+}
+
+impl<T, U: Unit> Quantity<T, U> {
+    /// Opt in to arithmetic with a quantity stored in a different type. See
+    /// [`Promoted`].
+    pub fn promote(self) -> Promoted<T, U> {
+        Promoted(self)
+    }
+}
+
+/// The type that values of this type and `Rhs` are both converted to before
+/// they're combined, which can hold every value of both.
+pub trait Promote<Rhs> {
+    type Output;
+
+    /// Convert both values to the output type.
+    fn promote(lhs: Self, rhs: Rhs) -> (Self::Output, Self::Output);
+}
+
+macro_rules! impl_promote_same {
+    ($($ty:ty),*) => {
+        $(
+            impl Promote<$ty> for $ty {
+                type Output = $ty;
+
+                fn promote(lhs: Self, rhs: Self) -> (Self, Self) {
+                    (lhs, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_promote_same!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64);
+
+macro_rules! impl_promote {
+    ($($wide:ty => $($narrow:ty),*;)*) => {
+        $($(
+            impl Promote<$narrow> for $wide {
+                type Output = $wide;
+
+                fn promote(lhs: Self, rhs: $narrow) -> (Self, Self) {
+                    (lhs, <$wide>::from(rhs))
+                }
+            }
+
+            impl Promote<$wide> for $narrow {
+                type Output = $wide;
+
+                fn promote(lhs: Self, rhs: $wide) -> ($wide, $wide) {
+                    (<$wide>::from(lhs), rhs)
+                }
+            }
+        )*)*
+    };
+}
+
+impl_promote! {
+    i16 => i8, u8;
+    i32 => i8, i16, u8, u16;
+    i64 => i8, i16, i32, u8, u16, u32;
+    i128 => i8, i16, i32, i64, u8, u16, u32, u64;
+    u16 => u8;
+    u32 => u8, u16;
+    u64 => u8, u16, u32;
+    u128 => u8, u16, u32, u64;
+    f32 => i8, i16, u8, u16;
+    f64 => i8, i16, i32, u8, u16, u32, f32;
+}
+
+impl<T1, T2, U: Unit> ops::Add<Promoted<T2, U>> for Quantity<T1, U>
+where
+    T1: Promote<T2>,
+    T1::Output: ops::Add<Output = T1::Output>,
+{
+    type Output = Quantity<T1::Output, U>;
+
+    fn add(self, rhs: Promoted<T2, U>) -> Self::Output {
+        let (lhs, rhs) = T1::promote(self.val, rhs.0.val);
+        Quantity::new(lhs + rhs)
+    }
+}
+
+impl<T1, T2, U: Unit> ops::Sub<Promoted<T2, U>> for Quantity<T1, U>
+where
+    T1: Promote<T2>,
+    T1::Output: ops::Sub<Output = T1::Output>,
+{
+    type Output = Quantity<T1::Output, U>;
+
+    fn sub(self, rhs: Promoted<T2, U>) -> Self::Output {
+        let (lhs, rhs) = T1::promote(self.val, rhs.0.val);
+        Quantity::new(lhs - rhs)
+    }
+}
+
+impl<T1, T2, U1: Unit, U2: Unit> ops::Mul<Promoted<T2, U2>> for Quantity<T1, U1>
+where
+    T1: Promote<T2>,
+    T1::Output: ops::Mul<Output = T1::Output>,
+    U1: ops::Mul<U2>,
+    U1::Output: Unit,
+{
+    type Output = Quantity<T1::Output, U1::Output>;
+
+    fn mul(self, rhs: Promoted<T2, U2>) -> Self::Output {
+        let (lhs, rhs) = T1::promote(self.val, rhs.0.val);
+        Quantity::new(lhs * rhs)
+    }
+}
+
+impl<T1, T2, U1: Unit, U2: Unit> ops::Div<Promoted<T2, U2>> for Quantity<T1, U1>
+where
+    T1: Promote<T2>,
+    T1::Output: ops::Div<Output = T1::Output>,
+    U1: ops::Div<U2>,
+    U1::Output: Unit,
+{
+    type Output = Quantity<T1::Output, U1::Output>;
+
+    fn div(self, rhs: Promoted<T2, U2>) -> Self::Output {
+        let (lhs, rhs) = T1::promote(self.val, rhs.0.val);
+        Quantity::new(lhs / rhs)
+    }
+}
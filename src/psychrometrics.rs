@@ -0,0 +1,99 @@
+//! Properties of moist air: saturation vapor pressure, relative humidity,
+//! and dew point.
+//!
+//! These use the Magnus formula with the coefficients of Alduchov and
+//! Eskridge (1996), which is accurate to within 0.4% of the saturation vapor
+//! pressure over water from -40 °C to 50 °C. Temperatures are in kelvins.
+//!
+//! ```rust
+//! use uy::psychrometrics::{self, percent_RH};
+//! use uy::si::quantities::Temperature;
+//! use uy::{si, Quantity};
+//!
+//! // 25 °C at 60% relative humidity.
+//! let t: Temperature<f64> = Quantity::new(298.15);
+//! let rh: Quantity<f64, percent_RH> = Quantity::new(60.0);
+//! assert_eq!(rh.to_string(), "60 %RH");
+//!
+//! let es: Quantity<f64, si::kilo<si::Pa>> = psychrometrics::saturation_vapor_pressure(t).convert();
+//! assert!((es.into_value() - 3.17).abs() < 0.01);
+//!
+//! let dew = psychrometrics::dew_point(t, rh);
+//! assert!((dew.into_value() - 273.15 - 16.7).abs() < 0.1);
+//! assert!((psychrometrics::relative_humidity(t, dew).into_value() - 60.0).abs() < 1e-9);
+//! ```
+//!
+//! Relative humidity is a kind of its own, so it can't be passed where any
+//! other percentage is expected:
+//!
+//! ```rust,compile_fail
+//! use uy::si::quantities::Temperature;
+//! use uy::{units, Quantity};
+//!
+//! let efficiency: Quantity<f64, units::percent> = Quantity::new(60.0);
+//! uy::psychrometrics::dew_point(Temperature::new(298.15), efficiency);
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use num_traits::Float;
+
+use crate::kind::kind;
+use crate::si::quantities::{Pressure, Temperature};
+use crate::{units, Kinded, Quantity};
+
+kind!(
+    /// Relative humidity, the partial pressure of water vapor in air as a
+    /// fraction of its saturation vapor pressure.
+    RelativeHumidity: units::percent, "%RH"
+);
+
+/// Percent relative humidity.
+pub type percent_RH = Kinded<RelativeHumidity, units::percent>;
+
+// The Magnus coefficients, as a factor in pascals, a dimensionless
+// exponent, and a temperature in degrees Celsius.
+const MAGNUS_A: f64 = 610.94;
+const MAGNUS_B: f64 = 17.625;
+const MAGNUS_C: f64 = 243.04;
+/// The offset from kelvins to degrees Celsius.
+const ZERO_CELSIUS: f64 = 273.15;
+
+fn constant<T: Float>(c: f64) -> T {
+    T::from(c).unwrap()
+}
+
+/// The exponent of the Magnus formula at `t` degrees Celsius.
+fn magnus_exponent<T: Float>(t: T) -> T {
+    constant::<T>(MAGNUS_B) * t / (t + constant(MAGNUS_C))
+}
+
+/// The pressure of water vapor in equilibrium with liquid water at
+/// `temperature`.
+pub fn saturation_vapor_pressure<T: Float>(temperature: Temperature<T>) -> Pressure<T> {
+    let t = temperature.val - constant(ZERO_CELSIUS);
+    Quantity::new(constant::<T>(MAGNUS_A) * magnus_exponent(t).exp())
+}
+
+/// The temperature to which air at `temperature` and `humidity` must be
+/// cooled for water to condense.
+pub fn dew_point<T: Float>(
+    temperature: Temperature<T>,
+    humidity: Quantity<T, percent_RH>,
+) -> Temperature<T> {
+    let t = temperature.val - constant(ZERO_CELSIUS);
+    let gamma = (humidity.val / constant(100.0)).ln() + magnus_exponent(t);
+    let dew = constant::<T>(MAGNUS_C) * gamma / (constant::<T>(MAGNUS_B) - gamma);
+    Quantity::new(dew + constant(ZERO_CELSIUS))
+}
+
+/// The relative humidity of air at `temperature` with a dew point of
+/// `dew_point`.
+pub fn relative_humidity<T: Float>(
+    temperature: Temperature<T>,
+    dew_point: Temperature<T>,
+) -> Quantity<T, percent_RH> {
+    let ratio =
+        saturation_vapor_pressure(dew_point).val / saturation_vapor_pressure(temperature).val;
+    Quantity::new(ratio * constant(100.0))
+}
@@ -0,0 +1,61 @@
+//! Conversions between [`Quantity`] and Python objects.
+//!
+//! A quantity is exchanged with Python as a `(value, unit)` tuple, where
+//! `unit` is the [`UnitInfo::SYMBOL`] of the quantity's unit. When
+//! extracting, the unit must match exactly.
+//!
+//! ```rust
+//! use pyo3::prelude::*;
+//! use uy::{si, Quantity};
+//!
+//! pyo3::Python::initialize();
+//! Python::attach(|py| {
+//!     let v: Quantity<f64, uy::Div<si::m, si::s>> = Quantity::new(3.5);
+//!     let obj = v.into_pyobject(py).unwrap();
+//!     assert_eq!(obj.to_string(), "(3.5, 'm·s⁻¹')");
+//!
+//!     let back: Quantity<f64, uy::Div<si::m, si::s>> = obj.extract().unwrap();
+//!     assert_eq!(back.into_value(), 3.5);
+//!     assert!(obj.extract::<Quantity<f64, si::m>>().is_err());
+//! });
+//! ```
+
+use ::pyo3::exceptions::PyValueError;
+use ::pyo3::prelude::*;
+use ::pyo3::types::PyTuple;
+
+use crate::{Quantity, UnitInfo};
+
+impl<'py, T, U> IntoPyObject<'py> for Quantity<T, U>
+where
+    T: IntoPyObject<'py>,
+    U: UnitInfo,
+{
+    type Target = PyTuple;
+    type Output = Bound<'py, PyTuple>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        (self.val, U::SYMBOL).into_pyobject(py)
+    }
+}
+
+impl<'a, 'py, T, U> FromPyObject<'a, 'py> for Quantity<T, U>
+where
+    T: FromPyObject<'a, 'py>,
+    U: UnitInfo,
+{
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let (val, unit): (T, String) = obj.extract()?;
+        if unit != U::SYMBOL {
+            return Err(PyValueError::new_err(format!(
+                "expected a quantity in {}, found {}",
+                U::SYMBOL,
+                unit
+            )));
+        }
+        Ok(Quantity::new(val))
+    }
+}
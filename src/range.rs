@@ -0,0 +1,579 @@
+//! Ranges of quantities, such as an operating envelope of `2.7 V..=5.5 V`,
+//! quantities that are kept within a range, and quantities with a
+//! tolerance.
+
+use std::{fmt, ops};
+
+use num_traits::{Float, NumCast};
+
+use crate::units::Percent;
+use crate::{Error, Quantity, Unit, UnitInfo};
+
+/// A half-open range of quantities, `start..end`.
+///
+/// ```rust
+/// use uy::{si, Quantity, QuantityRange, QuantityRangeInclusive};
+///
+/// let range: QuantityRange<i32, si::milli<si::s>> =
+///     (Quantity::new(0)..Quantity::new(10)).into();
+/// assert!(range.contains(&Quantity::new(9)));
+/// assert!(!range.contains(&Quantity::new(10)));
+///
+/// let steps: Vec<i32> = range.step_by(Quantity::new(4)).map(Quantity::into_value).collect();
+/// assert_eq!(steps, [0, 4, 8]);
+///
+/// let all: QuantityRangeInclusive<u8, si::s> = (Quantity::new(250)..=Quantity::new(255)).into();
+/// assert_eq!(all.step_by(Quantity::new(5)).count(), 2);
+/// ```
+#[derive(Clone, Copy)]
+pub struct QuantityRange<T, U: Unit> {
+    pub start: Quantity<T, U>,
+    pub end: Quantity<T, U>,
+}
+
+/// An inclusive range of quantities, `start..=end`.
+///
+/// ```rust
+/// use uy::{si, Quantity, QuantityRangeInclusive};
+///
+/// let supply: QuantityRangeInclusive<f32, si::V> =
+///     QuantityRangeInclusive::new(Quantity::new(2.7), Quantity::new(5.5));
+/// assert!(supply.contains(&Quantity::new(3.3)));
+/// assert_eq!(supply.clamp(Quantity::new(12.0)).into_value(), 5.5);
+///
+/// let usb = QuantityRangeInclusive::new(Quantity::new(4.75), Quantity::new(5.25));
+/// let both = supply.intersect(&usb).unwrap();
+/// assert_eq!(both.end.into_value(), 5.25);
+/// ```
+#[derive(Clone, Copy)]
+pub struct QuantityRangeInclusive<T, U: Unit> {
+    pub start: Quantity<T, U>,
+    pub end: Quantity<T, U>,
+}
+
+macro_rules! impl_range {
+    ($range:ident, $end_cmp:tt, $inclusive:literal) => {
+        impl<T, U: Unit> $range<T, U> {
+            pub fn new(start: Quantity<T, U>, end: Quantity<T, U>) -> Self {
+                Self { start, end }
+            }
+
+            /// Whether `q` lies within the range.
+            pub fn contains(&self, q: &Quantity<T, U>) -> bool
+            where
+                T: PartialOrd,
+            {
+                self.start.val <= q.val && q.val $end_cmp self.end.val
+            }
+
+            /// Whether the range contains no quantities.
+            pub fn is_empty(&self) -> bool
+            where
+                T: PartialOrd,
+            {
+                !self.contains(&self.start)
+            }
+
+            /// The range of quantities contained in both `self` and `other`,
+            /// or `None` if they don't overlap.
+            pub fn intersect(&self, other: &Self) -> Option<Self>
+            where
+                T: PartialOrd + Clone,
+            {
+                let start = if self.start.val >= other.start.val {
+                    &self.start
+                } else {
+                    &other.start
+                };
+                let end = if self.end.val <= other.end.val {
+                    &self.end
+                } else {
+                    &other.end
+                };
+                let range = Self::new(start.clone(), end.clone());
+                if range.is_empty() {
+                    None
+                } else {
+                    Some(range)
+                }
+            }
+
+            /// Iterate over the range from `start` in increments of `step`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `step` is not positive.
+            pub fn step_by(self, step: Quantity<T, U>) -> StepBy<T, U>
+            where
+                T: Copy + PartialOrd + num_traits::CheckedAdd + num_traits::Zero,
+            {
+                assert!(step.val > T::zero(), "step must be positive");
+                StepBy {
+                    next: Some(self.start.val),
+                    end: self.end.val,
+                    step: step.val,
+                    inclusive: $inclusive,
+                    _marker: std::marker::PhantomData,
+                }
+            }
+        }
+    };
+}
+
+impl_range!(QuantityRange, <, false);
+impl_range!(QuantityRangeInclusive, <=, true);
+
+impl<T: fmt::Debug, U: Unit> fmt::Debug for QuantityRange<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+impl<T: fmt::Debug, U: Unit> fmt::Debug for QuantityRangeInclusive<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..={:?}", self.start, self.end)
+    }
+}
+
+impl<T, U: Unit> From<ops::Range<Quantity<T, U>>> for QuantityRange<T, U> {
+    fn from(range: ops::Range<Quantity<T, U>>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<T, U: Unit> From<ops::RangeInclusive<Quantity<T, U>>> for QuantityRangeInclusive<T, U> {
+    fn from(range: ops::RangeInclusive<Quantity<T, U>>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::new(start, end)
+    }
+}
+
+impl<T, U: Unit> QuantityRangeInclusive<T, U> {
+    /// Restrict `q` to the range.
+    pub fn clamp(&self, q: Quantity<T, U>) -> Quantity<T, U>
+    where
+        T: PartialOrd + Clone,
+    {
+        if q.val < self.start.val {
+            self.start.clone()
+        } else if q.val > self.end.val {
+            self.end.clone()
+        } else {
+            q
+        }
+    }
+}
+
+/// Iterate from `start` up to, but not including, `end` in increments of
+/// `step`. This is the quantity equivalent of `(start..end).step_by(step)`,
+/// which isn't possible because `std::iter::Step` is unstable.
+///
+/// ```rust
+/// use uy::{si, Quantity};
+///
+/// let sweep: Vec<Quantity<i32, si::milli<si::V>>> =
+///     uy::range::range(Quantity::new(0), Quantity::new(3300), Quantity::new(1100)).collect();
+/// assert_eq!(sweep.len(), 3);
+/// assert_eq!(sweep[2].into_value(), 2200);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `step` is not positive.
+pub fn range<T, U: Unit>(
+    start: Quantity<T, U>,
+    end: Quantity<T, U>,
+    step: Quantity<T, U>,
+) -> StepBy<T, U>
+where
+    T: Copy + PartialOrd + num_traits::CheckedAdd + num_traits::Zero,
+{
+    QuantityRange::new(start, end).step_by(step)
+}
+
+/// Iterate from `start` up to and including `end` in increments of `step`.
+///
+/// ```rust
+/// use uy::{si, Quantity};
+///
+/// let ticks = uy::range::range_inclusive::<u32, si::milli<si::s>>(
+///     Quantity::new(0),
+///     Quantity::new(1000),
+///     Quantity::new(250),
+/// );
+/// assert_eq!(ticks.count(), 5);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `step` is not positive.
+pub fn range_inclusive<T, U: Unit>(
+    start: Quantity<T, U>,
+    end: Quantity<T, U>,
+    step: Quantity<T, U>,
+) -> StepBy<T, U>
+where
+    T: Copy + PartialOrd + num_traits::CheckedAdd + num_traits::Zero,
+{
+    QuantityRangeInclusive::new(start, end).step_by(step)
+}
+
+/// An iterator over a range of integer quantities in fixed increments,
+/// created by [`range`], [`range_inclusive`], or `step_by` on
+/// [`QuantityRange`] and [`QuantityRangeInclusive`].
+#[derive(Debug, Clone)]
+pub struct StepBy<T, U: Unit> {
+    next: Option<T>,
+    end: T,
+    step: T,
+    inclusive: bool,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<T, U: Unit> Iterator for StepBy<T, U>
+where
+    T: Copy + PartialOrd + num_traits::CheckedAdd,
+{
+    type Item = Quantity<T, U>;
+
+    fn next(&mut self) -> Option<Quantity<T, U>> {
+        let val = self.next?;
+        let in_range = if self.inclusive {
+            val <= self.end
+        } else {
+            val < self.end
+        };
+        if !in_range {
+            return None;
+        }
+        // Stepping past the largest value of `T` ends the iteration.
+        self.next = val.checked_add(&self.step);
+        Some(Quantity::new(val))
+    }
+}
+
+impl<T, U: Unit> std::iter::FusedIterator for StepBy<T, U> where
+    T: Copy + PartialOrd + num_traits::CheckedAdd
+{
+}
+
+/// A quantity that is always between `MIN` and `MAX` inclusive, given as
+/// whole numbers of `U`, such as a command limit for an actuator.
+///
+/// Constructing a `Bounded` or doing arithmetic on it checks the bounds, and
+/// fails with [`Error::OutOfRange`] if the result is outside them.
+///
+/// ```rust
+/// use uy::range::Bounded;
+/// use uy::{si, Quantity};
+///
+/// type MotorCurrent = Bounded<f32, si::milli<si::A>, -1500, 1500>;
+///
+/// let cmd = MotorCurrent::new(Quantity::new(1200.0)).unwrap();
+/// assert!((cmd + Quantity::new(200.0)).is_ok());
+///
+/// let err = (cmd + Quantity::new(400.0)).unwrap_err();
+/// assert_eq!(err.to_string(), "1600 mA is outside the range -1500 mA..=1500 mA");
+///
+/// assert_eq!(MotorCurrent::saturating(Quantity::new(-2000.0)).get().into_value(), -1500.0);
+///
+/// let span = Bounded::<i16, si::m, 0, 30000>::new(Quantity::new(30000)).unwrap();
+/// let err = (span + Quantity::new(30000)).unwrap_err();
+/// assert_eq!(err.to_string(), "30000 m + 30000 m is outside the range 0 m..=30000 m");
+/// ```
+pub struct Bounded<T, U: Unit, const MIN: i64, const MAX: i64>(Quantity<T, U>);
+
+impl<T, U, const MIN: i64, const MAX: i64> Bounded<T, U, MIN, MAX>
+where
+    T: PartialOrd + NumCast + fmt::Display,
+    U: UnitInfo,
+{
+    /// The smallest allowed quantity.
+    pub fn min() -> Quantity<T, U> {
+        Quantity::new(T::from(MIN).expect("`MIN` doesn't fit in the value type"))
+    }
+
+    /// The largest allowed quantity.
+    pub fn max() -> Quantity<T, U> {
+        Quantity::new(T::from(MAX).expect("`MAX` doesn't fit in the value type"))
+    }
+
+    /// The allowed quantities, as a range.
+    pub fn range() -> QuantityRangeInclusive<T, U> {
+        QuantityRangeInclusive::new(Self::min(), Self::max())
+    }
+
+    /// Check that `q` is within the bounds.
+    pub fn new(q: Quantity<T, U>) -> Result<Self, Error> {
+        const { assert!(MIN <= MAX, "`MIN` is greater than `MAX`") };
+        let (min, max) = (Self::min(), Self::max());
+        // Written so that NaN is out of range.
+        if q.val >= min.val && q.val <= max.val {
+            Ok(Self(q))
+        } else {
+            Err(Self::out_of_range(q.to_string()))
+        }
+    }
+
+    fn out_of_range(value: String) -> Error {
+        Error::OutOfRange {
+            value,
+            min: Self::min().to_string(),
+            max: Self::max().to_string(),
+        }
+    }
+
+    /// Clamp `q` to the bounds.
+    pub fn saturating(q: Quantity<T, U>) -> Self
+    where
+        T: Clone,
+    {
+        const { assert!(MIN <= MAX, "`MIN` is greater than `MAX`") };
+        Self(Self::range().clamp(q))
+    }
+}
+
+impl<T, U: Unit, const MIN: i64, const MAX: i64> Bounded<T, U, MIN, MAX> {
+    pub fn get(&self) -> &Quantity<T, U> {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Quantity<T, U> {
+        self.0
+    }
+}
+
+impl<T: Clone, U: Unit, const MIN: i64, const MAX: i64> Clone for Bounded<T, U, MIN, MAX> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Copy, U: Unit, const MIN: i64, const MAX: i64> Copy for Bounded<T, U, MIN, MAX> {}
+
+impl<T: PartialEq, U: Unit, const MIN: i64, const MAX: i64> PartialEq for Bounded<T, U, MIN, MAX> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.val == other.0.val
+    }
+}
+
+impl<T: fmt::Debug, U: UnitInfo, const MIN: i64, const MAX: i64> fmt::Debug
+    for Bounded<T, U, MIN, MAX>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Bounded({:?} in {}..={} {})",
+            self.0.val,
+            MIN,
+            MAX,
+            U::SYMBOL
+        )
+    }
+}
+
+impl<T, U: Unit, const MIN: i64, const MAX: i64> From<Bounded<T, U, MIN, MAX>> for Quantity<T, U> {
+    fn from(bounded: Bounded<T, U, MIN, MAX>) -> Self {
+        bounded.0
+    }
+}
+
+/// Arithmetic that reports overflow instead of panicking or wrapping, so
+/// that arithmetic on a [`Bounded`] integer that overflows its type fails
+/// with [`Error::OutOfRange`] like any other result outside the bounds.
+/// Floats don't overflow, they go to infinity, which is out of range.
+pub trait CheckedArith: Sized {
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+    fn checked_div(&self, rhs: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_arith_int {
+    ($($ty:ty),*) => {
+        $(
+            impl CheckedArith for $ty {
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    <$ty>::checked_add(*self, *rhs)
+                }
+
+                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                    <$ty>::checked_sub(*self, *rhs)
+                }
+
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    <$ty>::checked_mul(*self, *rhs)
+                }
+
+                fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                    <$ty>::checked_div(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_arith_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_checked_arith_float {
+    ($($ty:ty),*) => {
+        $(
+            impl CheckedArith for $ty {
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    Some(self + rhs)
+                }
+
+                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                    Some(self - rhs)
+                }
+
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    Some(self * rhs)
+                }
+
+                fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                    Some(self / rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_arith_float!(f32, f64);
+
+macro_rules! impl_bounded_ops {
+    ($($trait:ident::$fn:ident($checked:ident, $op:literal));*) => {
+        $(
+            /// Apply the operation, checking that the result is within the
+            /// bounds.
+            impl<T, U, const MIN: i64, const MAX: i64> ops::$trait<Quantity<T, U>> for Bounded<T, U, MIN, MAX>
+            where
+                T: CheckedArith + PartialOrd + NumCast + fmt::Display,
+                U: UnitInfo,
+            {
+                type Output = Result<Self, Error>;
+
+                fn $fn(self, rhs: Quantity<T, U>) -> Result<Self, Error> {
+                    match self.0.val.$checked(&rhs.val) {
+                        Some(val) => Self::new(Quantity::new(val)),
+                        None => Err(Self::out_of_range(format!("{} {} {}", self.0, $op, rhs))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bounded_ops!(Add::add(checked_add, "+"); Sub::sub(checked_sub, "-"));
+
+macro_rules! impl_bounded_scalar_ops {
+    ($($trait:ident::$fn:ident($checked:ident, $op:literal));*) => {
+        $(
+            /// Scale by a plain number, checking that the result is within
+            /// the bounds.
+            impl<T, U, const MIN: i64, const MAX: i64> ops::$trait<T> for Bounded<T, U, MIN, MAX>
+            where
+                T: CheckedArith + PartialOrd + NumCast + fmt::Display,
+                U: UnitInfo,
+            {
+                type Output = Result<Self, Error>;
+
+                fn $fn(self, rhs: T) -> Result<Self, Error> {
+                    match self.0.val.$checked(&rhs) {
+                        Some(val) => Self::new(Quantity::new(val)),
+                        None => Err(Self::out_of_range(format!("{} {} {}", self.0, $op, rhs))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bounded_scalar_ops!(Mul::mul(checked_mul, "*"); Div::div(checked_div, "/"));
+
+/// A nominal quantity with a symmetric tolerance, such as a resistor's
+/// 10 kΩ ±1 %.
+///
+/// ```rust
+/// use uy::{si, Quantity, Toleranced};
+///
+/// let r = Quantity::<f64, si::kilo<si::Ohm>>::with_tolerance(Quantity::new(10.0), Quantity::new(1.0));
+/// assert_eq!(r.nominal().into_value(), 10.0);
+/// assert!((r.tolerance().into_value() - 0.1).abs() < 1e-12);
+///
+/// let bounds = r.bounds();
+/// assert!((bounds.start.into_value() - 9.9).abs() < 1e-12);
+/// assert!((bounds.end.into_value() - 10.1).abs() < 1e-12);
+/// assert!(r.contains(&Quantity::new(10.05)));
+///
+/// let rod: Toleranced<f64, si::milli<si::m>> = Toleranced::from_bounds(Quantity::new(4.75), Quantity::new(5.25));
+/// assert_eq!(rod.nominal().into_value(), 5.0);
+/// assert_eq!(rod.to_string(), "5 mm ± 0.25 mm");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Toleranced<T, U: Unit> {
+    nominal: Quantity<T, U>,
+    tolerance: Quantity<T, U>,
+}
+
+impl<T: Float, U: Unit> Toleranced<T, U> {
+    /// A nominal quantity that may be off by up to `tolerance` either way.
+    pub fn new(nominal: Quantity<T, U>, tolerance: Quantity<T, U>) -> Self {
+        Self {
+            nominal,
+            tolerance: Quantity::new(tolerance.val.abs()),
+        }
+    }
+
+    /// The quantities from `min` to `max`, with the midpoint as the nominal
+    /// quantity.
+    pub fn from_bounds(min: Quantity<T, U>, max: Quantity<T, U>) -> Self {
+        let two = T::one() + T::one();
+        Self::new(
+            Quantity::new((min.val + max.val) / two),
+            Quantity::new((max.val - min.val) / two),
+        )
+    }
+
+    pub fn nominal(&self) -> Quantity<T, U> {
+        self.nominal
+    }
+
+    /// How far the quantity may be from nominal either way.
+    pub fn tolerance(&self) -> Quantity<T, U> {
+        self.tolerance
+    }
+
+    /// The smallest and largest quantities within tolerance.
+    pub fn bounds(&self) -> QuantityRangeInclusive<T, U> {
+        QuantityRangeInclusive::new(
+            Quantity::new(self.nominal.val - self.tolerance.val),
+            Quantity::new(self.nominal.val + self.tolerance.val),
+        )
+    }
+
+    /// Whether `q` is within tolerance.
+    pub fn contains(&self, q: &Quantity<T, U>) -> bool {
+        self.bounds().contains(q)
+    }
+}
+
+impl<T: Float, U: Unit> Quantity<T, U> {
+    /// `nominal` with a tolerance of `percent` of it either way.
+    pub fn with_tolerance(nominal: Self, percent: Percent<T>) -> Toleranced<T, U> {
+        let tolerance = nominal.val * percent.val / T::from(100).unwrap();
+        Toleranced::new(nominal, Quantity::new(tolerance))
+    }
+}
+
+impl<T: fmt::Display, U: UnitInfo> fmt::Display for Toleranced<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ± {}", self.nominal, self.tolerance)
+    }
+}
+
+impl<T: fmt::Debug, U: Unit> fmt::Debug for Toleranced<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} ± {:?}", self.nominal, self.tolerance)
+    }
+}
@@ -0,0 +1,91 @@
+//! Quantities stored in exact rationals from
+//! [`num-rational`](https://docs.rs/num-rational).
+//!
+//! Powers of ten only scale the numerator or the denominator, so converting
+//! between prefixes never loses anything, even to a coarser unit:
+//!
+//! ```rust
+//! use num_rational::{BigRational, Ratio};
+//! use uy::{si, Quantity};
+//!
+//! let third: Quantity<Ratio<i64>, si::m> = Quantity::new(Ratio::new(1, 3));
+//! let km: Quantity<Ratio<i64>, si::kilo<si::m>> = third.convert_lossy();
+//! assert_eq!(km.into_value(), Ratio::new(1, 3000));
+//! assert_eq!(km.convert::<si::m>().into_value(), Ratio::new(1, 3));
+//!
+//! let big: Quantity<Ratio<i64>, si::m> = Quantity::new(Ratio::from_integer(i64::MAX));
+//! assert!(big.checked_convert::<si::milli<si::m>>().is_none());
+//! assert_eq!(big.convert_saturating::<si::milli<si::m>>().into_value(), Ratio::from_integer(i64::MAX));
+//! let low: Quantity<Ratio<i64>, si::m> = Quantity::new(Ratio::new(i64::MIN + 1, 7));
+//! assert_eq!(low.convert_saturating::<si::milli<si::m>>().into_value(), Ratio::from_integer(i64::MIN));
+//!
+//! let exact: Quantity<BigRational, si::m> = Quantity::new(BigRational::from_integer(i64::MAX.into()));
+//! let mm: Quantity<BigRational, si::milli<si::m>> = exact.convert();
+//! assert_eq!(mm.into_value(), BigRational::from_integer((i128::from(i64::MAX) * 1000).into()));
+//! ```
+
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::CheckedMul;
+
+use crate::{ExactPowerOfTen, MulPowerOfTen};
+
+/// Scales the numerator when multiplying and the denominator when dividing,
+/// so the result is exact.
+impl<T> MulPowerOfTen for Ratio<T>
+where
+    T: Clone + Integer + CheckedMul + From<u8>,
+{
+    #[inline]
+    fn mul_power_of_ten(self, exp: i8) -> Self {
+        self.checked_mul_power_of_ten(exp)
+            .expect("rational quantity overflowed when scaled by a power of ten")
+    }
+
+    #[inline]
+    fn checked_mul_power_of_ten(self, exp: i8) -> Option<Self> {
+        let mut scale = T::from(1);
+        for _ in 0..exp.unsigned_abs() {
+            scale = scale.checked_mul(&T::from(10))?;
+        }
+        let (numer, denom) = self.into_raw();
+        if exp < 0 {
+            Some(Ratio::new(numer.checked_mul(&scale)?, denom))
+        } else {
+            Some(Ratio::new(numer, denom.checked_mul(&scale)?))
+        }
+    }
+
+    /// Saturates the numerator to the bounds of `T` when multiplying, and
+    /// the denominator when dividing.
+    #[inline]
+    fn saturating_mul_power_of_ten(self, exp: i8) -> Self {
+        if let Some(scaled) = self.clone().checked_mul_power_of_ten(exp) {
+            return scaled;
+        }
+        let max = max_value::<T>();
+        let (numer, _) = self.into_raw();
+        if exp > 0 {
+            Ratio::new(numer, max)
+        } else if numer < T::zero() {
+            Ratio::from_integer(T::zero() - max - T::one())
+        } else {
+            Ratio::from_integer(max)
+        }
+    }
+}
+
+/// The largest value of an integer type that has overflowed, which
+/// `Integer` has no bound for, found by doubling with `checked_mul`.
+fn max_value<T: Clone + Integer + CheckedMul + From<u8>>() -> T {
+    let mut high = T::one();
+    while let Some(next) = high.checked_mul(&T::from(2)) {
+        high = next;
+    }
+    high.clone() + (high - T::one())
+}
+
+impl<T, const FROM: i8, const TO: i8> ExactPowerOfTen<FROM, TO> for Ratio<T> where
+    T: Clone + Integer + CheckedMul + From<u8>
+{
+}
@@ -0,0 +1,83 @@
+//! Parallel iteration over quantities with [`rayon`](https://docs.rs/rayon).
+//!
+//! Slices and vectors of quantities can be iterated in parallel like any
+//! other, and summed since quantities implement [`Sum`](std::iter::Sum).
+//! [`ParallelQuantityIterator`] adds reductions that only need
+//! `PartialOrd`, so they work on floating-point quantities:
+//!
+//! ```rust
+//! use rayon::prelude::*;
+//! use uy::rayon::ParallelQuantityIterator;
+//! use uy::{si, Quantity};
+//!
+//! let samples: Vec<Quantity<f64, si::milli<si::V>>> =
+//!     (0..1000).map(|i| Quantity::new(i as f64)).collect();
+//!
+//! let total: Quantity<f64, si::milli<si::V>> = samples.par_iter().copied().sum();
+//! assert_eq!(total.into_value(), 499_500.0);
+//! assert_eq!(samples.par_iter().copied().max_quantity().unwrap().into_value(), 999.0);
+//!
+//! let volts: Vec<Quantity<f64, si::V>> = uy::rayon::convert(&samples);
+//! assert_eq!(volts[500].into_value(), 0.5);
+//! ```
+
+use ::rayon::prelude::*;
+
+use crate::{Quantity, Unit, UnitConvertExact};
+
+/// Reductions over parallel iterators of quantities.
+pub trait ParallelQuantityIterator<T, U: Unit>: ParallelIterator<Item = Quantity<T, U>>
+where
+    T: PartialOrd + Send,
+{
+    /// The smallest quantity, ignoring any that can't be compared, such as
+    /// NaNs.
+    fn min_quantity(self) -> Option<Quantity<T, U>> {
+        self.reduce_with(|a, b| {
+            if b.val < a.val || incomparable(&a.val) {
+                b
+            } else {
+                a
+            }
+        })
+    }
+
+    /// The largest quantity, ignoring any that can't be compared, such as
+    /// NaNs.
+    fn max_quantity(self) -> Option<Quantity<T, U>> {
+        self.reduce_with(|a, b| {
+            if b.val > a.val || incomparable(&a.val) {
+                b
+            } else {
+                a
+            }
+        })
+    }
+}
+
+impl<I, T, U: Unit> ParallelQuantityIterator<T, U> for I
+where
+    I: ParallelIterator<Item = Quantity<T, U>>,
+    T: PartialOrd + Send,
+{
+}
+
+/// Whether a value can't be compared with anything, not even itself.
+fn incomparable<T: PartialOrd>(val: &T) -> bool {
+    val.partial_cmp(val).is_none()
+}
+
+/// Convert every quantity in a slice to a different unit, in parallel.
+pub fn convert<T, U, Y>(slice: &[Quantity<T, U>]) -> Vec<Quantity<T, Y>>
+where
+    T: Clone + Send + Sync,
+    U: Unit,
+    Y: UnitConvertExact<T, U>,
+    Quantity<T, U>: Sync,
+    Quantity<T, Y>: Send,
+{
+    slice
+        .par_iter()
+        .map(|q| Quantity::new(Y::unit_convert(q.val.clone())))
+        .collect()
+}
@@ -0,0 +1,435 @@
+//! Looking up units by their symbols at runtime, such as to check the units
+//! in a configuration file before using them.
+//!
+//! A [`Registry`] maps symbols to units of one unit system, and parses unit
+//! expressions built from them, with prefixes, products, quotients, and
+//! powers, into a [`RuntimeUnit`]. [`Registry::si`] knows the SI units and
+//! the common units outside the SI that the [`FromStr`] impl of
+//! [`Quantity`] accepts:
+//!
+//! ```rust
+//! use uy::registry::{Registry, RuntimeUnit};
+//! use uy::{si, units, Ratio};
+//!
+//! let si = Registry::si();
+//! let kpa = si.parse("kPa").unwrap();
+//! assert!(kpa.same_dimension_as::<si::Pa>());
+//! assert_eq!(kpa.exp, 3);
+//!
+//! assert_eq!(si.parse("mg").unwrap(), RuntimeUnit::of::<si::micro<si::kg>>());
+//! let kmh = si.parse("km/h").unwrap();
+//! assert_eq!(kmh.factor, Ratio::new(1, 3600));
+//! assert!((kmh.factor_to::<uy::Div<si::m, si::s>>() - 1.0 / 3.6).abs() < 1e-12);
+//!
+//! assert!(!si.parse("kg/m").unwrap().same_dimension_as::<si::Pa>());
+//! assert_eq!(si.parse("mSv/h").unwrap().kind, Some("Sv"));
+//! assert_ne!(si.parse("Sv").unwrap(), si.parse("Gy").unwrap());
+//! assert!(si.parse("furlong").is_err());
+//!
+//! // Prefixes only apply to units that take them.
+//! assert_eq!(si.parse("g").unwrap(), RuntimeUnit::of::<si::milli<si::kg>>());
+//! assert!(si.parse("mkg").is_err());
+//! assert!(si.parse("k%").is_err());
+//! assert!(si.parse("Mmin").is_err());
+//! ```
+//!
+//! A [`DynQuantity`] is a value in a unit parsed at runtime, which can be
+//! checked against the unit a program expects before it's used.
+//!
+//! Registries for other unit systems start from the base units of the system
+//! with `Dimension::registry()`, and any registry can learn more units:
+//!
+//! ```rust
+//! use uy::registry::RuntimeUnit;
+//! use uy::{cgs, units};
+//!
+//! let mut registry = cgs::Dimension::registry();
+//! registry.insert::<cgs::dyne>("dyn");
+//! assert_eq!(registry.parse("kdyn").unwrap(), RuntimeUnit::of::<uy::si::kilo<cgs::dyne>>());
+//! assert!(registry.parse("N").is_err());
+//!
+//! let mut si = uy::registry::Registry::si().clone();
+//! si.insert::<units::kn>("knot");
+//! assert_eq!(si.parse("knot").unwrap(), si.parse("kn").unwrap());
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::parse::{convert_parsed, split_quantity, Parser};
+use crate::{chem, contrib, si, units, Error, Quantity, Ratio, Scalar, UnitInfo};
+
+/// A unit known at runtime: a power of ten, a factor for units such as
+/// `lbf`, the exponent of each base unit of a unit system, and the
+/// [kind](UnitInfo::KIND) of units such as `Sv`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeUnit {
+    pub exp: i32,
+    pub factor: Ratio,
+    pub dims: Vec<i32>,
+    pub kind: Option<&'static str>,
+}
+
+impl RuntimeUnit {
+    /// The unit `U`.
+    pub fn of<U: UnitInfo>() -> Self {
+        Self {
+            exp: U::PREFIX.into(),
+            factor: U::FACTOR,
+            dims: U::DIMENSION.iter().map(|&d| d.into()).collect(),
+            kind: U::KIND,
+        }
+    }
+
+    pub(crate) fn unitless(len: usize) -> Self {
+        Self {
+            exp: 0,
+            factor: Ratio::ONE,
+            dims: vec![0; len],
+            kind: None,
+        }
+    }
+
+    /// Whether the unit has the same base unit exponents as `U`,
+    /// regardless of scale.
+    pub fn same_dimension_as<U: UnitInfo>(&self) -> bool {
+        self.dims
+            .iter()
+            .copied()
+            .eq(U::DIMENSION.iter().map(|&d| i32::from(d)))
+    }
+
+    /// The factor a value in this unit is multiplied by to convert it to
+    /// `U`, as the nearest `f64`. This doesn't check the dimensions match.
+    pub fn factor_to<U: UnitInfo>(&self) -> f64 {
+        10f64.powi(self.exp - i32::from(U::PREFIX)) * self.factor.to_f64() / U::FACTOR.to_f64()
+    }
+
+    pub(crate) fn mul(mut self, rhs: &Self, sign: i32) -> Result<Self, Error> {
+        self.exp = self
+            .exp
+            .checked_add(sign * rhs.exp)
+            .ok_or(Error::Overflow)?;
+        let factor = if sign < 0 {
+            rhs.factor.recip()
+        } else {
+            rhs.factor
+        };
+        self.factor = self.factor.checked_mul(factor).ok_or(Error::Overflow)?;
+        for (d, r) in self.dims.iter_mut().zip(&rhs.dims) {
+            *d = d.checked_add(sign * r).ok_or(Error::Overflow)?;
+        }
+        // Like `Kinded`, a product or quotient keeps the kind of its units.
+        self.kind = self.kind.or(rhs.kind);
+        Ok(self)
+    }
+
+    pub(crate) fn pow(mut self, n: i32) -> Result<Self, Error> {
+        self.exp = self.exp.checked_mul(n).ok_or(Error::Overflow)?;
+        let base = if n < 0 {
+            self.factor.recip()
+        } else {
+            self.factor
+        };
+        // The factor is in lowest terms, so its powers are too.
+        self.factor = match (
+            base.numer.checked_pow(n.unsigned_abs()),
+            base.denom.checked_pow(n.unsigned_abs()),
+        ) {
+            (Some(numer), Some(denom)) => Ratio { numer, denom },
+            _ => return Err(Error::Overflow),
+        };
+        for d in &mut self.dims {
+            *d = d.checked_mul(n).ok_or(Error::Overflow)?;
+        }
+        Ok(self)
+    }
+}
+
+const PREFIXES: &[(&str, i32)] = &[
+    ("da", 1),
+    ("q", -30),
+    ("r", -27),
+    ("y", -24),
+    ("z", -21),
+    ("a", -18),
+    ("f", -15),
+    ("p", -12),
+    ("n", -9),
+    ("µ", -6),
+    ("μ", -6),
+    ("u", -6),
+    ("m", -3),
+    ("c", -2),
+    ("d", -1),
+    ("h", 2),
+    ("k", 3),
+    ("M", 6),
+    ("G", 9),
+    ("T", 12),
+    ("P", 15),
+    ("E", 18),
+    ("Z", 21),
+    ("Y", 24),
+    ("R", 27),
+    ("Q", 30),
+];
+
+/// Units of one unit system by their symbols.
+#[derive(Debug, Clone)]
+pub struct Registry {
+    base: &'static [&'static str],
+    units: HashMap<&'static str, Entry>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    unit: RuntimeUnit,
+    prefixable: bool,
+}
+
+impl Registry {
+    /// A registry of just the base units of a unit system, given their
+    /// symbols. Prefer the `Dimension::registry()` of the unit system.
+    pub fn with_base_units(base: &'static [&'static str]) -> Self {
+        let mut units = HashMap::new();
+        for (i, &symbol) in base.iter().enumerate() {
+            let mut unit = RuntimeUnit::unitless(base.len());
+            unit.dims[i] = 1;
+            units.insert(
+                symbol,
+                Entry {
+                    unit,
+                    prefixable: true,
+                },
+            );
+        }
+        // The kilogram is a base unit, so its prefixes apply to the gram.
+        if let Some(kg) = units.get_mut("kg") {
+            kg.prefixable = false;
+            let mut gram = kg.clone();
+            gram.unit.exp = -3;
+            gram.prefixable = true;
+            units.insert("g", gram);
+        }
+        Self { base, units }
+    }
+
+    /// The SI units, and the common units outside the SI.
+    pub fn si() -> &'static Registry {
+        static SI: OnceLock<Registry> = OnceLock::new();
+        SI.get_or_init(|| {
+            let mut r = si::Dimension::registry();
+            r.insert::<si::Hz>("Hz")
+                .insert::<si::N>("N")
+                .insert::<si::Pa>("Pa")
+                .insert::<si::J>("J")
+                .insert::<si::W>("W")
+                .insert::<si::C>("C")
+                .insert::<si::V>("V")
+                .insert::<si::F>("F")
+                .insert::<si::Ohm>("Ω")
+                .insert::<si::Ohm>("Ohm")
+                .insert::<si::Ohm>("ohm")
+                .insert::<si::S>("S")
+                .insert::<si::Wb>("Wb")
+                .insert::<si::T>("T")
+                .insert::<si::H>("H")
+                .insert::<si::Gy>("Gy")
+                .insert::<si::sr>("sr")
+                .insert::<si::lm>("lm")
+                .insert::<si::lx>("lx")
+                .insert::<si::Sv>("Sv")
+                .insert::<si::Bq>("Bq")
+                .insert_unprefixed::<si::counts>("counts")
+                .insert_unprefixed::<units::kgf>("kgf")
+                .insert_unprefixed::<units::lbf>("lbf")
+                .insert::<units::bar>("bar")
+                .insert_unprefixed::<units::atm>("atm")
+                .insert_unprefixed::<units::psi>("psi")
+                .insert_unprefixed::<units::mmHg>("mmHg")
+                .insert::<units::Torr>("Torr")
+                .insert::<units::Wh>("Wh")
+                .insert::<units::cal>("cal")
+                .insert_unprefixed::<units::BTU>("BTU")
+                .insert::<units::eV>("eV")
+                .insert_unprefixed::<units::mph>("mph")
+                .insert_unprefixed::<units::kn>("kn")
+                .insert_unprefixed::<units::min>("min")
+                .insert_unprefixed::<units::h>("h")
+                .insert::<units::L>("L")
+                .insert::<chem::M>("M")
+                .insert_unprefixed::<units::percent>("%")
+                .insert_unprefixed::<units::permille>("‰")
+                .insert_unprefixed::<units::permille>("permille")
+                .insert_unprefixed::<chem::ppm>("ppm")
+                .insert_unprefixed::<chem::ppb>("ppb")
+                .insert_unprefixed::<contrib::rpm>("rpm")
+                .insert_unprefixed::<contrib::gn>("gn")
+                .insert_unprefixed::<contrib::gn>("gₙ")
+                .insert_unprefixed::<contrib::mil>("mil")
+                .insert_unprefixed::<contrib::hp>("hp");
+            r
+        })
+    }
+
+    /// Add the unit `U` with the symbol `symbol`, which may be prefixed,
+    /// replacing any unit with that symbol.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `U` isn't in the unit system of the registry.
+    pub fn insert<U: UnitInfo>(&mut self, symbol: &'static str) -> &mut Self {
+        self.insert_entry::<U>(symbol, true)
+    }
+
+    /// Add the unit `U` with the symbol `symbol`, which can't be prefixed,
+    /// such as `min` or `%`, replacing any unit with that symbol.
+    ///
+    /// ```rust
+    /// use uy::registry::Registry;
+    /// use uy::units;
+    ///
+    /// let mut registry = uy::si::Dimension::registry();
+    /// registry.insert_unprefixed::<units::min>("min");
+    /// assert!(registry.parse("min").is_ok());
+    /// assert!(registry.parse("Mmin").is_err());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `U` isn't in the unit system of the registry.
+    pub fn insert_unprefixed<U: UnitInfo>(&mut self, symbol: &'static str) -> &mut Self {
+        self.insert_entry::<U>(symbol, false)
+    }
+
+    fn insert_entry<U: UnitInfo>(&mut self, symbol: &'static str, prefixable: bool) -> &mut Self {
+        assert!(
+            U::BASE_SYMBOLS == self.base,
+            "`{}` isn't in the unit system of the registry",
+            U::SYMBOL
+        );
+        let unit = RuntimeUnit::of::<U>();
+        self.units.insert(symbol, Entry { unit, prefixable });
+        self
+    }
+
+    /// The symbols of the base units of the unit system.
+    pub fn base_symbols(&self) -> &'static [&'static str] {
+        self.base
+    }
+
+    /// The unit with the symbol `symbol`, with a prefix if the unit takes
+    /// them.
+    pub fn get(&self, symbol: &str) -> Option<RuntimeUnit> {
+        if let Some(entry) = self.units.get(symbol) {
+            return Some(entry.unit.clone());
+        }
+        PREFIXES.iter().find_map(|&(prefix, exp)| {
+            let entry = self.units.get(symbol.strip_prefix(prefix)?)?;
+            if !entry.prefixable {
+                return None;
+            }
+            let mut unit = entry.unit.clone();
+            unit.exp += exp;
+            Some(unit)
+        })
+    }
+
+    /// Parse a unit such as `kg*m/s^2`, `m·s⁻¹`, `W/(m·K)`, or `10³·m`.
+    /// An empty string is unitless.
+    pub fn parse(&self, text: &str) -> Result<RuntimeUnit, Error> {
+        Parser::new(text, self).parse()
+    }
+}
+
+/// A quantity whose unit is only known at runtime, such as a setting read
+/// from a configuration file, until it's converted into a [`Quantity`] of
+/// the unit the program expects.
+///
+/// ```rust
+/// use uy::registry::{DynQuantity, Registry};
+/// use uy::{si, Error, Quantity};
+///
+/// let timeout: DynQuantity<u32> = DynQuantity::parse("1500 ms", Registry::si()).unwrap();
+/// assert_eq!(timeout.to_string(), "1500 ms");
+/// assert!(timeout.unit().same_dimension_as::<si::s>());
+///
+/// let ms: Quantity<u32, si::milli<si::s>> = timeout.clone().into_quantity().unwrap();
+/// assert_eq!(ms.into_value(), 1500);
+/// assert_eq!(timeout.clone().into_quantity::<si::s>().unwrap_err(), Error::Truncation);
+/// assert!(matches!(timeout.into_quantity::<si::m>(), Err(Error::DimensionMismatch { .. })));
+///
+/// let d = DynQuantity::from(Quantity::<f64, si::kilo<si::m>>::new(2.5));
+/// assert_eq!(d.into_quantity::<si::m>().unwrap().into_value(), 2500.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynQuantity<T> {
+    value: T,
+    unit: RuntimeUnit,
+    symbol: String,
+}
+
+impl<T> DynQuantity<T> {
+    /// Parse a value followed by a unit, separated by whitespace, with the
+    /// units of `registry`, like the [`FromStr`] impl of [`Quantity`].
+    pub fn parse(text: &str, registry: &Registry) -> Result<Self, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let (value, symbol) = split_quantity(text)?;
+        Ok(Self {
+            value,
+            unit: registry.parse(symbol)?,
+            symbol: symbol.to_string(),
+        })
+    }
+
+    /// The value, in [`unit`](Self::unit).
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The unit of the value.
+    pub fn unit(&self) -> &RuntimeUnit {
+        &self.unit
+    }
+
+    /// The unit as it was written.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Convert to a quantity in `U`, failing if the dimensions don't match,
+    /// the value overflows, or an integer would be truncated.
+    pub fn into_quantity<U: UnitInfo>(self) -> Result<Quantity<T, U>, Error>
+    where
+        T: Scalar,
+    {
+        convert_parsed::<T, U>(self.value, &self.unit, &self.symbol).map(Quantity::new)
+    }
+}
+
+impl<T, U: UnitInfo> From<Quantity<T, U>> for DynQuantity<T> {
+    fn from(q: Quantity<T, U>) -> Self {
+        Self {
+            value: q.into_value(),
+            unit: RuntimeUnit::of::<U>(),
+            symbol: U::SYMBOL.to_string(),
+        }
+    }
+}
+
+/// Formats the value followed by the unit as it was written.
+impl<T: fmt::Display> fmt::Display for DynQuantity<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.symbol.is_empty() {
+            write!(f, "{}", self.value)
+        } else {
+            write!(f, "{} {}", self.value, self.symbol)
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Integer quantities with an implicit prefix, such as lengths stored as a
+//! whole number of micrometers in an `i64`.
+//!
+//! Converting an integer quantity to a coarser unit truncates, so it's
+//! usually best to store values in the finest unit that's needed and only
+//! ever convert to finer units, widening the integer type as required.
+
+use crate::{Error, Quantity, Unit, UnitConvertExact};
+
+impl<T, U: Unit> Quantity<T, U> {
+    /// Create an integer quantity from a floating-point quantity, converting
+    /// its scale and rounding to the nearest integer.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let x = Quantity::<i64, si::micro<si::m>>::from_float(Quantity::<f64, si::m>::new(1.5));
+    /// assert_eq!(x.unwrap().into_value(), 1_500_000);
+    ///
+    /// let y = Quantity::<i8, si::milli<si::m>>::from_float(Quantity::<f64, si::m>::new(1.5));
+    /// assert_eq!(y.unwrap_err(), uy::Error::Overflow);
+    /// ```
+    pub fn from_float<F, Y>(q: Quantity<F, Y>) -> Result<Self, Error>
+    where
+        F: num_traits::Float,
+        Y: Unit,
+        U: UnitConvertExact<F, Y>,
+        T: num_traits::NumCast,
+    {
+        T::from(U::unit_convert(q.val).round())
+            .map(Quantity::new)
+            .ok_or(Error::Overflow)
+    }
+
+    /// Widen the integer type and convert to a finer unit at the same time,
+    /// without losing any information. This keeps a conversion to a finer
+    /// unit from overflowing the original type.
+    ///
+    /// Converting an integer to a coarser unit is a compile-time error, as
+    /// with [`convert`](Quantity::convert). The converted value must fit in
+    /// the wider type.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<i32, si::milli<si::m>> = Quantity::new(1500);
+    /// let b: Quantity<i64, si::nano<si::m>> = a.convert_widen();
+    /// assert_eq!(b.into_value(), 1_500_000_000);
+    ///
+    /// let t: Quantity<i32, si::s> = Quantity::new(2_000_000);
+    /// let t: Quantity<i64, si::nano<si::s>> = t.convert_widen();
+    /// assert_eq!(t.into_value(), 2_000_000_000_000_000);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use uy::{si, Quantity};
+    /// let a: Quantity<i32, si::milli<si::m>> = Quantity::new(1500);
+    /// let b: Quantity<i64, si::m> = a.convert_widen();
+    /// ```
+    #[inline]
+    pub fn convert_widen<T2, Y>(self) -> Quantity<T2, Y>
+    where
+        T2: From<T>,
+        Y: UnitConvertExact<T2, U>,
+    {
+        Quantity::new(Y::unit_convert(T2::from(self.val)))
+    }
+}
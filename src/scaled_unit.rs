@@ -0,0 +1,168 @@
+//! Units that are a multiple of another unit by a factor that isn't a power
+//! of ten, such as the kilogram-force or the pound-force.
+//!
+//! These can't be expressed with prefixes, so each is its own type, and
+//! converts to and from any unit of the same dimension through its
+//! [`Base`](ScaledUnit::Base) unit. Only floating-point values can be
+//! converted, since the factor is generally not an integer.
+
+use crate::{IsDimensionless, SameDimension, UnitConvertExact, UnitConvertLossy, UnitInfo};
+
+/// A unit equal to [`FACTOR`](UnitInfo::FACTOR) times its `Base` unit.
+///
+/// Scaled units don't take prefixes and can't be multiplied or divided by
+/// other units; convert them to their base unit first.
+///
+/// ```rust
+/// use uy::{si, units, Quantity};
+///
+/// let setpoint: Quantity<f64, units::kgf> = Quantity::new(2.0);
+/// let force: Quantity<f64, si::N> = setpoint.convert();
+/// assert!((force.into_value() - 19.6133).abs() < 1e-9);
+///
+/// let mn: Quantity<f64, si::milli<si::N>> = Quantity::new(19_613.3);
+/// let back: Quantity<f64, units::kgf> = mn.convert();
+/// assert!((back.into_value() - 2.0).abs() < 1e-9);
+/// assert_eq!(back.to_string(), "2 kgf");
+/// ```
+pub trait ScaledUnit: UnitInfo {
+    /// The unit this unit is a multiple of.
+    type Base: UnitInfo;
+}
+
+/// The factor of `X`, as a `T`.
+#[inline]
+pub fn factor<T: num_traits::Float, X: UnitInfo>() -> T {
+    T::from(X::FACTOR.to_f64()).unwrap()
+}
+
+impl<X: ScaledUnit, Y: ScaledUnit> SameDimension<X> for Y where Y::Base: SameDimension<X::Base> {}
+
+impl<X: ScaledUnit> IsDimensionless for X where X::Base: IsDimensionless {}
+
+impl<T: num_traits::Float, X: ScaledUnit, Y: ScaledUnit> UnitConvertLossy<T, X> for Y
+where
+    Y::Base: UnitConvertLossy<T, X::Base>,
+{
+    #[inline]
+    fn unit_convert_lossy(val: T) -> T {
+        Y::Base::unit_convert_lossy(val * factor::<T, X>()) / factor::<T, Y>()
+    }
+}
+
+impl<T: num_traits::Float, X: ScaledUnit, Y: ScaledUnit> UnitConvertExact<T, X> for Y
+where
+    Y::Base: UnitConvertLossy<T, X::Base>,
+{
+    #[inline]
+    fn unit_convert(val: T) -> T {
+        <Y as UnitConvertLossy<T, X>>::unit_convert_lossy(val)
+    }
+}
+
+/// Define a [`ScaledUnit`] named `$name` with the symbol `$symbol`, equal to
+/// `$numer / $denom` of `$base`.
+macro_rules! scaled_unit {
+    ($(#[$attr:meta])* $name:ident = $numer:literal / $denom:literal $base:ty, $symbol:literal $(, $unit_str:literal)?) => {
+        $(#[$attr])*
+        pub struct $name;
+
+        impl crate::Unit for $name {
+            const DEBUG_INFO: Option<(&'static str, i8, &'static [i8])> = Some((
+                <Self as crate::UnitInfo>::SYMBOL,
+                <Self as crate::UnitInfo>::PREFIX,
+                <Self as crate::UnitInfo>::DIMENSION,
+            ));
+        }
+
+        impl<T: PartialEq> crate::ScaleEq<T, Self> for $name {
+            #[inline]
+            fn scale_eq(lhs: &T, rhs: &T) -> bool {
+                lhs == rhs
+            }
+        }
+
+        impl<T: PartialOrd> crate::ScaleOrd<T, Self> for $name {
+            #[inline]
+            fn scale_partial_cmp(lhs: &T, rhs: &T) -> Option<std::cmp::Ordering> {
+                lhs.partial_cmp(rhs)
+            }
+        }
+
+        impl crate::UnitNormalize for $name {
+            type Output = Self;
+        }
+
+        impl crate::UnitScale for $name {
+            type Prefix = crate::TenTo<0>;
+            type Base = Self;
+        }
+
+        impl crate::UnitInfo for $name {
+            const BASE_SYMBOLS: &'static [&'static str] = <$base as crate::UnitInfo>::BASE_SYMBOLS;
+            const DIMENSION: &'static [i8] = <$base as crate::UnitInfo>::DIMENSION;
+            const PREFIX: i8 = <$base as crate::UnitInfo>::PREFIX;
+            const SYMBOL: &'static str = $symbol;
+            $(const UNIT_STR: &'static str = $unit_str;)?
+            const FACTOR: crate::Ratio = crate::Ratio::new($numer, $denom);
+            const KIND: Option<&'static str> = <$base as crate::UnitInfo>::KIND;
+        }
+
+        impl crate::ScaledUnit for $name {
+            type Base = $base;
+        }
+    };
+}
+pub(crate) use scaled_unit;
+
+/// Implement conversions from every [`ScaledUnit`] to the units of a unit
+/// system made with [`power_of_ten_unit_system!`](crate::power_of_ten_unit_system).
+///
+/// These are blanket impls over scaled units, which the orphan rule only
+/// allows in this crate, so they're separate from the public macro.
+macro_rules! scaled_unit_conversions {
+    ($system:ident { $($unit:ident),* }) => {
+        ::paste::paste! {
+            impl<
+                T: num_traits::Float,
+                X: crate::ScaledUnit,
+                const EXP: i8,
+                $(const [<$unit:upper>]: i8),*
+            > crate::UnitConvertLossy<T, $system<EXP, $([<$unit:upper>]),*>> for X
+            where
+                X::Base: crate::UnitConvertLossy<T, $system<EXP, $([<$unit:upper>]),*>>,
+            {
+                #[inline]
+                fn unit_convert_lossy(val: T) -> T {
+                    <X::Base as crate::UnitConvertLossy<T, $system<EXP, $([<$unit:upper>]),*>>>::unit_convert_lossy(val) / crate::scaled_unit::factor::<T, X>()
+                }
+            }
+
+            impl<
+                T: num_traits::Float,
+                X: crate::ScaledUnit,
+                const EXP: i8,
+                $(const [<$unit:upper>]: i8),*
+            > crate::UnitConvertExact<T, $system<EXP, $([<$unit:upper>]),*>> for X
+            where
+                X::Base: crate::UnitConvertLossy<T, $system<EXP, $([<$unit:upper>]),*>>,
+            {
+                #[inline]
+                fn unit_convert(val: T) -> T {
+                    <X as crate::UnitConvertLossy<T, $system<EXP, $([<$unit:upper>]),*>>>::unit_convert_lossy(val)
+                }
+            }
+
+            impl<
+                X: crate::ScaledUnit,
+                const EXP: i8,
+                $(const [<$unit:upper>]: i8),*
+            > crate::SameDimension<$system<EXP, $([<$unit:upper>]),*>> for X
+            where
+                X::Base: crate::SameDimension<$system<EXP, $([<$unit:upper>]),*>>,
+            {
+            }
+        }
+    };
+}
+pub(crate) use scaled_unit_conversions;
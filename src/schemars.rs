@@ -0,0 +1,47 @@
+//! JSON Schemas for quantities, through [`schemars`](https://docs.rs/schemars).
+//!
+//! A quantity has the schema of its value, annotated with the symbol of its
+//! unit under the `x-unit` keyword and in its description.
+//!
+//! ```rust
+//! use uy::{si, Quantity};
+//!
+//! let schema = schemars::schema_for!(Quantity<f64, uy::Div<si::m, si::s>>);
+//! assert_eq!(schema.get("type").unwrap(), "number");
+//! assert_eq!(schema.get("x-unit").unwrap(), "m·s⁻¹");
+//! assert_eq!(schema.get("description").unwrap(), "A quantity in m·s⁻¹.");
+//! ```
+
+use std::borrow::Cow;
+
+use ::schemars::{JsonSchema, Schema, SchemaGenerator};
+
+use crate::{Quantity, UnitInfo};
+
+impl<T, U> JsonSchema for Quantity<T, U>
+where
+    T: JsonSchema,
+    U: UnitInfo,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Quantity_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("uy::Quantity<{}, {}>", T::schema_id(), U::SYMBOL).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = generator.subschema_for::<T>();
+        let object = schema.ensure_object();
+        object
+            .entry("description")
+            .or_insert_with(|| format!("A quantity in {}.", U::SYMBOL).into());
+        object.insert("x-unit".into(), U::SYMBOL.into());
+        schema
+    }
+}
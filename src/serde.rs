@@ -0,0 +1,223 @@
+//! Serialization with [`serde`](https://docs.rs/serde).
+//!
+//! A quantity serializes as its raw value, with the unit implied by the
+//! type. Use [`string`] to include the unit in the serialized form instead,
+//! or [`tagged`] to check the unit with a compact binary tag.
+//!
+//! The raw value is the most compact form, so with a binary format like
+//! [`postcard`](https://docs.rs/postcard) a quantity takes no more space
+//! than its value:
+//!
+//! ```rust
+//! use uy::{si, Quantity};
+//!
+//! let altitude: Quantity<u16, si::m> = Quantity::new(1200);
+//! let bytes = postcard::to_allocvec(&altitude).unwrap();
+//! assert_eq!(bytes, postcard::to_allocvec(&1200u16).unwrap());
+//! ```
+//!
+//! This also holds for map keys, so maps keyed by integer quantities work
+//! with formats such as JSON that only allow string keys:
+//!
+//! ```rust
+//! use std::collections::BTreeMap;
+//! use uy::{si, Quantity};
+//!
+//! let mut setpoints: BTreeMap<Quantity<i64, si::milli<si::s>>, f64> = BTreeMap::new();
+//! setpoints.insert(Quantity::new(0), 20.0);
+//! setpoints.insert(Quantity::new(1500), 22.5);
+//!
+//! let json = serde_json::to_string(&setpoints).unwrap();
+//! assert_eq!(json, r#"{"0":20.0,"1500":22.5}"#);
+//! let back: BTreeMap<Quantity<i64, si::milli<si::s>>, f64> = serde_json::from_str(&json).unwrap();
+//! assert_eq!(back, setpoints);
+//! ```
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Quantity, Unit};
+
+impl<T: Serialize, U: Unit> Serialize for Quantity<T, U> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.val.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, U: Unit> Deserialize<'de> for Quantity<T, U> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Quantity::new)
+    }
+}
+
+pub mod string {
+    //! Serialize a quantity as a string with its unit, such as `"3.5 m/s"`,
+    //! for use with `#[serde(with = "uy::serde::string")]`. The string is the
+    //! [canonical text](crate::Quantity::to_canonical_string) of the
+    //! quantity.
+    //!
+    //! When deserializing, the unit may be any unit of the same dimension,
+    //! and the value is converted to the unit of the field. See the
+    //! [`FromStr`](std::str::FromStr) impl of [`Quantity`] for the accepted
+    //! syntax.
+    //!
+    //! ```rust
+    //! use serde::{Deserialize, Serialize};
+    //! use uy::{si, Quantity};
+    //!
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Config {
+    //!     #[serde(with = "uy::serde::string")]
+    //!     max_speed: Quantity<f64, uy::Div<si::m, si::s>>,
+    //!     #[serde(with = "uy::serde::string")]
+    //!     timeout: Quantity<u32, si::milli<si::s>>,
+    //! }
+    //!
+    //! let config: Config =
+    //!     serde_json::from_str(r#"{ "max_speed": "3500 mm/s", "timeout": "2 s" }"#).unwrap();
+    //! assert_eq!(config.max_speed.into_value(), 3.5);
+    //! assert_eq!(config.timeout.into_value(), 2000);
+    //! assert_eq!(
+    //!     serde_json::to_string(&config).unwrap(),
+    //!     r#"{"max_speed":"3.5 m/s","timeout":"2000 ms"}"#,
+    //! );
+    //!
+    //! let wrong = serde_json::from_str::<Config>(r#"{ "max_speed": "3.5 m", "timeout": "2 s" }"#);
+    //! assert!(wrong.is_err());
+    //! ```
+
+    use std::fmt;
+    use std::str::FromStr;
+
+    use ::serde::de::Error as _;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::{Error, Quantity, UnitInfo};
+
+    pub fn serialize<T, U, S>(q: &Quantity<T, U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        U: UnitInfo,
+        S: Serializer,
+    {
+        let unit = U::UNIT_STR;
+        if unit.is_empty() {
+            serializer.collect_str(&q.val)
+        } else {
+            serializer.collect_str(&format_args!("{} {}", q.val, unit))
+        }
+    }
+
+    pub fn deserialize<'de, T, U, D>(deserializer: D) -> Result<Quantity<T, U>, D::Error>
+    where
+        Quantity<T, U>: FromStr<Err = Error>,
+        U: UnitInfo,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+pub mod tagged {
+    //! Serialize a quantity as a [`unit_id`] followed by its value, for use
+    //! with `#[serde(with = "uy::serde::tagged")]`.
+    //!
+    //! The id is two bytes, so it's cheap enough for telemetry links, and
+    //! deserializing fails if it doesn't match the unit of the field. This
+    //! catches a sender and receiver that were built with different units
+    //! for the same field.
+    //!
+    //! ```rust
+    //! use serde::{Deserialize, Serialize};
+    //! use uy::{si, Quantity};
+    //!
+    //! #[derive(Serialize)]
+    //! struct Sent {
+    //!     #[serde(with = "uy::serde::tagged")]
+    //!     altitude: Quantity<u16, si::m>,
+    //! }
+    //!
+    //! #[derive(Deserialize)]
+    //! struct Received<U: uy::UnitInfo> {
+    //!     #[serde(with = "uy::serde::tagged")]
+    //!     altitude: Quantity<u16, U>,
+    //! }
+    //!
+    //! let bytes = postcard::to_allocvec(&Sent { altitude: Quantity::new(1200) }).unwrap();
+    //! assert_eq!(bytes.len(), 5);
+    //!
+    //! let ok: Received<si::m> = postcard::from_bytes(&bytes).unwrap();
+    //! assert_eq!(ok.altitude.into_value(), 1200);
+    //! assert!(postcard::from_bytes::<Received<si::deci<si::m>>>(&bytes).is_err());
+    //! ```
+
+    use ::serde::de::Error as _;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Quantity, UnitInfo};
+
+    /// A 16-bit hash of the unit system, dimension, and scale of `U`.
+    ///
+    /// Units with the same id are almost certainly the same unit, but units
+    /// of different [kinds](crate::Kind) with the same base unit share an id.
+    ///
+    /// ```rust
+    /// use uy::serde::tagged::unit_id;
+    /// use uy::si;
+    ///
+    /// assert_eq!(unit_id::<si::N>(), unit_id::<uy::Mul<si::kg, uy::Div<si::m, uy::Mul<si::s, si::s>>>>());
+    /// assert_ne!(unit_id::<si::N>(), unit_id::<si::kilo<si::N>>());
+    /// assert_ne!(unit_id::<si::m>(), unit_id::<uy::cgs::cm>());
+    /// ```
+    pub const fn unit_id<U: UnitInfo>() -> u16 {
+        // 32-bit FNV-1a, folded to 16 bits.
+        const fn fnv(mut hash: u32, bytes: &[u8]) -> u32 {
+            let mut i = 0;
+            while i < bytes.len() {
+                hash ^= bytes[i] as u32;
+                hash = hash.wrapping_mul(0x0100_0193);
+                i += 1;
+            }
+            hash
+        }
+
+        let mut hash = 0x811c_9dc5;
+        let mut i = 0;
+        while i < U::BASE_SYMBOLS.len() {
+            hash = fnv(hash, U::BASE_SYMBOLS[i].as_bytes());
+            hash = fnv(hash, &[0, U::DIMENSION[i] as u8]);
+            i += 1;
+        }
+        hash = fnv(hash, &[U::PREFIX as u8]);
+        hash = fnv(hash, &U::FACTOR.numer.to_le_bytes());
+        hash = fnv(hash, &U::FACTOR.denom.to_le_bytes());
+        (hash >> 16) as u16 ^ hash as u16
+    }
+
+    pub fn serialize<T, U, S>(q: &Quantity<T, U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        U: UnitInfo,
+        S: Serializer,
+    {
+        (unit_id::<U>(), &q.val).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, U, D>(deserializer: D) -> Result<Quantity<T, U>, D::Error>
+    where
+        T: Deserialize<'de>,
+        U: UnitInfo,
+        D: Deserializer<'de>,
+    {
+        let (id, val) = <(u16, T)>::deserialize(deserializer)?;
+        if id != unit_id::<U>() {
+            return Err(D::Error::custom(format_args!(
+                "expected a value in {} with unit id {:#06x}, found unit id {:#06x}",
+                U::SYMBOL,
+                unit_id::<U>(),
+                id,
+            )));
+        }
+        Ok(Quantity::new(val))
+    }
+}
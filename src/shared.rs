@@ -0,0 +1,191 @@
+//! Quantities whose values are shared with [`Arc`] or borrowed with [`Cow`],
+//! for values such as sample buffers and matrices that are too large to
+//! copy for every operation.
+//!
+//! Adding, subtracting, multiplying, or dividing by a reference to another
+//! shared quantity updates the left operand in place when nothing else
+//! holds its value, and only clones it when something does:
+//!
+//! ```rust
+//! use std::ops::AddAssign;
+//! use std::sync::Arc;
+//! use uy::{si, Quantity};
+//!
+//! #[derive(Clone, Debug, PartialEq)]
+//! struct Samples(Vec<f32>);
+//!
+//! impl AddAssign<&Samples> for Samples {
+//!     fn add_assign(&mut self, rhs: &Samples) {
+//!         self.0.iter_mut().zip(&rhs.0).for_each(|(a, b)| *a += b);
+//!     }
+//! }
+//!
+//! let trace: Quantity<Arc<Samples>, si::V> = Quantity::new(Samples(vec![1.0, 2.0])).into_shared();
+//! let offset: Quantity<Arc<Samples>, si::V> = Quantity::new(Samples(vec![0.5, 0.5])).into_shared();
+//!
+//! // `trace` is still held here, so its samples are cloned for the sum.
+//! let kept = trace.clone();
+//! let shifted = trace + &offset;
+//! assert_eq!(shifted.value().0, [1.5, 2.5]);
+//! assert_eq!(kept.value().0, [1.0, 2.0]);
+//!
+//! // Nothing else holds `shifted`, so it's updated in place.
+//! let before = Arc::as_ptr(shifted.value());
+//! let shifted = shifted + &offset;
+//! assert_eq!(Arc::as_ptr(shifted.value()), before);
+//! ```
+//!
+//! The units of the operands are checked as for any other quantity:
+//!
+//! ```rust,compile_fail
+//! use std::sync::Arc;
+//! use uy::{si, Quantity};
+//!
+//! let a: Quantity<Arc<f64>, si::V> = Quantity::new(1.0).into_shared();
+//! let b: Quantity<Arc<f64>, si::A> = Quantity::new(1.0).into_shared();
+//! let _ = a + &b;
+//! ```
+
+use std::borrow::Cow;
+use std::ops;
+use std::sync::Arc;
+
+use crate::{Quantity, Unit};
+
+impl<T, U: Unit> Quantity<T, U> {
+    /// Move the value into an [`Arc`], so it can be shared without copying
+    /// it.
+    #[inline]
+    pub fn into_shared(self) -> Quantity<Arc<T>, U> {
+        Quantity::new(Arc::new(self.val))
+    }
+
+    /// Borrow the value as a [`Cow`], which is only cloned if it's
+    /// modified.
+    ///
+    /// ```rust
+    /// use uy::{si, Quantity};
+    ///
+    /// let gain: Quantity<Vec<f64>, si::unitless> = Quantity::new(vec![1.0, 2.0]);
+    /// let mut view = gain.as_cow();
+    /// view.to_mut()[0] = 3.0;
+    /// assert_eq!(view.into_owned().into_value(), [3.0, 2.0]);
+    /// assert_eq!(gain.into_value(), [1.0, 2.0]);
+    /// ```
+    #[inline]
+    pub fn as_cow(&self) -> Quantity<Cow<'_, T>, U>
+    where
+        T: Clone,
+    {
+        Quantity::new(Cow::Borrowed(&self.val))
+    }
+}
+
+impl<T: Clone, U: Unit> Quantity<Arc<T>, U> {
+    /// A mutable reference to the value, which is cloned first if anything
+    /// else holds it. See [`Arc::make_mut`].
+    #[inline]
+    pub fn make_mut(&mut self) -> &mut T {
+        Arc::make_mut(&mut self.val)
+    }
+
+    /// Take the value out of the [`Arc`], cloning it if anything else holds
+    /// it.
+    #[inline]
+    pub fn unwrap_or_clone(self) -> Quantity<T, U> {
+        Quantity::new(Arc::unwrap_or_clone(self.val))
+    }
+}
+
+impl<T: Clone, U: Unit> Quantity<Cow<'_, T>, U> {
+    /// A mutable reference to the value, which is cloned first if it's
+    /// borrowed. See [`Cow::to_mut`].
+    #[inline]
+    pub fn to_mut(&mut self) -> &mut T {
+        self.val.to_mut()
+    }
+
+    /// Take the value out of the [`Cow`], cloning it if it's borrowed.
+    #[inline]
+    pub fn into_owned(self) -> Quantity<T, U> {
+        Quantity::new(self.val.into_owned())
+    }
+}
+
+macro_rules! impl_shared_ops {
+    ($($op:ident::$method:ident, $assign:ident::$assign_method:ident;)*) => {
+        $(
+            impl<'b, T, U: Unit> ops::$op<&'b Quantity<Arc<T>, U>> for Quantity<Arc<T>, U>
+            where
+                T: Clone + ops::$assign<&'b T>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn $method(mut self, rhs: &'b Quantity<Arc<T>, U>) -> Self {
+                    Arc::make_mut(&mut self.val).$assign_method(&*rhs.val);
+                    self
+                }
+            }
+
+            impl<'a, 'b, T, U: Unit> ops::$op<&'b Quantity<Cow<'b, T>, U>> for Quantity<Cow<'a, T>, U>
+            where
+                T: Clone + ops::$assign<&'b T>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn $method(mut self, rhs: &'b Quantity<Cow<'b, T>, U>) -> Self {
+                    self.val.to_mut().$assign_method(&*rhs.val);
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_shared_ops! {
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+}
+
+macro_rules! impl_shared_unit_ops {
+    ($($op:ident::$method:ident, $assign:ident::$assign_method:ident;)*) => {
+        $(
+            impl<'b, T, U1: Unit, U2: Unit> ops::$op<&'b Quantity<Arc<T>, U2>> for Quantity<Arc<T>, U1>
+            where
+                T: Clone + ops::$assign<&'b T>,
+                U1: ops::$op<U2>,
+                <U1 as ops::$op<U2>>::Output: Unit,
+            {
+                type Output = Quantity<Arc<T>, <U1 as ops::$op<U2>>::Output>;
+
+                #[inline]
+                fn $method(mut self, rhs: &'b Quantity<Arc<T>, U2>) -> Self::Output {
+                    Arc::make_mut(&mut self.val).$assign_method(&*rhs.val);
+                    Quantity::new(self.val)
+                }
+            }
+
+            impl<'a, 'b, T, U1: Unit, U2: Unit> ops::$op<&'b Quantity<Cow<'b, T>, U2>> for Quantity<Cow<'a, T>, U1>
+            where
+                T: Clone + ops::$assign<&'b T>,
+                U1: ops::$op<U2>,
+                <U1 as ops::$op<U2>>::Output: Unit,
+            {
+                type Output = Quantity<Cow<'a, T>, <U1 as ops::$op<U2>>::Output>;
+
+                #[inline]
+                fn $method(mut self, rhs: &'b Quantity<Cow<'b, T>, U2>) -> Self::Output {
+                    self.val.to_mut().$assign_method(&*rhs.val);
+                    Quantity::new(self.val)
+                }
+            }
+        )*
+    };
+}
+
+impl_shared_unit_ops! {
+    Mul::mul, MulAssign::mul_assign;
+    Div::div, DivAssign::div_assign;
+}
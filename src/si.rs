@@ -1,9 +1,33 @@
 //! The SI unit system, including base units, prefixes, and derived units.
+//!
+//! The [`Dimension`] of any unit is also available at runtime:
+//!
+//! ```rust
+//! use uy::si::{self, Dimension};
+//!
+//! let n = si::N::dimension();
+//! assert_eq!((n.s, n.m, n.kg), (-2, 1, 1));
+//! assert!(n.same_dimension(&si::kilo::<si::N>::dimension()));
+//! assert_eq!(n.mul(si::m::dimension()), si::J::dimension());
+//! assert!(si::unitless::dimension().is_dimensionless());
+//! assert_eq!(si::milli::<si::A>::dimension().to_string(), "mA");
+//! assert_eq!(Dimension::UNITLESS, si::unitless::dimension());
+//! ```
 
 #![allow(non_camel_case_types)]
 
 mod inner {
     crate::power_of_ten_unit_system!(Si {
+        s = "second",
+        m = "meter",
+        kg = "kilogram",
+        A = "ampere",
+        K = "kelvin",
+        mol = "mole",
+        cd = "candela",
+        rad = "radian"
+    });
+    crate::scaled_unit::scaled_unit_conversions!(Si {
         s,
         m,
         kg,
@@ -17,39 +41,100 @@ mod inner {
 
 pub use self::base::*;
 pub use self::derived::*;
-pub use self::inner::Si;
+pub use self::inner::{Dimension, Si};
 pub use self::prefixes::*;
 
+pub mod kinds {
+    //! Kinds of quantity that share a dimension with another, so they're
+    //! kept apart with [`Kinded`](crate::Kinded) units.
+
+    use crate::kind::kind;
+    use crate::si::*;
+
+    kind!(
+        /// Equivalent dose, the absorbed dose weighted by its biological
+        /// effect.
+        Sievert: Gy, "Sv"
+    );
+    kind!(
+        /// Radioactive decays per second.
+        Becquerel: Hz, "Bq"
+    );
+    kind!(
+        /// Events counted by a detector.
+        Counts: unitless, "counts"
+    );
+    kind!(
+        /// Torque, the moment of a force about an axis. It has the same
+        /// dimension as energy but is a different kind of quantity.
+        Torque: J, "N·m", "N*m"
+    );
+}
+
 pub mod prefixes {
     //! SI prefixes.
+    //!
+    //! Prefixes can be stacked, such as `milli<kilo<U>>`, as long as the
+    //! resulting power of ten stays within ±30. Stacking past that is a
+    //! compile-time error:
+    //!
+    //! ```rust,compile_fail
+    //! use uy::{si, Quantity};
+    //!
+    //! // error: prefix exponent out of range (max ±30)
+    //! let q: Quantity<f64, si::quetta<si::kilo<si::m>>> = Quantity::new(1.0);
+    //! ```
+    //!
+    //! Each prefix can be found in the documentation by the names of the SI
+    //! units it applies to, such as "kilonewton" for [`kilo`].
 
     use crate::{Mul, TenTo};
 
-    pub type quecto<U> = Mul<U, TenTo<-30>>;
-    pub type ronto<U> = Mul<U, TenTo<-27>>;
-    pub type yocto<U> = Mul<U, TenTo<-24>>;
-    pub type zepto<U> = Mul<U, TenTo<-21>>;
-    pub type atto<U> = Mul<U, TenTo<-18>>;
-    pub type femto<U> = Mul<U, TenTo<-15>>;
-    pub type pico<U> = Mul<U, TenTo<-12>>;
-    pub type nano<U> = Mul<U, TenTo<-9>>;
-    pub type micro<U> = Mul<U, TenTo<-6>>;
-    pub type milli<U> = Mul<U, TenTo<-3>>;
-    pub type centi<U> = Mul<U, TenTo<-2>>;
-    pub type deci<U> = Mul<U, TenTo<-1>>;
-
-    pub type deka<U> = Mul<U, TenTo<1>>;
-    pub type hecto<U> = Mul<U, TenTo<2>>;
-    pub type kilo<U> = Mul<U, TenTo<3>>;
-    pub type mega<U> = Mul<U, TenTo<6>>;
-    pub type giga<U> = Mul<U, TenTo<9>>;
-    pub type tera<U> = Mul<U, TenTo<12>>;
-    pub type peta<U> = Mul<U, TenTo<15>>;
-    pub type exa<U> = Mul<U, TenTo<18>>;
-    pub type zetta<U> = Mul<U, TenTo<21>>;
-    pub type yotta<U> = Mul<U, TenTo<24>>;
-    pub type ronna<U> = Mul<U, TenTo<27>>;
-    pub type quetta<U> = Mul<U, TenTo<30>>;
+    macro_rules! prefixes {
+        ($($prefix:ident = $exp:literal),* $(,)?) => {
+            $(prefixes!(@prefix $prefix = $exp);)*
+        };
+        (@prefix $prefix:ident = $exp:literal) => {
+            prefixes!(@aliases $prefix = $exp; "second", "meter", "ampere", "kelvin",
+                "mole", "candela", "radian", "hertz", "newton", "pascal",
+                "joule", "watt", "coulomb", "volt", "farad", "ohm", "siemens",
+                "weber", "tesla", "henry", "gray", "steradian", "lumen", "lux",
+                "sievert", "becquerel");
+        };
+        (@aliases $prefix:ident = $exp:literal; $($name:literal),*) => {
+            $crate::__private::paste::paste! {
+                $(#[doc(alias = "" $prefix $name)])*
+                pub type $prefix<U> = Mul<U, TenTo<$exp>>;
+            }
+        };
+    }
+
+    prefixes! {
+        quecto = -30,
+        ronto = -27,
+        yocto = -24,
+        zepto = -21,
+        atto = -18,
+        femto = -15,
+        pico = -12,
+        nano = -9,
+        micro = -6,
+        milli = -3,
+        centi = -2,
+        deci = -1,
+        deka = 1,
+        hecto = 2,
+        kilo = 3,
+        mega = 6,
+        giga = 9,
+        tera = 12,
+        peta = 15,
+        exa = 18,
+        zetta = 21,
+        yotta = 24,
+        ronna = 27,
+        quetta = 30,
+    }
 }
 
 pub mod base {
@@ -57,14 +142,23 @@ pub mod base {
 
     use super::Si;
 
+    #[doc(alias = "dimensionless")]
     pub type unitless = Si<0, 0, 0, 0, 0, 0, 0, 0, 0>;
+    #[doc(alias = "second")]
     pub type s = Si<0, 1, 0, 0, 0, 0, 0, 0, 0>;
+    #[doc(alias = "meter")]
     pub type m = Si<0, 0, 1, 0, 0, 0, 0, 0, 0>;
+    #[doc(alias = "kilogram")]
     pub type kg = Si<0, 0, 0, 1, 0, 0, 0, 0, 0>;
+    #[doc(alias = "ampere")]
     pub type A = Si<0, 0, 0, 0, 1, 0, 0, 0, 0>;
+    #[doc(alias = "kelvin")]
     pub type K = Si<0, 0, 0, 0, 0, 1, 0, 0, 0>;
+    #[doc(alias = "mole")]
     pub type mol = Si<0, 0, 0, 0, 0, 0, 1, 0, 0>;
+    #[doc(alias = "candela")]
     pub type cd = Si<0, 0, 0, 0, 0, 0, 0, 1, 0>;
+    #[doc(alias = "radian")]
     pub type rad = Si<0, 0, 0, 0, 0, 0, 0, 0, 1>;
 }
 
@@ -72,20 +166,394 @@ pub mod derived {
     //! SI derived units.
 
     use crate::si::base::*;
-    use crate::{Div, Mul};
+    use crate::{Div, Kinded, Mul};
 
+    #[doc(alias = "hertz")]
     pub type Hz = Div<unitless, s>;
+    #[doc(alias = "newton")]
     pub type N = Mul<kg, Div<m, Mul<s, s>>>;
+    #[doc(alias = "pascal")]
     pub type Pa = Div<N, Mul<m, m>>;
+    #[doc(alias = "joule")]
     pub type J = Mul<m, N>;
+    #[doc(alias = "watt")]
     pub type W = Div<J, s>;
+    #[doc(alias = "coulomb")]
     pub type C = Mul<s, A>;
+    #[doc(alias = "volt")]
     pub type V = Div<W, A>;
+    #[doc(alias = "farad")]
     pub type F = Div<C, V>;
+    #[doc(alias = "Ω")]
     pub type Ohm = Div<V, A>;
+    #[doc(alias = "siemens")]
     pub type S = Div<A, V>;
+    #[doc(alias = "weber")]
     pub type Wb = Div<J, A>;
+    #[doc(alias = "tesla")]
     pub type T = Div<Mul<V, s>, Mul<m, m>>;
+    #[doc(alias = "henry")]
     pub type H = Div<Mul<V, s>, A>;
+    #[doc(alias = "gray")]
     pub type Gy = Div<J, kg>;
+    /// The steradian. Solid angles are squared plane angles, which keeps
+    /// them from being confused with plain ratios.
+    #[doc(alias = "steradian")]
+    pub type sr = Mul<rad, rad>;
+    /// The lumen. Since the candela is a base unit, luminous flux can't be
+    /// confused with a radiant intensity times a solid angle, in `W·sr`.
+    #[doc(alias = "lumen")]
+    pub type lm = Mul<cd, sr>;
+    #[doc(alias = "lux")]
+    pub type lx = Div<lm, Mul<m, m>>;
+    /// The sievert, which can't be converted to or from the gray.
+    #[doc(alias = "sievert")]
+    pub type Sv = Kinded<super::kinds::Sievert, Gy>;
+    /// The becquerel, which can't be converted to or from the hertz.
+    #[doc(alias = "becquerel")]
+    pub type Bq = Kinded<super::kinds::Becquerel, Hz>;
+    /// A number of detector counts.
+    pub type counts = Kinded<super::kinds::Counts, unitless>;
+    /// The newton-meter of torque, which can't be converted to or from the
+    /// joule.
+    ///
+    /// ```rust
+    /// use uy::si::quantities::{Energy, Torque};
+    /// use uy::{si, Quantity, UnitInfo};
+    ///
+    /// let t: Quantity<f64, si::kilo<si::Nm>> = Quantity::new(1.2);
+    /// assert_eq!(t.to_string(), "1.2 kN·m");
+    /// assert_eq!(si::Nm::UNIT_STR, "N*m");
+    /// let t: Torque<f64> = t.convert();
+    /// assert_eq!(t.into_value(), 1200.0);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use uy::si::quantities::{Energy, Torque};
+    /// use uy::Quantity;
+    ///
+    /// let t: Torque<f64> = Quantity::new(12.0);
+    /// let e: Energy<f64> = t.convert();
+    /// ```
+    #[doc(alias = "newton-meter")]
+    #[doc(alias = "N·m")]
+    pub type Nm = Kinded<super::kinds::Torque, J>;
+}
+
+pub mod canonical {
+    //! The derived units written out as exponents of the base units, in the
+    //! order `Si<EXP, s, m, kg, A, K, mol, cd, rad>`. Each is checked at
+    //! compile time to be the same type as its alias in [`derived`](super::derived).
+
+    use crate::si::Si;
+
+    pub type Hz = Si<0, -1, 0, 0, 0, 0, 0, 0, 0>;
+    pub type N = Si<0, -2, 1, 1, 0, 0, 0, 0, 0>;
+    pub type Pa = Si<0, -2, -1, 1, 0, 0, 0, 0, 0>;
+    pub type J = Si<0, -2, 2, 1, 0, 0, 0, 0, 0>;
+    pub type W = Si<0, -3, 2, 1, 0, 0, 0, 0, 0>;
+    pub type C = Si<0, 1, 0, 0, 1, 0, 0, 0, 0>;
+    pub type V = Si<0, -3, 2, 1, -1, 0, 0, 0, 0>;
+    pub type F = Si<0, 4, -2, -1, 2, 0, 0, 0, 0>;
+    pub type Ohm = Si<0, -3, 2, 1, -2, 0, 0, 0, 0>;
+    pub type S = Si<0, 3, -2, -1, 2, 0, 0, 0, 0>;
+    pub type Wb = Si<0, -2, 2, 1, -1, 0, 0, 0, 0>;
+    pub type T = Si<0, -2, 0, 1, -1, 0, 0, 0, 0>;
+    pub type H = Si<0, -2, 2, 1, -2, 0, 0, 0, 0>;
+    pub type Gy = Si<0, -2, 2, 0, 0, 0, 0, 0, 0>;
+    pub type sr = Si<0, 0, 0, 0, 0, 0, 0, 0, 2>;
+    pub type lm = Si<0, 0, 0, 0, 0, 0, 0, 1, 2>;
+    pub type lx = Si<0, 0, -2, 0, 0, 0, 0, 1, 2>;
+
+    macro_rules! assert_canonical {
+        ($($unit:ident),*) => {
+            const _: () = {
+                trait SameAs<T> {}
+                impl<T> SameAs<T> for T {}
+                fn same<A: SameAs<B>, B>() {}
+                $(let _ = same::<super::derived::$unit, $unit>;)*
+            };
+        };
+    }
+
+    assert_canonical!(Hz, N, Pa, J, W, C, V, F, Ohm, S, Wb, T, H, Gy, sr, lm, lx);
+}
+
+pub mod quantities {
+    //! Named quantity types for the SI units.
+    //!
+    //! ```rust
+    //! use uy::si::quantities::{Length, Time, Velocity};
+    //!
+    //! fn speed(d: Length<f32>, t: Time<f32>) -> Velocity<f32> {
+    //!     d / t
+    //! }
+    //! ```
+
+    use crate::si::*;
+    use crate::{Div, Mul, Quantity};
+
+    pub type Dimensionless<T> = Quantity<T, unitless>;
+    pub type Time<T> = Quantity<T, s>;
+    /// The time taken by one cycle of a periodic signal.
+    pub type Period<T> = Quantity<T, s>;
+    pub type Length<T> = Quantity<T, m>;
+    pub type Mass<T> = Quantity<T, kg>;
+    pub type Current<T> = Quantity<T, A>;
+    pub type Temperature<T> = Quantity<T, K>;
+    pub type AmountOfSubstance<T> = Quantity<T, mol>;
+    pub type LuminousIntensity<T> = Quantity<T, cd>;
+    pub type Angle<T> = Quantity<T, rad>;
+
+    pub type Area<T> = Quantity<T, Mul<m, m>>;
+    pub type Volume<T> = Quantity<T, Mul<m, Mul<m, m>>>;
+    pub type Velocity<T> = Quantity<T, Div<m, s>>;
+    pub type Acceleration<T> = Quantity<T, Div<m, Mul<s, s>>>;
+    pub type AngularVelocity<T> = Quantity<T, Div<rad, s>>;
+    pub type Frequency<T> = Quantity<T, Hz>;
+    pub type Force<T> = Quantity<T, N>;
+    pub type Pressure<T> = Quantity<T, Pa>;
+    pub type Energy<T> = Quantity<T, J>;
+    pub type Power<T> = Quantity<T, W>;
+    pub type Charge<T> = Quantity<T, C>;
+    pub type ElectricPotential<T> = Quantity<T, V>;
+    pub type Capacitance<T> = Quantity<T, F>;
+    pub type Resistance<T> = Quantity<T, Ohm>;
+    pub type Conductance<T> = Quantity<T, S>;
+    pub type MagneticFlux<T> = Quantity<T, Wb>;
+    pub type MagneticFluxDensity<T> = Quantity<T, super::derived::T>;
+    pub type Inductance<T> = Quantity<T, H>;
+    pub type AbsorbedDose<T> = Quantity<T, Gy>;
+
+    pub type SolidAngle<T> = Quantity<T, sr>;
+    pub type LuminousFlux<T> = Quantity<T, lm>;
+    pub type Illuminance<T> = Quantity<T, lx>;
+    pub type Luminance<T> = Quantity<T, Div<cd, Mul<m, m>>>;
+    pub type RadiantIntensity<T> = Quantity<T, Div<W, sr>>;
+    pub type Irradiance<T> = Quantity<T, Div<W, Mul<m, m>>>;
+    pub type Radiance<T> = Quantity<T, Div<W, Mul<sr, Mul<m, m>>>>;
+
+    pub type EquivalentDose<T> = Quantity<T, Sv>;
+    pub type Activity<T> = Quantity<T, Bq>;
+    pub type Counts<T> = Quantity<T, counts>;
+    pub type CountRate<T> = Quantity<T, Div<counts, s>>;
+    pub type Torque<T> = Quantity<T, Nm>;
+}
+
+pub mod photometry {
+    //! Relationships between light sources and the light falling on surfaces.
+    //!
+    //! ```rust
+    //! use uy::si::photometry;
+    //! use uy::si::quantities::{Area, LuminousIntensity, SolidAngle};
+    //!
+    //! // A 100 cd lamp lighting a 2 m² desk that subtends 0.5 sr.
+    //! let flux = photometry::flux(LuminousIntensity::new(100.0), SolidAngle::new(0.5));
+    //! assert_eq!(flux.into_value(), 50.0);
+    //! assert_eq!(photometry::illuminance(flux, Area::new(2.0)).into_value(), 25.0);
+    //! ```
+    //!
+    //! Luminous and radiant quantities don't mix:
+    //!
+    //! ```rust,compile_fail
+    //! use uy::si::photometry;
+    //! use uy::si::quantities::{Area, RadiantIntensity, SolidAngle};
+    //!
+    //! let radiant: uy::Quantity<f64, uy::Mul<uy::Div<uy::si::W, uy::si::sr>, uy::si::sr>> =
+    //!     RadiantIntensity::new(1.0) * SolidAngle::new(0.5);
+    //! let _ = photometry::illuminance(radiant, Area::new(2.0));
+    //! ```
+
+    use std::ops;
+
+    use super::quantities::*;
+
+    /// The luminous flux emitted by a source of intensity `i` into a solid
+    /// angle `omega`.
+    pub fn flux<T: ops::Mul<Output = T>>(
+        i: LuminousIntensity<T>,
+        omega: SolidAngle<T>,
+    ) -> LuminousFlux<T> {
+        i * omega
+    }
+
+    /// The illuminance of a luminous flux `phi` spread evenly over an area.
+    pub fn illuminance<T: ops::Div<Output = T>>(
+        phi: LuminousFlux<T>,
+        area: Area<T>,
+    ) -> Illuminance<T> {
+        phi / area
+    }
+
+    /// The irradiance of a power `p` spread evenly over an area.
+    pub fn irradiance<T: ops::Div<Output = T>>(p: Power<T>, area: Area<T>) -> Irradiance<T> {
+        p / area
+    }
+}
+
+pub mod ee {
+    //! Electrical relationships between the SI quantities, such as Ohm's law.
+    //!
+    //! ```rust
+    //! use uy::si::ee;
+    //! use uy::si::quantities::{Current, ElectricPotential, Time};
+    //!
+    //! let v: ElectricPotential<f64> = ElectricPotential::new(12.0);
+    //! let i: Current<f64> = Current::new(0.5);
+    //! assert_eq!(ee::resistance(v, i).into_value(), 24.0);
+    //! assert_eq!(ee::energy(ee::power(v, i), Time::new(60.0)).into_value(), 360.0);
+    //! ```
+
+    use std::ops;
+
+    use super::quantities::*;
+
+    /// The power delivered by a current `i` flowing through a voltage `v`.
+    pub fn power<T: ops::Mul<Output = T>>(v: ElectricPotential<T>, i: Current<T>) -> Power<T> {
+        v * i
+    }
+
+    /// The voltage across a resistance `r` carrying a current `i`.
+    pub fn voltage<T: ops::Mul<Output = T>>(
+        i: Current<T>,
+        r: Resistance<T>,
+    ) -> ElectricPotential<T> {
+        i * r
+    }
+
+    /// The current through a resistance `r` with a voltage `v` across it.
+    pub fn current<T: ops::Div<Output = T>>(
+        v: ElectricPotential<T>,
+        r: Resistance<T>,
+    ) -> Current<T> {
+        v / r
+    }
+
+    /// The resistance that carries a current `i` with a voltage `v` across it.
+    pub fn resistance<T: ops::Div<Output = T>>(
+        v: ElectricPotential<T>,
+        i: Current<T>,
+    ) -> Resistance<T> {
+        v / i
+    }
+
+    /// The energy delivered by a power `p` over a time `t`.
+    pub fn energy<T: ops::Mul<Output = T>>(p: Power<T>, t: Time<T>) -> Energy<T> {
+        p * t
+    }
+
+    /// The charge moved by a current `i` over a time `t`.
+    pub fn charge<T: ops::Mul<Output = T>>(i: Current<T>, t: Time<T>) -> Charge<T> {
+        i * t
+    }
+}
+
+pub mod ext {
+    //! Constructing quantities from numbers by naming their unit.
+    //!
+    //! ```rust
+    //! use uy::si::ext::NumExt;
+    //! use uy::si::quantities::Velocity;
+    //! use uy::si;
+    //!
+    //! let v: Velocity<f64> = 3.0.meters() / 2.0.seconds();
+    //! assert_eq!(v.into_value(), 1.5);
+    //! assert_eq!(12.kilograms().to_string(), "12 kg");
+    //!
+    //! let d: uy::Quantity<i64, si::micro<si::m>> = 3.millimeters().convert();
+    //! assert_eq!(d.into_value(), 3000);
+    //! assert_eq!(250.microseconds().to_string(), "250 µs");
+    //! assert_eq!(5.grams().to_string(), "5 g");
+    //! ```
+
+    use crate::si::*;
+    use crate::Quantity;
+
+    macro_rules! num_ext {
+        ($($(#[$attr:meta])* $method:ident($($alias:literal),*) => $unit:ty),* $(,)?) => {
+            /// Methods on numbers that create a quantity with that value in
+            /// a given unit.
+            pub trait NumExt: Sized {
+                $(
+                    $(#[$attr])*
+                    $(#[doc(alias = $alias)])*
+                    fn $method(self) -> Quantity<Self, $unit> {
+                        Quantity::new(self)
+                    }
+                )*
+            }
+        };
+    }
+
+    num_ext! {
+        seconds("second", "s") => s,
+        meters("meter", "m") => m,
+        kilograms("kilogram", "kg") => kg,
+        amperes("ampere", "A") => A,
+        kelvins("kelvin", "K") => K,
+        moles("mole", "mol") => mol,
+        candelas("candela", "cd") => cd,
+        radians("radian", "rad") => rad,
+        hertz("Hz") => Hz,
+        newtons("newton", "N") => N,
+        pascals("pascal", "Pa") => Pa,
+        joules("joule", "J") => J,
+        watts("watt", "W") => W,
+        coulombs("coulomb", "C") => C,
+        volts("volt", "V") => V,
+        farads("farad", "F") => F,
+        ohms("ohm", "Ω") => Ohm,
+        siemens("S") => S,
+        webers("weber", "Wb") => Wb,
+        teslas("tesla", "T") => T,
+        henries("henry", "H") => H,
+
+        nanoseconds("nanosecond", "ns") => nano<s>,
+        microseconds("microsecond", "µs") => micro<s>,
+        milliseconds("millisecond", "ms") => milli<s>,
+        nanometers("nanometer", "nm") => nano<m>,
+        micrometers("micrometer", "µm") => micro<m>,
+        millimeters("millimeter", "mm") => milli<m>,
+        centimeters("centimeter", "cm") => centi<m>,
+        kilometers("kilometer", "km") => kilo<m>,
+        milligrams("milligram", "mg") => micro<kg>,
+        grams("gram", "g") => milli<kg>,
+        microamperes("microampere", "µA") => micro<A>,
+        milliamperes("milliampere", "mA") => milli<A>,
+        kilohertz("kHz") => kilo<Hz>,
+        megahertz("MHz") => mega<Hz>,
+        gigahertz("GHz") => giga<Hz>,
+        kilonewtons("kilonewton", "kN") => kilo<N>,
+        kilopascals("kilopascal", "kPa") => kilo<Pa>,
+        megapascals("megapascal", "MPa") => mega<Pa>,
+        kilojoules("kilojoule", "kJ") => kilo<J>,
+        megajoules("megajoule", "MJ") => mega<J>,
+        milliwatts("milliwatt", "mW") => milli<W>,
+        kilowatts("kilowatt", "kW") => kilo<W>,
+        megawatts("megawatt", "MW") => mega<W>,
+        millivolts("millivolt", "mV") => milli<V>,
+        kilovolts("kilovolt", "kV") => kilo<V>,
+        kiloohms("kiloohm", "kΩ") => kilo<Ohm>,
+        megaohms("megaohm", "MΩ") => mega<Ohm>,
+        picofarads("picofarad", "pF") => pico<F>,
+        nanofarads("nanofarad", "nF") => nano<F>,
+        microfarads("microfarad", "µF") => micro<F>,
+        microhenries("microhenry", "µH") => micro<H>,
+        millihenries("millihenry", "mH") => milli<H>,
+    }
+
+    impl NumExt for f32 {}
+    impl NumExt for f64 {}
+    impl NumExt for i8 {}
+    impl NumExt for i16 {}
+    impl NumExt for i32 {}
+    impl NumExt for i64 {}
+    impl NumExt for i128 {}
+    impl NumExt for isize {}
+    impl NumExt for u8 {}
+    impl NumExt for u16 {}
+    impl NumExt for u32 {}
+    impl NumExt for u64 {}
+    impl NumExt for u128 {}
+    impl NumExt for usize {}
 }
@@ -0,0 +1,115 @@
+//! Rounding and formatting quantities to a number of significant figures.
+
+use std::fmt;
+
+use num_traits::Float;
+
+use crate::{Quantity, UnitInfo};
+
+impl<T: Float, U: UnitInfo> Quantity<T, U> {
+    /// Round the value to `n` significant figures. Zero, infinities, and
+    /// NaN are returned unchanged.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let v: Quantity<f64, si::V> = Quantity::new(3.14159);
+    /// assert_eq!(v.round_to_sig_figs(3).into_value(), 3.14);
+    ///
+    /// let i: Quantity<f64, si::A> = Quantity::new(-0.0012345);
+    /// assert_eq!(i.round_to_sig_figs(2).into_value(), -0.0012);
+    ///
+    /// let tiny: Quantity<f64, si::m> = Quantity::new(1.2345e-300);
+    /// assert_eq!(tiny.round_to_sig_figs(17).into_value(), 1.2345e-300);
+    ///
+    /// let inf: Quantity<f64, si::m> = Quantity::new(f64::INFINITY);
+    /// assert_eq!(inf.round_to_sig_figs(2).into_value(), f64::INFINITY);
+    /// assert!(Quantity::<f64, si::m>::new(f64::NAN).round_to_sig_figs(2).into_value().is_nan());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn round_to_sig_figs(self, n: u32) -> Self {
+        assert!(n > 0, "a value must have at least one significant figure");
+        if self.val.is_zero() || !self.val.is_finite() {
+            return self;
+        }
+        // The power of ten that moves the last significant figure to the
+        // ones place.
+        let exp = n as i32 - 1 - magnitude(self.val);
+        let scaled = mul_power_of_ten(self.val, exp);
+        if !scaled.is_finite() {
+            return self;
+        }
+        Quantity::new(mul_power_of_ten(scaled.round(), -exp))
+    }
+
+    /// Format the quantity with `n` significant figures, followed by the
+    /// unit symbol, keeping any trailing zeros that are significant.
+    ///
+    /// Values with more than `n` digits before the decimal point are written
+    /// in exponent notation, so that no figures are implied by zeros. Zero,
+    /// infinities, and NaN are written as `Display` writes them.
+    ///
+    /// ```rust
+    /// # use uy::{si, Quantity};
+    /// let r: Quantity<f64, si::kilo<si::m>> = Quantity::new(4.7);
+    /// assert_eq!(r.to_string_sig_figs(3), "4.70 km");
+    ///
+    /// let d: Quantity<f32, si::m> = Quantity::new(12345.0);
+    /// assert_eq!(d.to_string_sig_figs(2), "1.2e4 m");
+    /// assert_eq!(d.to_string_sig_figs(5), "12345 m");
+    ///
+    /// let t: Quantity<f64, si::s> = Quantity::new(0.099951);
+    /// assert_eq!(t.to_string_sig_figs(3), "0.100 s");
+    ///
+    /// assert_eq!(Quantity::<f64, si::s>::new(0.0).to_string_sig_figs(3), "0 s");
+    /// assert_eq!(Quantity::<f64, si::s>::new(f64::NEG_INFINITY).to_string_sig_figs(3), "-inf s");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn to_string_sig_figs(&self, n: u32) -> String
+    where
+        T: fmt::Display + fmt::LowerExp,
+    {
+        let rounded = self.round_to_sig_figs(n).val;
+        let value = if rounded.is_zero() || !rounded.is_finite() {
+            rounded.to_string()
+        } else {
+            let magnitude = magnitude(rounded);
+            if magnitude >= n as i32 {
+                format!("{:.*e}", n as usize - 1, rounded)
+            } else {
+                format!("{:.*}", (n as i32 - 1 - magnitude).max(0) as usize, rounded)
+            }
+        };
+        if U::SYMBOL.is_empty() {
+            value
+        } else {
+            format!("{} {}", value, U::SYMBOL)
+        }
+    }
+}
+
+/// The power of ten of the most significant digit of a finite, nonzero
+/// value.
+fn magnitude<T: Float>(val: T) -> i32 {
+    val.abs().log10().floor().to_i32().unwrap()
+}
+
+/// `val` times 10^`exp`. Negative powers divide by the positive power, which
+/// is exact, and powers too large for `T` are applied in two steps.
+fn mul_power_of_ten<T: Float>(val: T, exp: i32) -> T {
+    let pow = T::from(10).unwrap().powi(exp.abs());
+    if !pow.is_finite() {
+        let half = exp / 2;
+        return mul_power_of_ten(mul_power_of_ten(val, half), exp - half);
+    }
+    if exp >= 0 {
+        val * pow
+    } else {
+        val / pow
+    }
+}
@@ -0,0 +1,81 @@
+//! Sorting and searching slices of floating-point quantities.
+//!
+//! Float quantities aren't `Ord`, so the sorting and searching methods of
+//! slices need a comparison closure for them. [`QuantitySliceExt`] orders
+//! them with [`Quantity::total_cmp`] instead, and looks up keys given
+//! in another unit of the same dimension.
+
+use num_traits::float::TotalOrder;
+
+use crate::{Quantity, Unit, UnitConvertLossy};
+
+/// Sorting and binary searches of a slice of quantities in `U` by their
+/// values, in the total order of [`Quantity::total_cmp`], where `-0.0` sorts
+/// before `0.0` and NaNs sort to the ends by sign.
+///
+/// ```rust
+/// use uy::slice::QuantitySliceExt;
+/// use uy::{si, Quantity};
+///
+/// let mut stamps: Vec<Quantity<f64, si::s>> =
+///     [2.5, 0.5, 1.0, 4.0].into_iter().map(Quantity::new).collect();
+/// stamps.sort_by_value();
+/// assert_eq!(stamps.iter().map(|q| q.into_value()).collect::<Vec<_>>(), [0.5, 1.0, 2.5, 4.0]);
+///
+/// assert_eq!(stamps.binary_search_quantity(Quantity::<f64, si::milli<si::s>>::new(2500.0)), Ok(2));
+/// assert_eq!(stamps.binary_search_quantity(Quantity::<f64, si::s>::new(3.0)), Err(3));
+/// assert_eq!(stamps.partition_point_quantity(Quantity::<f64, si::milli<si::s>>::new(800.0)), 1);
+/// ```
+///
+/// Keys must have the same dimension as the quantities in the slice:
+///
+/// ```rust,compile_fail
+/// use uy::slice::QuantitySliceExt;
+/// use uy::{si, Quantity};
+///
+/// let stamps = [Quantity::<f64, si::s>::new(1.0)];
+/// stamps.binary_search_quantity(Quantity::<f64, si::m>::new(1.0));
+/// ```
+pub trait QuantitySliceExt<T, U: Unit> {
+    /// Sort the quantities in ascending order of their values. The sort is
+    /// stable.
+    fn sort_by_value(&mut self);
+
+    /// Binary search the sorted quantities for `key`, converted to `U`, as
+    /// [`slice::binary_search`] does.
+    fn binary_search_quantity<Y>(&self, key: Quantity<T, Y>) -> Result<usize, usize>
+    where
+        Y: Unit,
+        U: UnitConvertLossy<T, Y>;
+
+    /// The index of the first of the sorted quantities that isn't less than
+    /// `key`, converted to `U`, as [`slice::partition_point`] finds.
+    fn partition_point_quantity<Y>(&self, key: Quantity<T, Y>) -> usize
+    where
+        Y: Unit,
+        U: UnitConvertLossy<T, Y>;
+}
+
+impl<T: TotalOrder, U: Unit> QuantitySliceExt<T, U> for [Quantity<T, U>] {
+    fn sort_by_value(&mut self) {
+        self.sort_by(|a, b| a.total_cmp(b));
+    }
+
+    fn binary_search_quantity<Y>(&self, key: Quantity<T, Y>) -> Result<usize, usize>
+    where
+        Y: Unit,
+        U: UnitConvertLossy<T, Y>,
+    {
+        let key = key.convert_lossy::<U>();
+        self.binary_search_by(|q| q.total_cmp(&key))
+    }
+
+    fn partition_point_quantity<Y>(&self, key: Quantity<T, Y>) -> usize
+    where
+        Y: Unit,
+        U: UnitConvertLossy<T, Y>,
+    {
+        let key = key.convert_lossy::<U>();
+        self.partition_point(|q| q.total_cmp(&key).is_lt())
+    }
+}
@@ -0,0 +1,176 @@
+//! Solving an equation between units for an unknown unit.
+//!
+//! An equation such as `F = m * a` fixes the unit of any one of its terms
+//! given the others. [`SolveFor`] rearranges an equation written with
+//! [`Equals`], [`Product`], [`Quotient`], and [`Unknown`] to find the unit of
+//! the unknown:
+//!
+//! ```rust
+//! use uy::solve::{Equals, Product, Quotient, SolveFor, Unknown};
+//! use uy::si;
+//!
+//! // F = m * a, so a = F / m.
+//! uy::assert_same_unit!(SolveFor<Equals<si::N, Product<si::kg, Unknown>>>, uy::Div<si::m, uy::Mul<si::s, si::s>>);
+//! // V = I * R, so R = V / I.
+//! uy::assert_same_unit!(SolveFor<Equals<si::V, Product<Unknown, si::A>>>, si::Ohm);
+//! // v = d / t, so t = d / v.
+//! uy::assert_same_unit!(SolveFor<Equals<uy::Div<si::m, si::s>, Quotient<si::m, Unknown>>>, si::s);
+//! // P = W / t, so W = P * t.
+//! uy::assert_same_unit!(SolveFor<Equals<si::W, Quotient<Unknown, si::s>>>, si::J);
+//! ```
+//!
+//! [`solve_for!`](crate::solve_for) turns an equation into a function that
+//! computes the unknown from the other terms.
+
+use std::marker::PhantomData;
+use std::ops;
+
+use crate::{Div, Mul, Unit};
+
+/// The unit of the [`Unknown`] in an equation. See the [module
+/// documentation](self).
+pub type SolveFor<Eq> = <Eq as Equation>::Unknown;
+
+/// An equation between units with one [`Unknown`] term, which can be solved
+/// for the unit of that term.
+pub trait Equation {
+    type Unknown: Unit;
+}
+
+/// The equation `L = R`.
+pub struct Equals<L, R>(PhantomData<(L, R)>);
+
+/// The product `A * B` in an equation.
+pub struct Product<A, B>(PhantomData<(A, B)>);
+
+/// The quotient `A / B` in an equation.
+pub struct Quotient<A, B>(PhantomData<(A, B)>);
+
+/// The term of an equation whose unit is being solved for.
+pub struct Unknown;
+
+impl<A: Unit + ops::Mul<B>, B: Unit> Equation for Equals<Unknown, Product<A, B>>
+where
+    Mul<A, B>: Unit,
+{
+    type Unknown = Mul<A, B>;
+}
+
+impl<A: Unit + ops::Div<B>, B: Unit> Equation for Equals<Unknown, Quotient<A, B>>
+where
+    Div<A, B>: Unit,
+{
+    type Unknown = Div<A, B>;
+}
+
+impl<L: Unit + ops::Div<K>, K: Unit> Equation for Equals<L, Product<K, Unknown>>
+where
+    Div<L, K>: Unit,
+{
+    type Unknown = Div<L, K>;
+}
+
+impl<L: Unit + ops::Div<K>, K: Unit> Equation for Equals<L, Product<Unknown, K>>
+where
+    Div<L, K>: Unit,
+{
+    type Unknown = Div<L, K>;
+}
+
+impl<L: Unit + ops::Mul<K>, K: Unit> Equation for Equals<L, Quotient<Unknown, K>>
+where
+    Mul<L, K>: Unit,
+{
+    type Unknown = Mul<L, K>;
+}
+
+impl<L: Unit, K: Unit + ops::Div<L>> Equation for Equals<L, Quotient<K, Unknown>>
+where
+    Div<K, L>: Unit,
+{
+    type Unknown = Div<K, L>;
+}
+
+/// Define a function that solves an equation for its unknown term, marked
+/// with `_`. Each known term is written as `(name: unit)` and becomes an
+/// argument of the function, in the order they appear, and the unit the
+/// function returns is found with [`SolveFor`](crate::solve::SolveFor).
+///
+/// ```rust
+/// use uy::{si, Quantity};
+///
+/// uy::solve_for! {
+///     /// The acceleration of a mass under a force.
+///     pub fn acceleration: (force: si::N) = (mass: si::kg) * _;
+/// }
+/// uy::solve_for! {
+///     fn resistance: (voltage: si::V) = _ * (current: si::A);
+/// }
+/// uy::solve_for! {
+///     fn travel_time: (speed: uy::Div<si::m, si::s>) = (distance: si::m) / _;
+/// }
+/// uy::solve_for! {
+///     fn energy: (power: si::W) = _ / (time: si::s);
+/// }
+///
+/// let a = acceleration(Quantity::<f64, si::N>::new(10.0), Quantity::new(4.0));
+/// assert_eq!(a.into_value(), 2.5);
+///
+/// let r: Quantity<f64, si::Ohm> = resistance(Quantity::new(5.0), Quantity::new(0.5));
+/// assert_eq!(r.into_value(), 10.0);
+///
+/// let t: Quantity<f64, si::s> = travel_time(Quantity::new(20.0), Quantity::new(100.0));
+/// assert_eq!(t.into_value(), 5.0);
+///
+/// let e: Quantity<u32, si::J> = energy(Quantity::new(60), Quantity::new(3600));
+/// assert_eq!(e.into_value(), 216_000);
+/// ```
+///
+/// The units of the terms are still checked where the function is called:
+///
+/// ```rust,compile_fail
+/// use uy::{si, Quantity};
+///
+/// uy::solve_for! {
+///     fn acceleration: (force: si::N) = (mass: si::kg) * _;
+/// }
+/// acceleration(Quantity::<f64, si::N>::new(10.0), Quantity::<f64, si::milli<si::kg>>::new(4.0));
+/// ```
+#[macro_export]
+macro_rules! solve_for {
+    ($(#[$attr:meta])* $vis:vis fn $name:ident: ($l:ident: $ul:ty) = ($k:ident: $uk:ty) * _ $(;)?) => {
+        $crate::solve_for!(@fn $(#[$attr])* $vis $name, ($l: $ul), ($k: $uk), $l / $k,
+            $crate::solve::Equals<$ul, $crate::solve::Product<$uk, $crate::solve::Unknown>>, Div);
+    };
+    ($(#[$attr:meta])* $vis:vis fn $name:ident: ($l:ident: $ul:ty) = _ * ($k:ident: $uk:ty) $(;)?) => {
+        $crate::solve_for!(@fn $(#[$attr])* $vis $name, ($l: $ul), ($k: $uk), $l / $k,
+            $crate::solve::Equals<$ul, $crate::solve::Product<$crate::solve::Unknown, $uk>>, Div);
+    };
+    ($(#[$attr:meta])* $vis:vis fn $name:ident: ($l:ident: $ul:ty) = _ / ($k:ident: $uk:ty) $(;)?) => {
+        $crate::solve_for!(@fn $(#[$attr])* $vis $name, ($l: $ul), ($k: $uk), $l * $k,
+            $crate::solve::Equals<$ul, $crate::solve::Quotient<$crate::solve::Unknown, $uk>>, Mul);
+    };
+    ($(#[$attr:meta])* $vis:vis fn $name:ident: ($l:ident: $ul:ty) = ($k:ident: $uk:ty) / _ $(;)?) => {
+        $crate::solve_for!(@fn $(#[$attr])* $vis $name, ($l: $ul), ($k: $uk), $k / $l,
+            $crate::solve::Equals<$ul, $crate::solve::Quotient<$uk, $crate::solve::Unknown>>, Div);
+    };
+    ($(#[$attr:meta])* $vis:vis fn $name:ident: _ = ($a:ident: $ua:ty) * ($b:ident: $ub:ty) $(;)?) => {
+        $crate::solve_for!(@fn $(#[$attr])* $vis $name, ($a: $ua), ($b: $ub), $a * $b,
+            $crate::solve::Equals<$crate::solve::Unknown, $crate::solve::Product<$ua, $ub>>, Mul);
+    };
+    ($(#[$attr:meta])* $vis:vis fn $name:ident: _ = ($a:ident: $ua:ty) / ($b:ident: $ub:ty) $(;)?) => {
+        $crate::solve_for!(@fn $(#[$attr])* $vis $name, ($a: $ua), ($b: $ub), $a / $b,
+            $crate::solve::Equals<$crate::solve::Unknown, $crate::solve::Quotient<$ua, $ub>>, Div);
+    };
+    (@fn $(#[$attr:meta])* $vis:vis $name:ident, ($a:ident: $ua:ty), ($b:ident: $ub:ty),
+        $x:ident $op:tt $y:ident, $eq:ty, $trait:ident) => {
+        $(#[$attr])*
+        $vis fn $name<T: ::core::ops::$trait<Output = T>>(
+            $a: $crate::Quantity<T, $ua>,
+            $b: $crate::Quantity<T, $ub>,
+        ) -> $crate::Quantity<T, $crate::solve::SolveFor<$eq>> {
+            let ($a, $b) = ($a.into_value(), $b.into_value());
+            $crate::Quantity::new($x $op $y)
+        }
+    };
+}
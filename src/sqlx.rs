@@ -0,0 +1,91 @@
+//! Storing quantities in database columns with [`sqlx`](https://docs.rs/sqlx).
+//!
+//! A quantity is stored as its raw value, so a `Quantity<f64, U>` column has
+//! the same SQL type as an `f64` column, and so on. The unit is not stored;
+//! use [`unit_check`] to record it in the schema alongside the column.
+//!
+//! ```rust
+//! use sqlx::{Database, Decode, Encode, Type};
+//! use uy::{si, Quantity};
+//!
+//! fn storable<'q, 'r, DB: Database, Q: Type<DB> + Encode<'q, DB> + Decode<'r, DB>>() {}
+//!
+//! fn columns<DB: Database>()
+//! where
+//!     f64: Type<DB> + for<'q> Encode<'q, DB> + for<'r> Decode<'r, DB>,
+//! {
+//!     storable::<DB, Quantity<f64, si::milli<si::A>>>();
+//! }
+//!
+//! assert_eq!(
+//!     uy::sqlx::unit_check::<si::milli<si::A>>("current_unit"),
+//!     "CHECK (\"current_unit\" = 'mA')",
+//! );
+//! ```
+
+use ::sqlx::database::Database;
+use ::sqlx::decode::Decode;
+use ::sqlx::encode::{Encode, IsNull};
+use ::sqlx::error::BoxDynError;
+use ::sqlx::types::Type;
+
+use crate::{Quantity, Unit, UnitInfo};
+
+impl<DB, T, U> Type<DB> for Quantity<T, U>
+where
+    DB: Database,
+    T: Type<DB>,
+    U: Unit,
+{
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+impl<'q, DB, T, U> Encode<'q, DB> for Quantity<T, U>
+where
+    DB: Database,
+    T: Encode<'q, DB>,
+    U: Unit,
+{
+    fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.val.encode(buf)
+    }
+
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.val.encode_by_ref(buf)
+    }
+
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        self.val.produces()
+    }
+
+    fn size_hint(&self) -> usize {
+        self.val.size_hint()
+    }
+}
+
+impl<'r, DB, T, U> Decode<'r, DB> for Quantity<T, U>
+where
+    DB: Database,
+    T: Decode<'r, DB>,
+    U: Unit,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        T::decode(value).map(Quantity::new)
+    }
+}
+
+/// A SQL `CHECK` constraint requiring a text column to hold the symbol of
+/// the unit `U`, for tables that store the unit next to each value.
+pub fn unit_check<U: UnitInfo>(column: &str) -> String {
+    format!(
+        "CHECK (\"{}\" = '{}')",
+        column.replace('"', "\"\""),
+        U::SYMBOL.replace('\'', "''")
+    )
+}
@@ -0,0 +1,317 @@
+//! Accumulating statistics over streams of quantities, such as telemetry.
+
+use std::{fmt, ops};
+
+use crate::{Quantity, Unit};
+
+/// Counts of quantities falling into buckets with fixed boundaries.
+///
+/// Each bucket counts the quantities less than or equal to its upper bound
+/// and greater than the bound of the bucket before it. Quantities above the
+/// last bound are counted separately.
+///
+/// ```rust
+/// use uy::stats::Histogram;
+/// use uy::{si, Quantity};
+///
+/// let bounds = [1, 5, 10, 50].map(Quantity::<u32, si::milli<si::s>>::new);
+/// let mut latency = Histogram::new(bounds);
+/// for ms in [2, 3, 4, 7, 12, 80] {
+///     latency.record(Quantity::new(ms));
+/// }
+///
+/// assert_eq!(latency.counts(), &[0, 3, 1, 1]);
+/// assert_eq!(latency.overflow(), 1);
+/// assert_eq!(latency.percentile(0.5).unwrap().into_value(), 5);
+/// assert!(latency.percentile(0.99).is_none());
+/// ```
+#[derive(Clone)]
+pub struct Histogram<T, U: Unit> {
+    bounds: Vec<Quantity<T, U>>,
+    counts: Vec<u64>,
+    overflow: u64,
+}
+
+impl<T: PartialOrd, U: Unit> Histogram<T, U> {
+    /// Create a histogram with the given upper bounds of its buckets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounds are not in strictly increasing order.
+    pub fn new(bounds: impl IntoIterator<Item = Quantity<T, U>>) -> Self {
+        let bounds: Vec<_> = bounds.into_iter().collect();
+        assert!(
+            bounds.windows(2).all(|w| w[0].val < w[1].val),
+            "histogram bounds must be strictly increasing"
+        );
+        let counts = vec![0; bounds.len()];
+        Self {
+            bounds,
+            counts,
+            overflow: 0,
+        }
+    }
+
+    /// Count a quantity in the bucket it falls into.
+    pub fn record(&mut self, q: Quantity<T, U>) {
+        let i = self.bounds.partition_point(|b| b.val < q.val);
+        match self.counts.get_mut(i) {
+            Some(count) => *count += 1,
+            None => self.overflow += 1,
+        }
+    }
+
+    /// The upper bounds of the buckets.
+    pub fn bounds(&self) -> &[Quantity<T, U>] {
+        &self.bounds
+    }
+
+    /// The number of quantities counted in each bucket.
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// The number of quantities above the last bound.
+    pub fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// The total number of quantities recorded.
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum::<u64>() + self.overflow
+    }
+
+    /// The upper bound of the bucket containing the quantity at fraction `p`
+    /// of the way through the recorded quantities, such as `0.99` for the
+    /// 99th percentile.
+    ///
+    /// Returns `None` if nothing has been recorded or the percentile is
+    /// above the last bound.
+    pub fn percentile(&self, p: f64) -> Option<Quantity<T, U>>
+    where
+        T: Clone,
+    {
+        let total = self.total();
+        if total == 0 {
+            return None;
+        }
+        let rank = ((p.clamp(0.0, 1.0) * total as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (bound, count) in self.bounds.iter().zip(&self.counts) {
+            seen += count;
+            if seen >= rank {
+                return Some(bound.clone());
+            }
+        }
+        None
+    }
+}
+
+impl<T: fmt::Debug, U: Unit> fmt::Debug for Histogram<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Histogram")
+            .field("bounds", &self.bounds)
+            .field("counts", &self.counts)
+            .field("overflow", &self.overflow)
+            .finish()
+    }
+}
+
+/// The minimum, maximum, and mean of a stream of quantities.
+///
+/// ```rust
+/// use uy::stats::MinMaxMean;
+/// use uy::{si, Quantity};
+///
+/// let mut supply: MinMaxMean<f32, si::V> = MinMaxMean::new();
+/// for v in [3.2, 3.3, 3.4] {
+///     supply.record(Quantity::new(v));
+/// }
+///
+/// assert_eq!(supply.min().unwrap().into_value(), 3.2);
+/// assert_eq!(supply.max().unwrap().into_value(), 3.4);
+/// assert!((supply.mean().unwrap().into_value() - 3.3).abs() < 1e-6);
+/// ```
+#[derive(Clone)]
+pub struct MinMaxMean<T, U: Unit> {
+    min: Option<Quantity<T, U>>,
+    max: Option<Quantity<T, U>>,
+    sum: Option<Quantity<T, U>>,
+    count: u64,
+}
+
+impl<T, U: Unit> MinMaxMean<T, U> {
+    /// Statistics with no quantities recorded yet.
+    pub fn new() -> Self {
+        Self {
+            min: None,
+            max: None,
+            sum: None,
+            count: 0,
+        }
+    }
+
+    /// Include a quantity in the statistics.
+    pub fn record(&mut self, q: Quantity<T, U>)
+    where
+        T: Copy + PartialOrd + ops::Add<Output = T>,
+    {
+        if self.min.is_none_or(|min| q.val < min.val) {
+            self.min = Some(q);
+        }
+        if self.max.is_none_or(|max| q.val > max.val) {
+            self.max = Some(q);
+        }
+        self.sum = Some(match self.sum {
+            Some(sum) => sum + q,
+            None => q,
+        });
+        self.count += 1;
+    }
+
+    /// The number of quantities recorded.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The smallest quantity recorded, if any.
+    pub fn min(&self) -> Option<Quantity<T, U>>
+    where
+        T: Copy,
+    {
+        self.min
+    }
+
+    /// The largest quantity recorded, if any.
+    pub fn max(&self) -> Option<Quantity<T, U>>
+    where
+        T: Copy,
+    {
+        self.max
+    }
+
+    /// The mean of the quantities recorded, if any. Integer means are
+    /// truncated towards zero.
+    ///
+    /// Once the count doesn't fit in `T`, such as after 256 samples of a
+    /// `u8`, the mean is computed in `f64` instead.
+    ///
+    /// ```rust
+    /// use uy::stats::MinMaxMean;
+    /// use uy::{si, Quantity};
+    ///
+    /// let mut level: MinMaxMean<u8, si::unitless> = MinMaxMean::new();
+    /// for _ in 0..300 {
+    ///     level.record(Quantity::new(0));
+    /// }
+    /// assert_eq!(level.mean().unwrap().into_value(), 0);
+    /// ```
+    pub fn mean(&self) -> Option<Quantity<T, U>>
+    where
+        T: Copy + ops::Div<Output = T> + num_traits::NumCast,
+    {
+        let sum = self.sum?;
+        let mean = match T::from(self.count) {
+            Some(count) => sum.val / count,
+            None => T::from(sum.val.to_f64()? / self.count as f64)?,
+        };
+        Some(Quantity::new(mean))
+    }
+}
+
+impl<T: fmt::Debug, U: Unit> fmt::Debug for MinMaxMean<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MinMaxMean")
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("sum", &self.sum)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<T, U: Unit> Default for MinMaxMean<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A running sum of floating-point quantities with compensated (Kahan)
+/// summation, so the rounding error doesn't grow with the number of
+/// quantities added.
+///
+/// ```rust
+/// use uy::stats::KahanSum;
+/// use uy::{si, Quantity};
+///
+/// let step: Quantity<f32, si::J> = Quantity::new(0.1);
+/// let mut energy = KahanSum::new();
+/// let mut naive = Quantity::new(0.0);
+/// for _ in 0..1_000_000 {
+///     energy.add(step);
+///     naive = naive + step;
+/// }
+///
+/// assert!((energy.total().into_value() - 100_000.0).abs() < 1e-2);
+/// assert!((naive.into_value() - 100_000.0).abs() > 100.0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct KahanSum<T, U: Unit> {
+    sum: Quantity<T, U>,
+    compensation: T,
+}
+
+impl<T: num_traits::Float, U: Unit> KahanSum<T, U> {
+    pub fn new() -> Self {
+        Self {
+            sum: Quantity::new(T::zero()),
+            compensation: T::zero(),
+        }
+    }
+
+    /// Add a quantity to the sum.
+    pub fn add(&mut self, q: Quantity<T, U>) {
+        // The compensation holds the low-order bits lost from the last
+        // addition, which are added back to the next quantity.
+        let q = q.val - self.compensation;
+        let sum = self.sum.val + q;
+        self.compensation = (sum - self.sum.val) - q;
+        self.sum = Quantity::new(sum);
+    }
+
+    /// The sum of the quantities added so far.
+    pub fn total(&self) -> Quantity<T, U> {
+        self.sum
+    }
+}
+
+impl<T: fmt::Debug, U: Unit> fmt::Debug for KahanSum<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KahanSum")
+            .field("sum", &self.sum)
+            .field("compensation", &self.compensation)
+            .finish()
+    }
+}
+
+impl<T: num_traits::Float, U: Unit> Default for KahanSum<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: num_traits::Float, U: Unit> Extend<Quantity<T, U>> for KahanSum<T, U> {
+    fn extend<I: IntoIterator<Item = Quantity<T, U>>>(&mut self, iter: I) {
+        for q in iter {
+            self.add(q);
+        }
+    }
+}
+
+impl<T: num_traits::Float, U: Unit> FromIterator<Quantity<T, U>> for KahanSum<T, U> {
+    fn from_iter<I: IntoIterator<Item = Quantity<T, U>>>(iter: I) -> Self {
+        let mut sum = Self::new();
+        sum.extend(iter);
+        sum
+    }
+}
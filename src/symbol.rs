@@ -0,0 +1,316 @@
+//! Construction of unit symbols, at compile time and in other styles at
+//! runtime.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{fmt_with_symbol, Quantity, Unit, UnitInfo};
+
+const CAPACITY: usize = 192;
+
+/// A fixed-capacity string buffer that can be built in a `const` context.
+pub struct SymbolBuf {
+    bytes: [u8; CAPACITY],
+    len: usize,
+}
+
+impl SymbolBuf {
+    const fn new() -> Self {
+        Self {
+            bytes: [0; CAPACITY],
+            len: 0,
+        }
+    }
+
+    pub const fn as_str(&self) -> &str {
+        let (bytes, _) = self.bytes.split_at(self.len);
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => panic!("unit symbol is not valid UTF-8"),
+        }
+    }
+
+    const fn push_str(mut self, s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(self.len < CAPACITY, "unit symbol is too long");
+            self.bytes[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    const fn push_superscript(mut self, n: i8) -> Self {
+        const DIGITS: [&str; 10] = ["⁰", "¹", "²", "³", "⁴", "⁵", "⁶", "⁷", "⁸", "⁹"];
+
+        if n < 0 {
+            self = self.push_str("⁻");
+        }
+        let n = n.unsigned_abs();
+        if n >= 100 {
+            self = self.push_str(DIGITS[(n / 100) as usize]);
+        }
+        if n >= 10 {
+            self = self.push_str(DIGITS[(n / 10 % 10) as usize]);
+        }
+        self.push_str(DIGITS[(n % 10) as usize])
+    }
+
+    const fn push_int(mut self, n: i8) -> Self {
+        const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+        if n < 0 {
+            self = self.push_str("-");
+        }
+        let n = n.unsigned_abs();
+        if n >= 100 {
+            self = self.push_str(DIGITS[(n / 100) as usize]);
+        }
+        if n >= 10 {
+            self = self.push_str(DIGITS[(n / 10 % 10) as usize]);
+        }
+        self.push_str(DIGITS[(n % 10) as usize])
+    }
+
+    /// Push the exponent of a base unit or power of ten in the given style.
+    const fn push_exponent(self, n: i8, style: Style) -> Self {
+        match style {
+            Style::Unicode => self.push_superscript(n),
+            Style::Ascii => self.push_str("^").push_int(n),
+            Style::Spaced => self.push_int(n),
+        }
+    }
+}
+
+/// How unit symbols are written, for [`Quantity::display_styled`].
+///
+/// [`Quantity::display_styled`]: crate::Quantity::display_styled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Superscript exponents and `·` between base units, such as `m·s⁻²`.
+    Unicode,
+    /// Plain ASCII, such as `m/s^2`.
+    Ascii,
+    /// Plain ASCII with spaces between base units and exponents written
+    /// after them, such as `m s-2`.
+    Spaced,
+}
+
+impl Style {
+    /// The symbol of `U` in this style. Units with a symbol of their own,
+    /// such as `lbf`, are written with the ASCII symbol in the spaced style.
+    ///
+    /// ```rust
+    /// use uy::{si, units, SymbolStyle};
+    ///
+    /// type Accel = uy::Div<si::m, uy::Mul<si::s, si::s>>;
+    /// assert_eq!(SymbolStyle::Unicode.symbol::<Accel>(), "m·s⁻²");
+    /// assert_eq!(SymbolStyle::Ascii.symbol::<Accel>(), "m/s^2");
+    /// assert_eq!(SymbolStyle::Spaced.symbol::<Accel>(), "m s-2");
+    /// assert_eq!(SymbolStyle::Spaced.symbol::<si::micro<si::m>>(), "um");
+    /// assert_eq!(SymbolStyle::Spaced.symbol::<units::lbf>(), "lbf");
+    /// ```
+    pub fn symbol<U: UnitInfo>(self) -> Cow<'static, str> {
+        match self {
+            Style::Unicode => Cow::Borrowed(U::SYMBOL),
+            Style::Ascii => Cow::Borrowed(U::UNIT_STR),
+            Style::Spaced => {
+                let derived = unit_symbol(U::BASE_SYMBOLS, U::DIMENSION, U::PREFIX, Style::Unicode);
+                if derived.as_str() != U::SYMBOL {
+                    return Cow::Borrowed(U::UNIT_STR);
+                }
+                let spaced = unit_symbol(U::BASE_SYMBOLS, U::DIMENSION, U::PREFIX, Style::Spaced);
+                Cow::Owned(spaced.as_str().to_owned())
+            }
+        }
+    }
+}
+
+/// The SI prefix symbol for a power of ten, if there is one.
+pub const fn prefix_symbol(exp: i8) -> Option<&'static str> {
+    Some(match exp {
+        -30 => "q",
+        -27 => "r",
+        -24 => "y",
+        -21 => "z",
+        -18 => "a",
+        -15 => "f",
+        -12 => "p",
+        -9 => "n",
+        -6 => "µ",
+        -3 => "m",
+        -2 => "c",
+        -1 => "d",
+        1 => "da",
+        2 => "h",
+        3 => "k",
+        6 => "M",
+        9 => "G",
+        12 => "T",
+        15 => "P",
+        18 => "E",
+        21 => "Z",
+        24 => "Y",
+        27 => "R",
+        30 => "Q",
+        _ => return None,
+    })
+}
+
+/// `a` and `b` joined by `separator`, or whichever isn't empty.
+pub(crate) const fn join_symbols(a: &str, separator: &str, b: &str) -> SymbolBuf {
+    let buf = SymbolBuf::new().push_str(a);
+    if a.is_empty() || b.is_empty() {
+        buf.push_str(b)
+    } else {
+        buf.push_str(separator).push_str(b)
+    }
+}
+
+pub const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Build the symbol of a unit scaled by `10^exp` with the given exponent of
+/// each base unit, such as `km`, `m·s⁻¹`, or `10⁻⁶·m²` in the Unicode style
+/// and `km`, `m/s`, or `10^-6*m^2` in the ASCII style, and `km`, `m s-1`,
+/// or `1e-6 m2` in the spaced style.
+///
+/// Base units with a positive exponent come first, each group in the order
+/// the unit system declares them.
+pub const fn unit_symbol(base: &[&str], dims: &[i8], exp: i8, style: Style) -> SymbolBuf {
+    let mut buf = SymbolBuf::new();
+    let ascii = matches!(style, Style::Ascii | Style::Spaced);
+    let divisors = matches!(style, Style::Ascii);
+
+    let mut nonzero = 0;
+    let mut single = 0;
+    let mut i = 0;
+    while i < dims.len() {
+        if dims[i] != 0 {
+            nonzero += 1;
+            single = i;
+        }
+        i += 1;
+    }
+
+    if nonzero == 1 && dims[single] == 1 {
+        // The kilogram is the only base unit with a prefix in its name, so
+        // its multiples are prefixes of the gram instead.
+        let (symbol, exp) = if str_eq(base[single], "kg") {
+            ("g", exp + 3)
+        } else {
+            (base[single], exp)
+        };
+        if exp == 0 {
+            return buf.push_str(symbol);
+        }
+        if let Some(prefix) = prefix_symbol(exp) {
+            let prefix = if ascii && exp == -6 { "u" } else { prefix };
+            return buf.push_str(prefix).push_str(symbol);
+        }
+    }
+
+    let separator = match style {
+        Style::Unicode => "·",
+        Style::Ascii => "*",
+        Style::Spaced => " ",
+    };
+    let mut first = true;
+    if exp != 0 {
+        buf = match style {
+            Style::Spaced => buf.push_str("1e").push_int(exp),
+            _ => buf.push_str("10").push_exponent(exp, style),
+        };
+        first = false;
+    }
+
+    let mut positive = true;
+    loop {
+        let mut i = 0;
+        while i < dims.len() {
+            if (positive && dims[i] > 0) || (!positive && dims[i] < 0) {
+                // In ASCII, base units with a negative exponent are written
+                // as divisors instead.
+                let n = if divisors { dims[i].abs() } else { dims[i] };
+                if divisors && !positive {
+                    if first {
+                        buf = buf.push_str("1");
+                    }
+                    buf = buf.push_str("/");
+                } else if !first {
+                    buf = buf.push_str(separator);
+                }
+                first = false;
+                buf = buf.push_str(base[i]);
+                if n != 1 {
+                    buf = buf.push_exponent(n, style);
+                }
+            }
+            i += 1;
+        }
+        if !positive {
+            break;
+        }
+        positive = false;
+    }
+
+    buf
+}
+
+/// A quantity displayed with its unit symbol in a given [`Style`]. Created
+/// by [`Quantity::display_styled`].
+///
+/// Like the `Display` impl of [`Quantity`], the sign and precision flags
+/// apply to the value, while width and alignment apply to the value and
+/// symbol together.
+pub struct DisplayStyled<'a, T, U: Unit> {
+    quantity: &'a Quantity<T, U>,
+    style: Style,
+}
+
+impl<T, U: Unit> Quantity<T, U> {
+    /// Display the quantity with its unit symbol in `style`, such as for log
+    /// aggregation systems that can't handle superscripts.
+    ///
+    /// ```rust
+    /// use uy::{si, Quantity, SymbolStyle};
+    ///
+    /// let g: Quantity<f64, uy::Div<si::m, uy::Mul<si::s, si::s>>> = Quantity::new(9.81);
+    /// assert_eq!(g.display_styled(SymbolStyle::Unicode).to_string(), "9.81 m·s⁻²");
+    /// assert_eq!(g.display_styled(SymbolStyle::Ascii).to_string(), "9.81 m/s^2");
+    /// assert_eq!(format!("{:.1}", g.display_styled(SymbolStyle::Spaced)), "9.8 m s-2");
+    /// ```
+    pub fn display_styled(&self, style: Style) -> DisplayStyled<'_, T, U> {
+        DisplayStyled {
+            quantity: self,
+            style,
+        }
+    }
+}
+
+impl<T: fmt::Display, U: UnitInfo> fmt::Display for DisplayStyled<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = &self.quantity.val;
+        let value = match (f.precision(), f.sign_plus()) {
+            (Some(p), true) => format!("{:+.*}", p, val),
+            (Some(p), false) => format!("{:.*}", p, val),
+            (None, true) => format!("{:+}", val),
+            (None, false) => format!("{}", val),
+        };
+        fmt_with_symbol(f, &value, &self.style.symbol::<U>())
+    }
+}
@@ -0,0 +1,194 @@
+//! Property testing of units with [`proptest`](https://docs.rs/proptest),
+//! for checking that custom units and unit systems follow the same laws as
+//! the built-in ones.
+//!
+//! Quantities implement [`Arbitrary`], and the `check_*` functions return a
+//! [`TestCaseError`] so they can be used with `?` inside `proptest!`:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest::test_runner::TestRunner;
+//! use uy::testing::{check_roundtrip, check_same_unit, quantity};
+//! use uy::{si, Quantity};
+//!
+//! check_same_unit::<uy::Div<uy::Mul<si::N, si::m>, si::m>, si::N>().unwrap();
+//! assert!(check_same_unit::<si::Sv, si::Gy>().is_err());
+//!
+//! let mut runner = TestRunner::default();
+//! runner
+//!     .run(&quantity::<si::m, _>(-1_000_000i32..1_000_000), |q| {
+//!         check_roundtrip::<_, _, si::milli<si::m>>(q)
+//!     })
+//!     .unwrap();
+//! runner
+//!     .run(&any::<Quantity<i16, si::m>>(), |q| {
+//!         check_roundtrip::<_, _, si::micro<si::m>>(q.convert_widen::<i32, si::m>())
+//!     })
+//!     .unwrap();
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use num_traits::Float;
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::strategy::{BoxedStrategy, Strategy};
+use proptest::test_runner::TestCaseError;
+
+use crate::__private::{same_dimension, same_unit};
+use crate::{CommonScale, Quantity, Scalar, UnitConvertExact, UnitConvertLossy, UnitInfo};
+
+impl<T: Arbitrary + 'static, U: UnitInfo + 'static> Arbitrary for Quantity<T, U> {
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: T::Parameters) -> BoxedStrategy<Self> {
+        proptest::arbitrary::any_with::<T>(args)
+            .prop_map(Quantity::new)
+            .boxed()
+    }
+}
+
+/// Quantities in `U` whose values are generated by `values`, such as a
+/// range.
+///
+/// ```rust
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+/// use uy::si;
+///
+/// let strategy = uy::testing::quantity::<si::V, _>(-5.0f64..5.0);
+/// let q = strategy.new_tree(&mut TestRunner::default()).unwrap().current();
+/// assert!((-5.0..5.0).contains(&q.into_value()));
+/// ```
+pub fn quantity<U: UnitInfo, S: Strategy>(values: S) -> impl Strategy<Value = Quantity<S::Value, U>>
+where
+    S::Value: fmt::Debug,
+{
+    values.prop_map(Quantity::new)
+}
+
+/// Quantities with any value of type `T`, in `U`.
+pub fn any_quantity<T: Arbitrary + 'static, U: UnitInfo + 'static>() -> BoxedStrategy<Quantity<T, U>>
+{
+    any::<Quantity<T, U>>()
+}
+
+/// Check that converting `q` to `Y` and back gives `q` again.
+///
+/// Values that overflow `T` in `Y` are rejected rather than failing the
+/// test.
+pub fn check_roundtrip<T, U, Y>(q: Quantity<T, U>) -> Result<(), TestCaseError>
+where
+    T: Scalar + fmt::Debug,
+    U: UnitInfo + CommonScale<Y> + UnitConvertLossy<T, Y>,
+    Y: UnitInfo + UnitConvertExact<T, U>,
+{
+    let Some(converted) = q.clone().checked_convert::<Y>() else {
+        return Err(TestCaseError::reject("value overflows in the finer unit"));
+    };
+    let back = converted.clone().convert_lossy::<U>();
+    if back.val != q.val {
+        return Err(TestCaseError::fail(format!(
+            "{:?} converted to {:?} and back is {:?}",
+            q, converted, back
+        )));
+    }
+    Ok(())
+}
+
+/// Check that converting `q` to `Y` and back gives `q` again, to within a
+/// few rounding errors.
+///
+/// ```rust
+/// use proptest::prelude::*;
+/// use proptest::test_runner::TestRunner;
+/// use uy::{si, units};
+///
+/// TestRunner::default()
+///     .run(&uy::testing::quantity::<units::psi, _>(-1e6..1e6), |q| {
+///         uy::testing::check_roundtrip_float::<f64, _, si::kilo<si::Pa>>(q)
+///     })
+///     .unwrap();
+/// ```
+pub fn check_roundtrip_float<T, U, Y>(q: Quantity<T, U>) -> Result<(), TestCaseError>
+where
+    T: Float + fmt::Debug,
+    U: UnitInfo + UnitConvertLossy<T, Y>,
+    Y: UnitInfo + UnitConvertLossy<T, U>,
+{
+    let converted = q.convert_lossy::<Y>();
+    let back = converted.convert_lossy::<U>();
+    let tolerance = q.val.abs() * T::epsilon() * T::from(4).unwrap();
+    if (back.val - q.val).abs() > tolerance {
+        return Err(TestCaseError::fail(format!(
+            "{:?} converted to {:?} and back is {:?}",
+            q, converted, back
+        )));
+    }
+    Ok(())
+}
+
+/// Check that the [canonical text](Quantity::to_canonical_string) of `q`
+/// parses back into `q`.
+///
+/// ```rust
+/// use proptest::prelude::*;
+/// use proptest::test_runner::TestRunner;
+/// use uy::{si, Quantity};
+///
+/// let mut runner = TestRunner::default();
+/// runner
+///     .run(&any::<Quantity<i64, si::milli<si::N>>>(), uy::testing::check_canonical_roundtrip)
+///     .unwrap();
+/// runner
+///     .run(&uy::testing::quantity::<si::kilo<si::Pa>, _>(proptest::num::f64::NORMAL), |q| {
+///         uy::testing::check_canonical_roundtrip(q)
+///     })
+///     .unwrap();
+/// ```
+pub fn check_canonical_roundtrip<T, U>(q: Quantity<T, U>) -> Result<(), TestCaseError>
+where
+    T: Scalar + fmt::Debug + fmt::Display + FromStr,
+    T::Err: fmt::Display,
+    U: UnitInfo,
+{
+    let text = q.to_canonical_string();
+    match text.parse::<Quantity<T, U>>() {
+        Ok(back) if back.val == q.val => Ok(()),
+        Ok(back) => Err(TestCaseError::fail(format!(
+            "{:?} was written as `{}` and parsed as {:?}",
+            q, text, back
+        ))),
+        Err(e) => Err(TestCaseError::fail(format!(
+            "{:?} was written as `{}`, which doesn't parse: {}",
+            q, text, e
+        ))),
+    }
+}
+
+/// Check that `A` and `B` are the same unit, including their scale and
+/// [kind](crate::UnitInfo::KIND), such as to test an identity of unit
+/// arithmetic.
+pub fn check_same_unit<A: UnitInfo, B: UnitInfo>() -> Result<(), TestCaseError> {
+    if !same_unit::<A, B>() {
+        return Err(TestCaseError::fail(format!(
+            "{} is not the same unit as {}",
+            A::SYMBOL,
+            B::SYMBOL
+        )));
+    }
+    Ok(())
+}
+
+/// Check that `A` and `B` have the same dimension, regardless of scale.
+pub fn check_same_dimension<A: UnitInfo, B: UnitInfo>() -> Result<(), TestCaseError> {
+    if !same_dimension::<A, B>() {
+        return Err(TestCaseError::fail(format!(
+            "{} does not have the same dimension as {}",
+            A::SYMBOL,
+            B::SYMBOL
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//! Helpers for times, frequencies, and periods.
+
+use std::ops;
+use std::time::Duration;
+
+use num_traits::Float;
+
+use crate::si::quantities::{Frequency, Period};
+use crate::{si, Div, Mul, Quantity, Unit};
+
+impl<T: Float> Quantity<T, si::Hz> {
+    /// The time taken by one cycle at this frequency.
+    ///
+    /// ```rust
+    /// # use uy::si::quantities::Frequency;
+    /// let mains: Frequency<f64> = Frequency::new(50.0);
+    /// assert_eq!(mains.period().into_value(), 0.02);
+    /// ```
+    pub fn period(self) -> Period<T> {
+        Quantity::new(self.val.recip())
+    }
+
+    /// The frequency of `count` events happening over `duration`.
+    ///
+    /// ```rust
+    /// # use uy::si::quantities::{Frequency, Time};
+    /// let ticks = Frequency::from_rate(500.0, Time::new(2.0));
+    /// assert_eq!(ticks.into_value(), 250.0);
+    /// ```
+    pub fn from_rate(count: T, duration: Quantity<T, si::s>) -> Frequency<T> {
+        Quantity::new(count / duration.val)
+    }
+}
+
+impl<T: Float> Quantity<T, si::s> {
+    /// The frequency of a cycle that takes this long.
+    ///
+    /// ```rust
+    /// # use uy::si::quantities::Period;
+    /// let frame: Period<f32> = Period::new(0.04);
+    /// assert_eq!(frame.frequency().into_value(), 25.0);
+    /// ```
+    pub fn frequency(self) -> Frequency<T> {
+        Quantity::new(self.val.recip())
+    }
+
+    /// The length of a [`Duration`] in seconds.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use uy::si::quantities::Time;
+    /// let t: Time<f64> = Time::from_duration(Duration::from_millis(1500));
+    /// assert_eq!(t.into_value(), 1.5);
+    /// ```
+    pub fn from_duration(duration: Duration) -> Self {
+        Quantity::new(T::from(duration.as_secs_f64()).unwrap())
+    }
+
+    /// Convert to a [`Duration`], or `None` if the time is negative, not
+    /// finite, or too long for a `Duration`.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use uy::si::quantities::Time;
+    /// assert_eq!(Time::new(0.25f64).to_duration(), Some(Duration::from_millis(250)));
+    /// assert_eq!(Time::new(-1.0f64).to_duration(), None);
+    /// ```
+    pub fn to_duration(self) -> Option<Duration> {
+        Duration::try_from_secs_f64(self.val.to_f64()?).ok()
+    }
+}
+
+impl<T, U: Unit> Quantity<T, U> {
+    /// The change accumulated at this rate over `dt`, as one step of Euler
+    /// integration.
+    ///
+    /// ```rust
+    /// use uy::si::quantities::{Acceleration, Length, Time, Velocity};
+    /// use uy::Quantity;
+    ///
+    /// let g: Acceleration<f64> = Quantity::new(-9.8);
+    /// let dt: Time<f64> = Quantity::new(0.5);
+    /// let (mut x, mut v): (Length<f64>, Velocity<f64>) = (Quantity::new(100.0), Quantity::new(0.0));
+    /// for _ in 0..2 {
+    ///     v = v + g.integrate(dt);
+    ///     x = x + v.integrate(dt);
+    /// }
+    /// assert!((x.into_value() - 92.65).abs() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn integrate(self, dt: Quantity<T, si::s>) -> Quantity<T, Mul<U, si::s>>
+    where
+        T: ops::Mul<Output = T>,
+        U: ops::Mul<si::s>,
+        Mul<U, si::s>: Unit,
+    {
+        Quantity::new(self.val * dt.val)
+    }
+
+    /// The average rate of change of a quantity that changed by `self` over
+    /// `dt`.
+    ///
+    /// ```rust
+    /// use uy::si::quantities::{Length, Time, Velocity};
+    /// use uy::Quantity;
+    ///
+    /// let dx: Length<f64> = Quantity::new(3.0);
+    /// let v: Velocity<f64> = dx.differentiate(Time::new(2.0));
+    /// assert_eq!(v.into_value(), 1.5);
+    /// ```
+    #[inline]
+    pub fn differentiate(self, dt: Quantity<T, si::s>) -> Quantity<T, Div<U, si::s>>
+    where
+        T: ops::Div<Output = T>,
+        U: ops::Div<si::s>,
+        Div<U, si::s>: Unit,
+    {
+        Quantity::new(self.val / dt.val)
+    }
+}
@@ -0,0 +1,113 @@
+//! Timers for [`tokio`](https://docs.rs/tokio) that take times as
+//! quantities, so timeouts can be carried with their unit from
+//! configuration to the call that waits.
+//!
+//! ```rust
+//! use uy::{si, Quantity};
+//!
+//! # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+//! let poll: Quantity<u64, si::milli<si::s>> = Quantity::new(5);
+//! uy::tokio::sleep(poll).await;
+//!
+//! let limit: Quantity<f32, si::milli<si::s>> = Quantity::new(1.5);
+//! let result = uy::tokio::timeout(limit, std::future::pending::<()>()).await;
+//! assert!(result.is_err());
+//! # });
+//! ```
+
+use std::future::{Future, IntoFuture};
+use std::time::Duration;
+
+use ::tokio::time::{Interval, Sleep, Timeout};
+use num_traits::ToPrimitive;
+
+use crate::{si, MulPowerOfTen, Quantity, SameDimension};
+
+/// The length of a time as a [`Duration`], or `None` if it's negative, not
+/// finite, or too long for a `Duration`. Integer times are converted exactly,
+/// down to the nanosecond.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use uy::{si, units, Quantity};
+///
+/// let t: Quantity<u32, si::micro<si::s>> = Quantity::new(1_500_250);
+/// assert_eq!(uy::tokio::duration(t), Some(Duration::from_micros(1_500_250)));
+///
+/// let t: Quantity<f64, units::min> = Quantity::new(0.5);
+/// assert_eq!(uy::tokio::duration(t), Some(Duration::from_secs(30)));
+/// assert_eq!(uy::tokio::duration(Quantity::<i8, si::s>::new(-1)), None);
+/// ```
+pub fn duration<T, U>(t: Quantity<T, U>) -> Option<Duration>
+where
+    T: MulPowerOfTen + ToPrimitive,
+    U: SameDimension<si::s>,
+{
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+    if !T::TRUNCATES {
+        let secs = t.val.to_f64()? * 10f64.powi(U::PREFIX.into()) * U::FACTOR.to_f64();
+        return Duration::try_from_secs_f64(secs).ok();
+    }
+
+    let exp = i32::from(U::PREFIX) + 9;
+    let pow = 10u128.checked_pow(exp.unsigned_abs())?;
+    let scaled = t.val.to_u128()?.checked_mul(U::FACTOR.numer)?;
+    let nanos = if exp < 0 {
+        scaled / pow / U::FACTOR.denom
+    } else {
+        scaled.checked_mul(pow)? / U::FACTOR.denom
+    };
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+    Some(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+}
+
+/// The [`duration`], with negative times as zero and times too long for a
+/// `Duration` as [`Duration::MAX`].
+fn saturating_duration<T, U>(t: Quantity<T, U>) -> Duration
+where
+    T: MulPowerOfTen + ToPrimitive,
+    U: SameDimension<si::s>,
+{
+    let negative = t.val.to_f64().is_some_and(|v| v <= 0.0);
+    duration(t).unwrap_or(if negative {
+        Duration::ZERO
+    } else {
+        Duration::MAX
+    })
+}
+
+/// Wait until `t` has elapsed, like [`tokio::time::sleep`].
+/// Negative times don't wait.
+pub fn sleep<T, U>(t: Quantity<T, U>) -> Sleep
+where
+    T: MulPowerOfTen + ToPrimitive,
+    U: SameDimension<si::s>,
+{
+    ::tokio::time::sleep(saturating_duration(t))
+}
+
+/// Require `future` to complete before `t` has elapsed, like
+/// [`tokio::time::timeout`].
+pub fn timeout<T, U, F>(t: Quantity<T, U>, future: F) -> Timeout<F::IntoFuture>
+where
+    T: MulPowerOfTen + ToPrimitive,
+    U: SameDimension<si::s>,
+    F: IntoFuture,
+    F::IntoFuture: Future,
+{
+    ::tokio::time::timeout(saturating_duration(t), future)
+}
+
+/// Tick every `period`, like [`tokio::time::interval`].
+///
+/// # Panics
+///
+/// Panics if `period` is zero or negative.
+pub fn interval<T, U>(period: Quantity<T, U>) -> Interval
+where
+    T: MulPowerOfTen + ToPrimitive,
+    U: SameDimension<si::s>,
+{
+    ::tokio::time::interval(saturating_duration(period))
+}
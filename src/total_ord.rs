@@ -0,0 +1,102 @@
+//! The total order of floating-point quantities.
+
+use std::{cmp, fmt};
+
+use num_traits::float::TotalOrder;
+
+use crate::{Quantity, Unit, UnitInfo};
+
+impl<T: TotalOrder, U: Unit> Quantity<T, U> {
+    /// Compare the values in the total order of [`f64::total_cmp`], where
+    /// `-0.0` is less than `0.0` and NaNs are ordered by their sign and
+    /// payload.
+    ///
+    /// ```rust
+    /// use uy::{si, Quantity};
+    ///
+    /// let temps: Vec<Quantity<f64, si::K>> = vec![Quantity::new(290.5), Quantity::new(301.0)];
+    /// let hottest = temps.iter().max_by(|a, b| a.total_cmp(b)).unwrap();
+    /// assert_eq!(hottest.into_value(), 301.0);
+    /// ```
+    #[inline]
+    pub fn total_cmp(&self, other: &Self) -> cmp::Ordering {
+        self.val.total_cmp(&other.val)
+    }
+
+    /// Wrap the quantity in [`OrdQuantity`], so it's ordered by
+    /// [`total_cmp`](Quantity::total_cmp).
+    #[inline]
+    pub fn total_ord(self) -> OrdQuantity<T, U> {
+        OrdQuantity(self)
+    }
+}
+
+/// A floating-point quantity that implements `Ord` with
+/// [`Quantity::total_cmp`], so it can be a key of a `BTreeMap` or sorted
+/// without a comparison closure. Equality follows the same order, so `-0.0`
+/// and `0.0` are different keys and a NaN is equal to itself.
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use uy::{si, OrdQuantity, Quantity};
+///
+/// let mut loads: BTreeMap<OrdQuantity<f64, si::s>, &str> = BTreeMap::new();
+/// loads.insert(Quantity::new(2.5).total_ord(), "peak");
+/// loads.insert(Quantity::new(0.5).total_ord(), "idle");
+///
+/// let (first, label) = loads.first_key_value().unwrap();
+/// assert_eq!((first.0.into_value(), *label), (0.5, "idle"));
+///
+/// let mut v = vec![OrdQuantity(Quantity::<f32, si::m>::new(3.0)), OrdQuantity(Quantity::new(-1.0))];
+/// v.sort();
+/// assert_eq!(v[0].0.into_value(), -1.0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct OrdQuantity<T, U: Unit>(pub Quantity<T, U>);
+
+impl<T: TotalOrder, U: Unit> PartialEq for OrdQuantity<T, U> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<T: TotalOrder, U: Unit> Eq for OrdQuantity<T, U> {}
+
+impl<T: TotalOrder, U: Unit> PartialOrd for OrdQuantity<T, U> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: TotalOrder, U: Unit> Ord for OrdQuantity<T, U> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<T, U: Unit> From<Quantity<T, U>> for OrdQuantity<T, U> {
+    fn from(q: Quantity<T, U>) -> Self {
+        OrdQuantity(q)
+    }
+}
+
+impl<T, U: Unit> From<OrdQuantity<T, U>> for Quantity<T, U> {
+    fn from(q: OrdQuantity<T, U>) -> Self {
+        q.0
+    }
+}
+
+impl<T: fmt::Debug, U: UnitInfo> fmt::Debug for OrdQuantity<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OrdQuantity").field(&self.0).finish()
+    }
+}
+
+impl<T: fmt::Display, U: UnitInfo> fmt::Display for OrdQuantity<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,209 @@
+//! Common units outside the SI, each defined as an exact multiple of an SI
+//! unit. See [`ScaledUnit`](crate::ScaledUnit) for how they convert.
+
+#![allow(non_camel_case_types)]
+
+use crate::scaled_unit::scaled_unit;
+use crate::si::quantities::Acceleration;
+use crate::{si, Div, Mul, Quantity, TenTo};
+
+/// The standard acceleration due to gravity, 9.80665 m·s⁻².
+///
+/// ```rust
+/// use uy::{si, units};
+///
+/// let g = units::standard_gravity::<f64>();
+/// let weight: uy::Quantity<f64, si::N> = uy::Quantity::<f64, si::kg>::new(2.0) * g;
+/// assert!((weight.into_value() - 19.6133).abs() < 1e-9);
+/// ```
+pub fn standard_gravity<T: num_traits::Float>() -> Acceleration<T> {
+    Quantity::new(T::from(9.80665).unwrap())
+}
+
+scaled_unit!(
+    /// The kilogram-force, the weight of one kilogram under standard gravity.
+    kgf = 980665 / 100000 si::N, "kgf"
+);
+scaled_unit!(
+    /// The pound-force, the weight of one avoirdupois pound under standard
+    /// gravity.
+    lbf = 44482216152605 / 10000000000000 si::N, "lbf"
+);
+
+/// The bar, 10⁵ Pa.
+///
+/// ```rust
+/// use uy::{si, units, Quantity};
+///
+/// let tyre: Quantity<f64, units::psi> = Quantity::new(32.0);
+/// let bar: Quantity<f64, units::bar> = tyre.convert();
+/// assert!((bar.into_value() - 2.206).abs() < 1e-3);
+///
+/// let sea_level: Quantity<f64, units::atm> = Quantity::new(1.0);
+/// let torr: Quantity<f64, units::Torr> = sea_level.convert();
+/// assert!((torr.into_value() - 760.0).abs() < 1e-9);
+/// let mbar: Quantity<f64, units::mbar> = sea_level.convert();
+/// assert!((mbar.into_value() - 1013.25).abs() < 1e-9);
+///
+/// let p: Quantity<i32, si::kilo<si::Pa>> = Quantity::<i32, units::bar>::new(3).convert();
+/// assert_eq!(p.into_value(), 300);
+/// ```
+pub type bar = Mul<si::Pa, TenTo<5>>;
+/// The millibar, 100 Pa.
+pub type mbar = si::hecto<si::Pa>;
+
+scaled_unit!(
+    /// The standard atmosphere, 101 325 Pa.
+    atm = 101325 / 1 si::Pa, "atm"
+);
+scaled_unit!(
+    /// Pounds-force per square inch.
+    psi = 44482216152605 / 6451600000 si::Pa, "psi"
+);
+scaled_unit!(
+    /// The conventional millimeter of mercury, 133.322387415 Pa.
+    mmHg = 133322387415 / 1000000000 si::Pa, "mmHg"
+);
+scaled_unit!(
+    /// The torr, 1/760 of a standard atmosphere.
+    Torr = 101325 / 760 si::Pa, "Torr"
+);
+
+scaled_unit!(
+    /// The watt-hour, 3600 J.
+    ///
+    /// ```rust
+    /// use uy::{si, units, Quantity};
+    ///
+    /// let battery: Quantity<f64, units::Wh> = Quantity::new(50.0);
+    /// let j: Quantity<f64, si::kilo<si::J>> = battery.convert();
+    /// assert!((j.into_value() - 180.0).abs() < 1e-9);
+    ///
+    /// let kwh: Quantity<f64, units::kWh> = battery.convert();
+    /// assert!((kwh.into_value() - 0.05).abs() < 1e-12);
+    ///
+    /// let food: Quantity<f64, units::cal> = Quantity::new(250e3);
+    /// let kwh: Quantity<f64, units::kWh> = food.convert();
+    /// assert!((kwh.into_value() - 0.290_555).abs() < 1e-6);
+    ///
+    /// let photon: Quantity<f64, units::eV> = Quantity::new(2.0);
+    /// let j: Quantity<f64, si::J> = photon.convert();
+    /// assert!((j.into_value() - 3.204_353_268e-19).abs() < 1e-28);
+    /// ```
+    Wh = 3600 / 1 si::J, "Wh"
+);
+scaled_unit!(
+    /// The kilowatt-hour, 3.6 MJ.
+    kWh = 3600 / 1 si::kilo<si::J>, "kWh"
+);
+scaled_unit!(
+    /// The thermochemical calorie, 4.184 J.
+    cal = 4184 / 1000 si::J, "cal"
+);
+scaled_unit!(
+    /// The international table British thermal unit, 1055.05585262 J.
+    BTU = 105505585262 / 100000000 si::J, "BTU"
+);
+scaled_unit!(
+    /// The electronvolt, 1.602176634 × 10⁻¹⁹ J.
+    eV = 1602176634 / 10000000000 si::atto<si::J>, "eV"
+);
+
+scaled_unit!(
+    /// The minute, 60 s.
+    min = 60 / 1 si::s, "min"
+);
+scaled_unit!(
+    /// The hour, 3600 s.
+    h = 3600 / 1 si::s, "h"
+);
+/// The liter, 10⁻³ m³.
+pub type L = si::milli<Mul<si::m, Mul<si::m, si::m>>>;
+
+scaled_unit!(
+    /// Kilometers per hour, 1/3.6 m·s⁻¹.
+    ///
+    /// ```rust
+    /// use uy::{si, units, Quantity};
+    ///
+    /// let speed: Quantity<f64, units::kmh> = Quantity::new(90.0);
+    /// let ms: Quantity<f64, uy::Div<si::m, si::s>> = speed.convert();
+    /// assert!((ms.into_value() - 25.0).abs() < 1e-9);
+    ///
+    /// let mph: Quantity<f64, units::mph> = speed.convert();
+    /// assert!((mph.into_value() - 55.923).abs() < 1e-3);
+    /// let kn: Quantity<f64, units::kn> = speed.convert();
+    /// assert!((kn.into_value() - 48.596).abs() < 1e-3);
+    ///
+    /// let pump: Quantity<f64, units::L_per_min> = Quantity::new(12.0);
+    /// let flow: Quantity<f64, uy::Div<uy::Mul<si::m, uy::Mul<si::m, si::m>>, si::s>> = pump.convert();
+    /// assert!((flow.into_value() - 2e-4).abs() < 1e-12);
+    /// ```
+    kmh = 5 / 18 Div<si::m, si::s>, "km/h"
+);
+scaled_unit!(
+    /// Miles per hour, 0.44704 m·s⁻¹.
+    mph = 44704 / 100000 Div<si::m, si::s>, "mph"
+);
+scaled_unit!(
+    /// The knot, one nautical mile per hour, 1852/3600 m·s⁻¹.
+    kn = 1852 / 3600 Div<si::m, si::s>, "kn"
+);
+scaled_unit!(
+    /// The nautical mile, 1852 m.
+    nmi = 1852 / 1 si::m, "nmi"
+);
+scaled_unit!(
+    /// Liters per minute, 10⁻³/60 m³·s⁻¹.
+    L_per_min = 1 / 60 Div<L, si::s>, "L/min"
+);
+
+scaled_unit!(
+    /// The degree, π/180 rad, to the 19 significant figures that fit its
+    /// factor.
+    ///
+    /// ```rust
+    /// use uy::{si, units, Quantity};
+    ///
+    /// let heading: Quantity<f64, units::deg> = Quantity::new(90.0);
+    /// let rad: Quantity<f64, si::rad> = heading.convert();
+    /// assert!((rad.into_value() - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
+    /// assert_eq!(heading.to_string(), "90 °");
+    /// ```
+    deg = 1745329251994329577 / 100000000000000000000 si::rad, "°", "deg"
+);
+
+scaled_unit!(
+    /// The percent, a ratio of 10⁻².
+    ///
+    /// ```rust
+    /// use uy::units::{Percent, Permille, Ppm};
+    /// use uy::{si, Quantity, UnitInfo};
+    ///
+    /// let efficiency: Percent<f64> = Quantity::new(42.0);
+    /// assert_eq!(efficiency.to_string(), "42 %");
+    /// assert_eq!("42 %".parse::<Percent<f64>>().unwrap().into_value(), 42.0);
+    /// assert!((efficiency.convert::<si::unitless>().into_value() - 0.42).abs() < 1e-12);
+    ///
+    /// let slope: Permille<f64> = efficiency.convert();
+    /// assert!((slope.into_value() - 420.0).abs() < 1e-9);
+    /// assert_eq!(Quantity::<f64, uy::units::permille>::new(2.5).to_string(), "2.5 ‰");
+    /// assert_eq!(uy::units::permille::UNIT_STR, "permille");
+    ///
+    /// let tolerance: Ppm<f64> = Quantity::<f64, uy::units::percent>::new(0.005).convert();
+    /// assert!((tolerance.into_value() - 50.0).abs() < 1e-9);
+    /// ```
+    percent = 1 / 1 si::centi<si::unitless>, "%"
+);
+scaled_unit!(
+    /// The permille, a ratio of 10⁻³.
+    permille = 1 / 1 si::milli<si::unitless>, "‰", "permille"
+);
+pub use crate::chem::ppm;
+
+/// A ratio in percent.
+pub type Percent<T> = Quantity<T, percent>;
+/// A ratio in permille.
+pub type Permille<T> = Quantity<T, permille>;
+/// A ratio in parts per million.
+pub type Ppm<T> = Quantity<T, ppm>;
@@ -0,0 +1,73 @@
+//! Conversions between [`Quantity`] and the quantities of the `uom` crate.
+//!
+//! Any prefix of a unit converts to and from the matching `uom` quantity,
+//! for both `f32` and `f64` storage.
+//!
+//! ```rust
+//! use uy::{si, Quantity};
+//! use uom::si::f64::Length;
+//! use uom::si::length::meter;
+//!
+//! let a: Quantity<f64, si::kilo<si::m>> = Quantity::new(1.5);
+//! let b: Length = a.into();
+//! assert_eq!(b.get::<meter>(), 1500.0);
+//!
+//! let c: Quantity<f64, si::milli<si::m>> = b.into();
+//! assert_eq!(c.into_value(), 1_500_000.0);
+//! ```
+
+use crate::si::Si;
+use crate::{MulPowerOfTen, Quantity};
+
+macro_rules! impl_uom {
+    ($($quantity:ident, $module:ident::$unit:ident = [$s:literal, $m:literal, $kg:literal, $a:literal, $k:literal, $mol:literal, $cd:literal];)*) => {
+        $(
+            impl_uom!(@float f32, $quantity, $module::$unit, [$s, $m, $kg, $a, $k, $mol, $cd]);
+            impl_uom!(@float f64, $quantity, $module::$unit, [$s, $m, $kg, $a, $k, $mol, $cd]);
+        )*
+    };
+    (@float $t:ident, $quantity:ident, $module:ident::$unit:ident, [$s:literal, $m:literal, $kg:literal, $a:literal, $k:literal, $mol:literal, $cd:literal]) => {
+        impl<const EXP: i8> From<Quantity<$t, Si<EXP, $s, $m, $kg, $a, $k, $mol, $cd, 0>>>
+            for ::uom::si::$t::$quantity
+        {
+            fn from(q: Quantity<$t, Si<EXP, $s, $m, $kg, $a, $k, $mol, $cd, 0>>) -> Self {
+                Self::new::<::uom::si::$module::$unit>(q.into_value().mul_power_of_ten(-EXP))
+            }
+        }
+
+        impl<const EXP: i8> From<::uom::si::$t::$quantity>
+            for Quantity<$t, Si<EXP, $s, $m, $kg, $a, $k, $mol, $cd, 0>>
+        {
+            fn from(q: ::uom::si::$t::$quantity) -> Self {
+                Quantity::new(q.get::<::uom::si::$module::$unit>().mul_power_of_ten(EXP))
+            }
+        }
+    };
+}
+
+impl_uom! {
+    Time, time::second = [1, 0, 0, 0, 0, 0, 0];
+    Length, length::meter = [0, 1, 0, 0, 0, 0, 0];
+    Mass, mass::kilogram = [0, 0, 1, 0, 0, 0, 0];
+    ElectricCurrent, electric_current::ampere = [0, 0, 0, 1, 0, 0, 0];
+    ThermodynamicTemperature, thermodynamic_temperature::kelvin = [0, 0, 0, 0, 1, 0, 0];
+    AmountOfSubstance, amount_of_substance::mole = [0, 0, 0, 0, 0, 1, 0];
+    LuminousIntensity, luminous_intensity::candela = [0, 0, 0, 0, 0, 0, 1];
+    Area, area::square_meter = [0, 2, 0, 0, 0, 0, 0];
+    Volume, volume::cubic_meter = [0, 3, 0, 0, 0, 0, 0];
+    Velocity, velocity::meter_per_second = [-1, 1, 0, 0, 0, 0, 0];
+    Acceleration, acceleration::meter_per_second_squared = [-2, 1, 0, 0, 0, 0, 0];
+    Frequency, frequency::hertz = [-1, 0, 0, 0, 0, 0, 0];
+    Force, force::newton = [-2, 1, 1, 0, 0, 0, 0];
+    Pressure, pressure::pascal = [-2, -1, 1, 0, 0, 0, 0];
+    Energy, energy::joule = [-2, 2, 1, 0, 0, 0, 0];
+    Power, power::watt = [-3, 2, 1, 0, 0, 0, 0];
+    ElectricCharge, electric_charge::coulomb = [1, 0, 0, 1, 0, 0, 0];
+    ElectricPotential, electric_potential::volt = [-3, 2, 1, -1, 0, 0, 0];
+    Capacitance, capacitance::farad = [4, -2, -1, 2, 0, 0, 0];
+    ElectricalResistance, electrical_resistance::ohm = [-3, 2, 1, -2, 0, 0, 0];
+    ElectricalConductance, electrical_conductance::siemens = [3, -2, -1, 2, 0, 0, 0];
+    MagneticFlux, magnetic_flux::weber = [-2, 2, 1, -1, 0, 0, 0];
+    MagneticFluxDensity, magnetic_flux_density::tesla = [-2, 0, 1, -1, 0, 0, 0];
+    Inductance, inductance::henry = [-2, 2, 1, -2, 0, 0, 0];
+}
@@ -0,0 +1,377 @@
+//! Collections of quantities that share a single unit.
+//!
+//! A [`QuantityVec`] stores plain values next to each other, with the unit
+//! carried once by the type, so the values can be handed to code that works
+//! on slices of numbers and converted in bulk:
+//!
+//! ```rust
+//! use uy::{si, Quantity, QuantityVec};
+//!
+//! let mut setpoints: QuantityVec<i32, si::V> = QuantityVec::from_values(vec![1, 2]);
+//! setpoints.push(Quantity::new(4));
+//!
+//! let millivolts: QuantityVec<i32, si::milli<si::V>> = setpoints.convert();
+//! assert_eq!(millivolts.values(), &[1000, 2000, 4000]);
+//!
+//! let offset = millivolts + Quantity::new(500);
+//! assert_eq!(offset.iter().max().unwrap().into_value(), 4500);
+//! ```
+
+use std::marker::PhantomData;
+use std::{fmt, iter, ops, slice, vec};
+
+use crate::{Quantity, Unit, UnitConvertExact, UnitConvertLossy, UnitInfo};
+
+/// A vector of values in the unit `U`.
+pub struct QuantityVec<T, U: Unit> {
+    pub(crate) values: Vec<T>,
+    _marker: PhantomData<U>,
+}
+
+/// A borrowed slice of values in the unit `U`.
+pub struct QuantitySlice<'a, T, U: Unit> {
+    values: &'a [T],
+    _marker: PhantomData<U>,
+}
+
+impl<T, U: Unit> QuantityVec<T, U> {
+    pub fn new() -> Self {
+        Self::from_values(Vec::new())
+    }
+
+    /// Attach the unit to a vector of plain values.
+    pub fn from_values(values: Vec<T>) -> Self {
+        Self {
+            values,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The values, without the unit.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The values, without the unit, to modify in place.
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    /// Unwrap the vector of values, discarding the unit.
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
+
+    pub fn as_slice(&self) -> QuantitySlice<'_, T, U> {
+        QuantitySlice::from_values(&self.values)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn push(&mut self, q: Quantity<T, U>) {
+        self.values.push(q.val);
+    }
+
+    pub fn get(&self, index: usize) -> Option<Quantity<T, U>>
+    where
+        T: Clone,
+    {
+        self.as_slice().get(index)
+    }
+
+    /// Iterate over copies of the quantities.
+    pub fn iter(&self) -> Iter<'_, T, U>
+    where
+        T: Clone,
+    {
+        self.as_slice().iter()
+    }
+
+    /// Convert every value to another unit, reusing the allocation.
+    pub fn convert<Y: UnitConvertExact<T, U>>(self) -> QuantityVec<T, Y> {
+        QuantityVec::from_values(self.values.into_iter().map(Y::unit_convert).collect())
+    }
+
+    /// Convert every value to another unit, truncating integers when
+    /// converting to a coarser unit.
+    pub fn convert_lossy<Y: UnitConvertLossy<T, U>>(self) -> QuantityVec<T, Y> {
+        QuantityVec::from_values(self.values.into_iter().map(Y::unit_convert_lossy).collect())
+    }
+
+    fn zip_with(mut self, rhs: QuantitySlice<'_, T, U>, f: impl Fn(&mut T, T)) -> Self
+    where
+        T: Clone,
+    {
+        assert_eq!(self.len(), rhs.len(), "collections differ in length");
+        for (a, b) in self.values.iter_mut().zip(rhs.values) {
+            f(a, b.clone());
+        }
+        self
+    }
+}
+
+impl<'a, T, U: Unit> QuantitySlice<'a, T, U> {
+    /// Attach the unit to a slice of plain values.
+    pub fn from_values(values: &'a [T]) -> Self {
+        Self {
+            values,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The values, without the unit.
+    pub fn values(&self) -> &'a [T] {
+        self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Quantity<T, U>>
+    where
+        T: Clone,
+    {
+        self.values.get(index).cloned().map(Quantity::new)
+    }
+
+    /// Iterate over copies of the quantities.
+    pub fn iter(&self) -> Iter<'a, T, U>
+    where
+        T: Clone,
+    {
+        Iter {
+            inner: self.values.iter(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn to_vec(&self) -> QuantityVec<T, U>
+    where
+        T: Clone,
+    {
+        QuantityVec::from_values(self.values.to_vec())
+    }
+
+    /// Convert every value into a new vector in another unit.
+    ///
+    /// ```rust
+    /// use uy::vec::QuantitySlice;
+    /// use uy::{si, QuantityVec};
+    ///
+    /// let raw = [1500, 2000];
+    /// let ms: QuantitySlice<'_, i64, si::milli<si::s>> = QuantitySlice::from_values(&raw);
+    /// let us: QuantityVec<i64, si::micro<si::s>> = ms.convert();
+    /// assert_eq!(us.values(), &[1_500_000, 2_000_000]);
+    /// ```
+    pub fn convert<Y: UnitConvertExact<T, U>>(&self) -> QuantityVec<T, Y>
+    where
+        T: Clone,
+    {
+        QuantityVec::from_values(self.values.iter().cloned().map(Y::unit_convert).collect())
+    }
+}
+
+impl<T, U: Unit> Default for QuantityVec<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, U: Unit> Clone for QuantityVec<T, U> {
+    fn clone(&self) -> Self {
+        Self::from_values(self.values.clone())
+    }
+}
+
+impl<T, U: Unit> Clone for QuantitySlice<'_, T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, U: Unit> Copy for QuantitySlice<'_, T, U> {}
+
+impl<T: PartialEq, U: Unit> PartialEq for QuantityVec<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+impl<T: PartialEq, U: Unit> PartialEq for QuantitySlice<'_, T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+impl<T: fmt::Debug, U: UnitInfo> fmt::Debug for QuantityVec<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T: fmt::Debug, U: UnitInfo> fmt::Debug for QuantitySlice<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} {}", self.values, U::SYMBOL)
+    }
+}
+
+impl<T, U: Unit> FromIterator<Quantity<T, U>> for QuantityVec<T, U> {
+    fn from_iter<I: IntoIterator<Item = Quantity<T, U>>>(iter: I) -> Self {
+        Self::from_values(iter.into_iter().map(|q| q.val).collect())
+    }
+}
+
+impl<T, U: Unit> Extend<Quantity<T, U>> for QuantityVec<T, U> {
+    fn extend<I: IntoIterator<Item = Quantity<T, U>>>(&mut self, iter: I) {
+        self.values.extend(iter.into_iter().map(|q| q.val));
+    }
+}
+
+impl<T, U: Unit> From<Vec<Quantity<T, U>>> for QuantityVec<T, U> {
+    fn from(quantities: Vec<Quantity<T, U>>) -> Self {
+        quantities.into_iter().collect()
+    }
+}
+
+impl<'a, T, U: Unit> From<&'a QuantityVec<T, U>> for QuantitySlice<'a, T, U> {
+    fn from(vec: &'a QuantityVec<T, U>) -> Self {
+        vec.as_slice()
+    }
+}
+
+/// An iterator over copies of the quantities in a [`QuantitySlice`].
+pub struct Iter<'a, T, U: Unit> {
+    inner: slice::Iter<'a, T>,
+    _marker: PhantomData<U>,
+}
+
+impl<T: Clone, U: Unit> Iterator for Iter<'_, T, U> {
+    type Item = Quantity<T, U>;
+
+    fn next(&mut self) -> Option<Quantity<T, U>> {
+        self.inner.next().cloned().map(Quantity::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: Clone, U: Unit> DoubleEndedIterator for Iter<'_, T, U> {
+    fn next_back(&mut self) -> Option<Quantity<T, U>> {
+        self.inner.next_back().cloned().map(Quantity::new)
+    }
+}
+
+impl<T: Clone, U: Unit> ExactSizeIterator for Iter<'_, T, U> {}
+
+impl<T, U: Unit> IntoIterator for QuantityVec<T, U> {
+    type Item = Quantity<T, U>;
+    type IntoIter = iter::Map<vec::IntoIter<T>, fn(T) -> Quantity<T, U>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter().map(Quantity::new)
+    }
+}
+
+impl<'a, T: Clone, U: Unit> IntoIterator for &'a QuantityVec<T, U> {
+    type Item = Quantity<T, U>;
+    type IntoIter = Iter<'a, T, U>;
+
+    fn into_iter(self) -> Iter<'a, T, U> {
+        self.iter()
+    }
+}
+
+impl<'a, T: Clone, U: Unit> IntoIterator for QuantitySlice<'a, T, U> {
+    type Item = Quantity<T, U>;
+    type IntoIter = Iter<'a, T, U>;
+
+    fn into_iter(self) -> Iter<'a, T, U> {
+        self.iter()
+    }
+}
+
+macro_rules! impl_ops {
+    ($($trait:ident::$fn:ident, $assign_trait:ident::$assign_fn:ident);*) => {
+        $(
+            /// Apply the operation to each value and the quantity.
+            impl<T: Clone + ops::$assign_trait, U: Unit> ops::$trait<Quantity<T, U>> for QuantityVec<T, U> {
+                type Output = Self;
+
+                fn $fn(mut self, rhs: Quantity<T, U>) -> Self {
+                    for v in &mut self.values {
+                        ops::$assign_trait::$assign_fn(v, rhs.val.clone());
+                    }
+                    self
+                }
+            }
+
+            /// Apply the operation elementwise.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the collections differ in length.
+            impl<T: Clone + ops::$assign_trait, U: Unit> ops::$trait<QuantitySlice<'_, T, U>> for QuantityVec<T, U> {
+                type Output = Self;
+
+                fn $fn(self, rhs: QuantitySlice<'_, T, U>) -> Self {
+                    self.zip_with(rhs, ops::$assign_trait::$assign_fn)
+                }
+            }
+
+            /// Apply the operation elementwise.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the collections differ in length.
+            impl<T: Clone + ops::$assign_trait, U: Unit> ops::$trait<&QuantityVec<T, U>> for QuantityVec<T, U> {
+                type Output = Self;
+
+                fn $fn(self, rhs: &QuantityVec<T, U>) -> Self {
+                    self.zip_with(rhs.as_slice(), ops::$assign_trait::$assign_fn)
+                }
+            }
+        )*
+    };
+}
+
+impl_ops!(Add::add, AddAssign::add_assign; Sub::sub, SubAssign::sub_assign);
+
+macro_rules! impl_scalar_ops {
+    ($($trait:ident::$fn:ident, $assign_trait:ident::$assign_fn:ident);*) => {
+        $(
+            /// Scale each value by a plain number.
+            ///
+            /// ```rust
+            /// use uy::{si, QuantityVec};
+            ///
+            /// let lengths: QuantityVec<i32, si::m> = QuantityVec::from_values(vec![2, 4]);
+            /// assert_eq!((lengths * 3 / 2).values(), &[3, 6]);
+            /// ```
+            impl<T: Clone + ops::$assign_trait, U: Unit> ops::$trait<T> for QuantityVec<T, U> {
+                type Output = Self;
+
+                fn $fn(mut self, rhs: T) -> Self {
+                    for v in &mut self.values {
+                        ops::$assign_trait::$assign_fn(v, rhs.clone());
+                    }
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_ops!(Mul::mul, MulAssign::mul_assign; Div::div, DivAssign::div_assign);
@@ -0,0 +1,65 @@
+//! Quantities for JavaScript, through [`wasm-bindgen`](https://docs.rs/wasm-bindgen).
+//!
+//! Quantities can't be exported directly, since their unit lives in the type
+//! system, so they are converted into a [`JsQuantity`] carrying the value and
+//! the unit symbol instead.
+//!
+//! ```rust
+//! use uy::wasm::JsQuantity;
+//! use uy::{si, Quantity};
+//!
+//! let p: Quantity<f32, si::kilo<si::W>> = Quantity::new(1.5);
+//! let js = JsQuantity::from(p);
+//! assert_eq!(js.value(), 1.5);
+//! assert_eq!(js.unit(), "10³·m²·kg·s⁻³");
+//! assert_eq!(js.to_js_string(), "1.5 10³·m²·kg·s⁻³");
+//! ```
+
+use ::wasm_bindgen::prelude::*;
+
+use crate::{Quantity, UnitInfo};
+
+/// A value tagged with the symbol of its unit.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsQuantity {
+    value: f64,
+    unit: String,
+    display: String,
+}
+
+#[wasm_bindgen]
+impl JsQuantity {
+    /// The value, in the unit given by `unit`.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The symbol of the unit.
+    #[wasm_bindgen(getter)]
+    pub fn unit(&self) -> String {
+        self.unit.clone()
+    }
+
+    /// The value followed by the unit symbol.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.display.clone()
+    }
+}
+
+impl<T, U> From<Quantity<T, U>> for JsQuantity
+where
+    T: Into<f64> + std::fmt::Display,
+    U: UnitInfo,
+{
+    fn from(q: Quantity<T, U>) -> Self {
+        let display = q.to_string();
+        JsQuantity {
+            value: q.val.into(),
+            unit: U::SYMBOL.to_owned(),
+            display,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Support for [`zeroize`](https://docs.rs/zeroize), so buffers of sensitive
+//! measurements can be scrubbed from memory without unwrapping their units.
+//!
+//! ```rust
+//! use uy::{si, Quantity, QuantityVec};
+//! use zeroize::Zeroize;
+//!
+//! let mut dose: Quantity<f64, si::milli<si::A>> = Quantity::new(12.5);
+//! dose.zeroize();
+//! assert_eq!(dose.into_value(), 0.0);
+//!
+//! let mut readings: QuantityVec<i32, si::m> = QuantityVec::from_values(vec![3, 1, 4]);
+//! readings.zeroize();
+//! assert!(readings.is_empty());
+//! ```
+
+use ::zeroize::Zeroize;
+
+use crate::{Quantity, QuantityVec, Unit};
+
+impl<T: Zeroize, U: Unit> Zeroize for Quantity<T, U> {
+    fn zeroize(&mut self) {
+        self.val.zeroize();
+    }
+}
+
+/// Zeroes every value and the spare capacity of the vector, then clears it.
+impl<T: Zeroize, U: Unit> Zeroize for QuantityVec<T, U> {
+    fn zeroize(&mut self) {
+        self.values.zeroize();
+    }
+}